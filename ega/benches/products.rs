@@ -0,0 +1,62 @@
+//! The products of two [`Multivector`]s, via the operators & via the explicit
+//! SIMD implementation in [`ega::simd`]
+//!
+//! Without the `simd` feature the operators use the scalar implementation, so
+//! `cargo bench --bench products` compares the two paths.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ega::conventions::{ConvertConvention, BIVECTOR_NET};
+use ega::*;
+use std::hint::black_box;
+
+const COUNT: usize = 1024;
+
+/// A deterministic spread of multivectors
+fn values(mut state: u32) -> Vec<Multivector> {
+  let mut next = || {
+    state ^= state << 13;
+    state ^= state >> 17;
+    state ^= state << 5;
+    (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+  };
+  (0..COUNT)
+    .map(|_| {
+      let elements = core::array::from_fn(|_| next());
+      Multivector::from_convention(&BIVECTOR_NET, elements)
+    })
+    .collect()
+}
+
+fn bench_product(
+  c: &mut Criterion,
+  name: &str,
+  scalar: fn(Multivector, Multivector) -> Multivector,
+  simd: fn(Multivector, Multivector) -> Multivector,
+) {
+  let (lhs, rhs) = (values(0x2545_f491), values(0x9e37_79b9));
+  let mut group = c.benchmark_group(name);
+  for (path, product) in [("operator", scalar), ("simd", simd)] {
+    group.bench_function(path, |bencher| {
+      bencher.iter(|| {
+        for (&a, &b) in lhs.iter().zip(&rhs) {
+          black_box(product(black_box(a), black_box(b)));
+        }
+      })
+    });
+  }
+  group.finish();
+}
+
+fn products(c: &mut Criterion) {
+  bench_product(
+    c,
+    "geometric_product",
+    |a, b| a.geometric_product(b),
+    simd::geometric_product,
+  );
+  bench_product(c, "meet", |a, b| a.meet(b), simd::meet);
+  bench_product(c, "join", |a, b| a.join(b), simd::join);
+}
+
+criterion_group!(benches, products);
+criterion_main!(benches);
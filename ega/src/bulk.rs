@@ -0,0 +1,342 @@
+//! Reductions over slices of values, with parallel versions behind the
+//! `rayon` feature
+//!
+//! Every reduction splits its input into blocks of [`BLOCK_SIZE`] elements,
+//! reduces each block from first to last, & then combines the results of the
+//! blocks from first to last. The parallel versions only distribute the
+//! blocks between threads, so they give the same results as the serial
+//! versions, bit for bit, however many threads are used.
+
+use crate::*;
+
+/// The number of elements in each block of a reduction
+pub const BLOCK_SIZE: usize = 1024;
+
+/// An axis aligned bounding box
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bounds {
+  pub min: [f32; 3],
+  pub max: [f32; 3],
+}
+
+impl Bounds {
+  #[inline]
+  fn point([x, y, z]: [f32; 3]) -> Self {
+    Bounds {
+      min: [x, y, z],
+      max: [x, y, z],
+    }
+  }
+
+  #[inline]
+  fn union(self, other: Bounds) -> Bounds {
+    Bounds {
+      min: core::array::from_fn(|i| self.min[i].min(other.min[i])),
+      max: core::array::from_fn(|i| self.max[i].max(other.max[i])),
+    }
+  }
+}
+
+/// The Euclidean coordinates of a point, or `None` for a point at infinity
+#[inline]
+fn coordinates(point: &Trivector) -> Option<[f32; 3]> {
+  (point.e123 != 0.).then(|| {
+    [
+      point.e032 / point.e123,
+      point.e013 / point.e123,
+      point.e021 / point.e123,
+    ]
+  })
+}
+
+/// The product `motors[0] * motors[1] * .. * motors[n - 1]`
+///
+/// Applying the result is the same as applying each of the motors, from last
+/// to first. An empty slice gives the identity.
+pub fn compose(motors: &[Motor]) -> Motor {
+  motors
+    .chunks(BLOCK_SIZE)
+    .map(compose_block)
+    .fold(Motor::identity(), |a, b| a * b)
+}
+
+#[inline]
+fn compose_block(motors: &[Motor]) -> Motor {
+  motors.iter().fold(Motor::identity(), |a, &b| a * b)
+}
+
+/// The bounds of the Euclidean points, or `None` if there are none
+///
+/// Points at infinity are ignored.
+pub fn bounds(points: &[Trivector]) -> Option<Bounds> {
+  points
+    .chunks(BLOCK_SIZE)
+    .filter_map(bounds_block)
+    .reduce(Bounds::union)
+}
+
+#[inline]
+fn bounds_block(points: &[Trivector]) -> Option<Bounds> {
+  points
+    .iter()
+    .filter_map(coordinates)
+    .map(Bounds::point)
+    .reduce(Bounds::union)
+}
+
+/// The centroid of the Euclidean points, with a weight of `1`, or `None` if
+/// there are none
+///
+/// Points at infinity are ignored.
+pub fn centroid(points: &[Trivector]) -> Option<Trivector> {
+  let sum = points
+    .chunks(BLOCK_SIZE)
+    .map(centroid_block)
+    .fold(CentroidSum::default(), CentroidSum::add);
+  sum.centroid()
+}
+
+#[derive(Copy, Clone, Default)]
+struct CentroidSum {
+  sum: [f32; 3],
+  count: usize,
+}
+
+impl CentroidSum {
+  #[inline]
+  fn add(self, other: CentroidSum) -> CentroidSum {
+    CentroidSum {
+      sum: core::array::from_fn(|i| self.sum[i] + other.sum[i]),
+      count: self.count + other.count,
+    }
+  }
+
+  #[inline]
+  fn centroid(self) -> Option<Trivector> {
+    let n = self.count as f32;
+    (self.count > 0).then(|| Trivector {
+      e123: 1.,
+      e032: self.sum[0] / n,
+      e013: self.sum[1] / n,
+      e021: self.sum[2] / n,
+    })
+  }
+}
+
+#[inline]
+fn centroid_block(points: &[Trivector]) -> CentroidSum {
+  points
+    .iter()
+    .filter_map(coordinates)
+    .map(|sum| CentroidSum { sum, count: 1 })
+    .fold(CentroidSum::default(), CentroidSum::add)
+}
+
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+
+#[cfg(feature = "rayon")]
+mod parallel {
+  use super::*;
+  use ::rayon::prelude::*;
+
+  /// Reduce each block in parallel, then combine the results in order
+  fn par_blocks<T: Sync, R: Send>(
+    values: &[T],
+    reduce_block: impl Fn(&[T]) -> R + Sync + Send,
+  ) -> Vec<R> {
+    values.par_chunks(BLOCK_SIZE).map(reduce_block).collect()
+  }
+
+  /// The parallel version of [`compose`], with an identical result
+  pub fn par_compose(motors: &[Motor]) -> Motor {
+    par_blocks(motors, compose_block)
+      .into_iter()
+      .fold(Motor::identity(), |a, b| a * b)
+  }
+
+  /// The parallel version of [`bounds`], with an identical result
+  pub fn par_bounds(points: &[Trivector]) -> Option<Bounds> {
+    par_blocks(points, bounds_block)
+      .into_iter()
+      .flatten()
+      .reduce(Bounds::union)
+  }
+
+  /// The parallel version of [`centroid`], with an identical result
+  pub fn par_centroid(points: &[Trivector]) -> Option<Trivector> {
+    par_blocks(points, centroid_block)
+      .into_iter()
+      .fold(CentroidSum::default(), CentroidSum::add)
+      .centroid()
+  }
+
+  /// The parallel version of [`Motor::transform_points`]
+  ///
+  /// # Panics
+  ///
+  /// If `points` & `out` have different lengths.
+  pub fn par_transform_points(
+    motor: &Motor,
+    points: &[Trivector],
+    out: &mut [Trivector],
+  ) {
+    assert_eq!(points.len(), out.len(), "mismatched slice lengths");
+    let matrix = motor.point_matrix();
+    points
+      .par_chunks(BLOCK_SIZE)
+      .zip(out.par_chunks_mut(BLOCK_SIZE))
+      .for_each(|(points, out)| transform_points_by(&matrix, points, out));
+  }
+
+  /// The parallel version of [`Motor::transform_planes`]
+  ///
+  /// # Panics
+  ///
+  /// If `planes` & `out` have different lengths.
+  pub fn par_transform_planes(
+    motor: &Motor,
+    planes: &[Vector],
+    out: &mut [Vector],
+  ) {
+    assert_eq!(planes.len(), out.len(), "mismatched slice lengths");
+    let matrix = motor.plane_matrix();
+    planes
+      .par_chunks(BLOCK_SIZE)
+      .zip(out.par_chunks_mut(BLOCK_SIZE))
+      .for_each(|(planes, out)| transform_planes_by(&matrix, planes, out));
+  }
+
+  /// The parallel version of [`Motor::transform_lines`]
+  ///
+  /// # Panics
+  ///
+  /// If `lines` & `out` have different lengths.
+  pub fn par_transform_lines(
+    motor: &Motor,
+    lines: &[Bivector],
+    out: &mut [Bivector],
+  ) {
+    assert_eq!(lines.len(), out.len(), "mismatched slice lengths");
+    let matrix = motor.line_matrix();
+    lines
+      .par_chunks(BLOCK_SIZE)
+      .zip(out.par_chunks_mut(BLOCK_SIZE))
+      .for_each(|(lines, out)| transform_lines_by(&matrix, lines, out));
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+
+  fn point(x: f32, y: f32, z: f32) -> Trivector {
+    Trivector { e123: 1., e032: x, e013: y, e021: z }
+  }
+
+  /// Rotations about the z axis by small angles, spanning several blocks
+  fn motors(n: usize) -> Vec<Motor> {
+    (0..n)
+      .map(|i| {
+        let angle = (i % 17) as f32 * 1e-3;
+        Motor { s: angle.cos(), e12: angle.sin(), ..zero() }
+      })
+      .collect()
+  }
+
+  #[test]
+  fn compose_small() {
+    let motors = motors(5);
+    let expected = motors[0] * motors[1] * motors[2] * motors[3] * motors[4];
+    assert_eq!(dbg!(compose(&motors)), dbg!(expected));
+    assert_eq!(compose(&[]), Motor::identity());
+  }
+
+  #[test]
+  fn bounds_of_points() {
+    let points = [
+      point(1., -2., 3.),
+      point(-4., 5., 0.5),
+      // a point at infinity is ignored
+      Trivector { e123: 0., e032: 100., e013: 0., e021: 0. },
+      // a point with a weight of 2, at (1, 1, 1)
+      Trivector { e123: 2., e032: 2., e013: 2., e021: 2. },
+    ];
+    let expected = Bounds { min: [-4., -2., 0.5], max: [1., 5., 3.] };
+    assert_eq!(dbg!(bounds(&points)), Some(expected));
+    assert_eq!(bounds(&[]), None);
+  }
+
+  #[test]
+  fn centroid_of_points() {
+    let points = [
+      point(1., 2., 3.),
+      point(3., 2., 1.),
+      Trivector { e123: 0., e032: 100., e013: 0., e021: 0. },
+      Trivector { e123: 2., e032: 4., e013: 4., e021: 4. },
+    ];
+    assert_eq!(dbg!(centroid(&points)), Some(point(2., 2., 2.)));
+    assert_eq!(centroid(&[]), None);
+  }
+
+  #[cfg(feature = "rayon")]
+  mod parallel {
+    use super::*;
+
+    const N: usize = 10 * BLOCK_SIZE + 17;
+
+    /// A deterministic cloud of points, spanning several blocks
+    fn cloud(n: usize) -> Vec<Trivector> {
+      (0..n)
+        .map(|i| {
+          let t = i as f32 * 0.618_034;
+          point(t.sin() * 10., (t * 1.3).cos() * 7., t.fract() - 0.5)
+        })
+        .collect()
+    }
+
+    #[test]
+    fn compose_matches_serial() {
+      let motors = motors(N);
+      let result = par_compose(&motors);
+      assert_eq!(result, compose(&motors));
+    }
+
+    #[test]
+    fn bounds_and_centroid_match_serial() {
+      let points = cloud(N);
+      assert_eq!(par_bounds(&points), bounds(&points));
+      assert_eq!(par_centroid(&points), centroid(&points));
+    }
+
+    #[test]
+    fn transforms_match_serial() {
+      let motor = Motor::from_even(
+        Vector { e0: 1., e1: 1., e2: 0., e3: 0. }
+          * Vector { e0: 0., e1: 0.6, e2: 0.8, e3: 0. },
+      );
+
+      let points = cloud(N);
+      let (mut serial, mut parallel) = (vec![zero(); N], vec![zero(); N]);
+      motor.transform_points(&points, &mut serial);
+      par_transform_points(&motor, &points, &mut parallel);
+      assert_eq!(serial, parallel);
+
+      let planes: Vec<Vector> = points
+        .iter()
+        .map(|p| Vector { e0: p.e123, e1: p.e032, e2: p.e013, e3: p.e021 })
+        .collect();
+      let (mut serial, mut parallel) = (vec![zero(); N], vec![zero(); N]);
+      motor.transform_planes(&planes, &mut serial);
+      par_transform_planes(&motor, &planes, &mut parallel);
+      assert_eq!(serial, parallel);
+
+      let lines: Vec<Bivector> = points.windows(2).map(|w| w[0].join(w[1])).collect();
+      let (mut serial, mut parallel) = (vec![zero(); N - 1], vec![zero(); N - 1]);
+      motor.transform_lines(&lines, &mut serial);
+      par_transform_lines(&motor, &lines, &mut parallel);
+      assert_eq!(serial, parallel);
+    }
+  }
+}
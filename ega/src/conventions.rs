@@ -0,0 +1,278 @@
+use crate::*;
+
+/// A basis element of some other convention, & the sign relating it to the
+/// corresponding native (bivector.net) basis element
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BasisElement {
+  /// The name of the basis element in the other convention
+  pub name: &'static str,
+  /// `+1.` or `-1.`, such that `native = sign * other`
+  pub sign: f32,
+}
+
+/// A basis convention, mapping each of the native basis elements to a basis
+/// element of another library or text
+///
+/// `basis` is ordered the same as the native components in memory:
+/// ```skip
+/// [
+///       e0,    e1,    e2,    e3,
+///   scalar,   e23,   e31,   e12,
+///      e01,   e02,   e03, e0123,
+///     e123,  e032,  e013,  e021,
+/// ]
+/// ```
+/// & `order` gives the index at which the other convention stores each of
+/// these in a whole multivector. Within each grade, the components keep the
+/// native order.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Convention {
+  pub name: &'static str,
+  pub basis: [BasisElement; 16],
+  pub order: [usize; 16],
+}
+
+/// The native storage order
+const NATIVE_ORDER: [usize; 16] =
+  [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+const fn b(name: &'static str, sign: f32) -> BasisElement {
+  BasisElement { name, sign }
+}
+
+/// The native convention, following the Cayley tables from bivector.net
+#[rustfmt::skip]
+pub const BIVECTOR_NET: Convention = Convention {
+  name: "bivector.net",
+  basis: [
+    b("e0", 1.),   b("e1", 1.),   b("e2", 1.),   b("e3", 1.),
+    b("1", 1.),    b("e23", 1.),  b("e31", 1.),  b("e12", 1.),
+    b("e01", 1.),  b("e02", 1.),  b("e03", 1.),  b("e0123", 1.),
+    b("e123", 1.), b("e032", 1.), b("e013", 1.), b("e021", 1.),
+  ],
+  order: NATIVE_ORDER,
+};
+
+/// The convention used by the klein library
+///
+/// klein uses the same basis elements & signs as bivector.net, but stores
+/// the pseudoscalar first in its `p2` register, `[e0123, e01, e02, e03]`,
+/// where it shares the register with the moment of a line
+#[rustfmt::skip]
+pub const KLEIN: Convention = Convention {
+  name: "klein",
+  basis: [
+    b("e0", 1.),   b("e1", 1.),   b("e2", 1.),   b("e3", 1.),
+    b("1", 1.),    b("e23", 1.),  b("e31", 1.),  b("e12", 1.),
+    b("e01", 1.),  b("e02", 1.),  b("e03", 1.),  b("e0123", 1.),
+    b("e123", 1.), b("e032", 1.), b("e013", 1.), b("e021", 1.),
+  ],
+  order: [0, 1, 2, 3, 4, 5, 6, 7, 9, 10, 11, 8, 12, 13, 14, 15],
+};
+
+/// The convention used by rigidgeometricalgebra.org & E. Lengyel's books
+///
+/// This algebra is the dual of the native one: the degenerate basis vector
+/// is `e4`, points are vectors `x e1 + y e2 + z e3 + w e4` & planes are
+/// trivectors `x e423 + y e431 + z e412 + w e321`. Lines are bivectors with
+/// the direction in `e41, e42, e43` & the moment in `e23, e31, e12`; their
+/// signs are chosen such that the [`Join`] of two native points converts to
+/// the wedge product of the two converted points.
+#[rustfmt::skip]
+pub const RIGID: Convention = Convention {
+  name: "rigidgeometricalgebra.org",
+  basis: [
+    b("e321", 1.), b("e423", 1.), b("e431", 1.), b("e412", 1.),
+    b("e1234", 1.), b("e41", -1.), b("e42", -1.), b("e43", -1.),
+    b("e23", -1.), b("e31", -1.), b("e12", -1.), b("1", 1.),
+    b("e4", 1.),   b("e1", 1.),   b("e2", 1.),   b("e3", 1.),
+  ],
+  order: NATIVE_ORDER,
+};
+
+/// Conversion to & from the components of another [`Convention`]
+pub trait ConvertConvention: Sized {
+  /// The components in the other convention, in its order for a
+  /// [`Multivector`], & the native order for the other types
+  type Components;
+
+  /// Get the components in the other convention
+  fn to_convention(self, convention: &Convention) -> Self::Components;
+
+  /// Construct a value from the components of the other convention
+  fn from_convention(
+    convention: &Convention,
+    components: Self::Components,
+  ) -> Self;
+}
+
+/// Apply the signs from `convention.basis[OFFSET..OFFSET + N]`
+#[inline]
+fn apply_signs<const OFFSET: usize, const N: usize>(
+  convention: &Convention,
+  mut elements: [f32; N],
+) -> [f32; N] {
+  for (i, element) in elements.iter_mut().enumerate() {
+    *element *= convention.basis[OFFSET + i].sign;
+  }
+  elements
+}
+
+macro_rules! impl_convert_convention {
+  (
+    $type:ty, $array:ident, $to_array:ident, $from_array:ident,
+    $offset:literal, $count:literal
+  ) => {
+    impl ConvertConvention for $type {
+      type Components = [f32; $count];
+
+      #[inline]
+      fn to_convention(self, convention: &Convention) -> [f32; $count] {
+        apply_signs::<$offset, $count>(convention, self.$to_array().elements)
+      }
+
+      #[inline]
+      fn from_convention(
+        convention: &Convention,
+        components: [f32; $count],
+      ) -> Self {
+        // the signs are ±1, so are their own inverse
+        let elements = apply_signs::<$offset, $count>(convention, components);
+        $array::from(elements).$from_array()
+      }
+    }
+  };
+}
+
+impl ConvertConvention for Multivector {
+  type Components = [f32; 16];
+
+  #[inline]
+  fn to_convention(self, convention: &Convention) -> [f32; 16] {
+    let elements = self.to_multivector_array().elements;
+    let elements = apply_signs::<0, 16>(convention, elements);
+    let mut components = [0.; 16];
+    for (element, &index) in elements.into_iter().zip(&convention.order) {
+      components[index] = element;
+    }
+    components
+  }
+
+  #[inline]
+  fn from_convention(
+    convention: &Convention,
+    components: [f32; 16],
+  ) -> Multivector {
+    let elements = convention.order.map(|index| components[index]);
+    let elements = apply_signs::<0, 16>(convention, elements);
+    MultivectorArray::from(elements).to_multivector()
+  }
+}
+impl_convert_convention! {
+  Vector, VectorArray, to_vector_array, to_vector, 0, 4
+}
+impl_convert_convention! {
+  Scalar, ScalarArray, to_scalar_array, to_scalar, 4, 1
+}
+impl_convert_convention! {
+  Bivector, BivectorArray, to_bivector_array, to_bivector, 5, 6
+}
+impl_convert_convention! {
+  Pseudoscalar, PseudoscalarArray, to_pseudoscalar_array, to_pseudoscalar,
+  11, 1
+}
+impl_convert_convention! {
+  Trivector, TrivectorArray, to_trivector_array, to_trivector, 12, 4
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  #[test]
+  fn native_is_identity() {
+    let result = MULTIVECTOR_A.to_convention(&BIVECTOR_NET);
+    let expected = MULTIVECTOR_A.to_multivector_array().elements;
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn klein_order() {
+    // klein stores [e0123, e01, e02, e03] together
+    let result = MULTIVECTOR_A.to_convention(&KLEIN);
+    let native = MULTIVECTOR_A.to_multivector_array().elements;
+    assert_eq!(result[8..12], [native[11], native[8], native[9], native[10]]);
+    assert_eq!(result[..8], native[..8]);
+    assert_eq!(result[12..], native[12..]);
+    let result = Multivector::from_convention(&KLEIN, result);
+    assert_eq!(dbg!(result), dbg!(MULTIVECTOR_A));
+    // the grades keep the native order
+    assert_eq!(BIVECTOR_A.to_convention(&KLEIN), BIVECTOR_A.to_convention(&BIVECTOR_NET));
+  }
+
+  #[test]
+  fn round_trip() {
+    let components = MULTIVECTOR_A.to_convention(&RIGID);
+    let result = Multivector::from_convention(&RIGID, components);
+    assert_eq!(dbg!(result), dbg!(MULTIVECTOR_A));
+
+    let components = BIVECTOR_A.to_convention(&RIGID);
+    let result = Bivector::from_convention(&RIGID, components);
+    assert_eq!(dbg!(result), dbg!(BIVECTOR_A));
+
+    let components = TRIVECTOR_C.to_convention(&RIGID);
+    let result = Trivector::from_convention(&RIGID, components);
+    assert_eq!(dbg!(result), dbg!(TRIVECTOR_C));
+  }
+
+  #[test]
+  fn grades_agree_with_multivector() {
+    let multivector = MULTIVECTOR_A.to_convention(&RIGID);
+
+    assert_eq!(MULTIVECTOR_A.grade_1().to_convention(&RIGID)[..], multivector[0..4]);
+    assert_eq!(MULTIVECTOR_A.grade_0().to_convention(&RIGID)[..], multivector[4..5]);
+    assert_eq!(MULTIVECTOR_A.grade_2().to_convention(&RIGID)[..], multivector[5..11]);
+    assert_eq!(MULTIVECTOR_A.grade_4().to_convention(&RIGID)[..], multivector[11..12]);
+    assert_eq!(MULTIVECTOR_A.grade_3().to_convention(&RIGID)[..], multivector[12..16]);
+  }
+
+  #[test]
+  fn rigid_point() {
+    // the point (2, 3, 5), with weight 1
+    let point = Trivector { e123: 1., e032: 2., e013: 3., e021: 5. };
+    // [e4, e1, e2, e3]
+    let result = point.to_convention(&RIGID);
+    let expected = [1., 2., 3., 5.];
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn rigid_plane() {
+    // the plane 2x + 3y + 5z - 7 = 0
+    let plane = Vector { e0: -7., e1: 2., e2: 3., e3: 5. };
+    // [e321, e423, e431, e412]
+    let result = plane.to_convention(&RIGID);
+    let expected = [-7., 2., 3., 5.];
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn rigid_line() {
+    // the line from p = (1, 2, 3) to q = (4, 6, 11)
+    let p = Trivector { e123: 1., e032: 1., e013: 2., e021: 3. };
+    let q = Trivector { e123: 1., e032: 4., e013: 6., e021: 11. };
+    let line = p.join(q);
+
+    // in the rigid convention p ∧ q has the direction v = q - p in
+    // e41, e42, e43 & the moment m = p × q in e23, e31, e12
+    // [e41, e42, e43, e23, e31, e12]
+    let result = line.to_convention(&RIGID);
+    let expected = [
+      3., 4., 8.,
+      2.*11. - 3.*6., 3.*4. - 1.*11., 1.*6. - 2.*4.,
+    ];
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+}
@@ -0,0 +1,37 @@
+use ::core::fmt::{Display, Formatter};
+
+/// An error from an operation on degenerate geometry
+///
+/// Returned by the `try_` variants of operations which would otherwise divide
+/// by zero, & silently produce infinities or NaNs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EgaError {
+  /// The value has a norm of zero, so can't be normalised
+  ZeroNorm,
+  /// The value has no inverse
+  NotInvertible,
+  /// The elements are parallel, so they meet at infinity, or are coincident
+  Parallel,
+  /// An element lies at infinity, where a Euclidean element is needed
+  IdealResult,
+  /// An element is Euclidean, where an ideal element is needed
+  NotIdeal,
+  /// The value isn't normalised, where a normalised value is needed
+  NotUnit,
+}
+
+impl Display for EgaError {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      EgaError::ZeroNorm => fmt.write_str("value has a norm of zero"),
+      EgaError::NotInvertible => fmt.write_str("value is not invertible"),
+      EgaError::Parallel => fmt.write_str("elements are parallel"),
+      EgaError::IdealResult => fmt.write_str("element lies at infinity"),
+      EgaError::NotIdeal => fmt.write_str("element is not ideal"),
+      EgaError::NotUnit => fmt.write_str("value is not normalised"),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EgaError {}
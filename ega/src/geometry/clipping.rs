@@ -0,0 +1,470 @@
+//! Clipping polygons by planes, & slicing meshes
+//!
+//! The side of a plane a unit point lies on is the sign of their meet, & an
+//! edge crossing the plane meets it at the intersection. The part of a
+//! polygon which is kept is in front of each plane, on the side its normal
+//! points towards; points on the plane count as in front.
+//!
+//! Slicing a mesh instead treats vertices on the plane as though the plane
+//! were moved very slightly towards negative x, or negative y or z if it's
+//! parallel to those axes. This doesn't depend on which way the plane faces,
+//! so a plane through a face of a mesh slices it the same way with either
+//! normal.
+
+use std::collections::HashMap;
+use std::vec::Vec;
+
+use super::{Point, Polygon, Triangle};
+use crate::*;
+
+/// A connected sequence of points
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polyline {
+  /// The points, without the first repeated at the end of a closed polyline
+  pub points: Vec<Point>,
+  /// Whether the last point connects back to the first
+  pub closed: bool,
+}
+
+/// The part of the polygon in front of the plane, using Sutherland–Hodgman
+/// clipping
+///
+/// Concave polygons may be clipped into several parts, which are returned as
+/// one polygon, joined along the plane by edges with no area. Returns `None`
+/// when no area remains.
+#[inline]
+pub fn clip_polygon(
+  polygon: &Polygon,
+  plane: impl Into<Vector>,
+) -> Option<Polygon> {
+  clip_polygon_to_planes(polygon, [plane.into()])
+}
+
+/// The part of the polygon in front of every plane, e.g. within a frustum,
+/// or another convex region bounded by planes facing inwards
+///
+/// Returns `None` when no area remains.
+#[inline]
+pub fn clip_polygon_to_planes(
+  polygon: &Polygon,
+  planes: impl IntoIterator<Item = Vector>,
+) -> Option<Polygon> {
+  let mut vertices: Vec<Trivector> =
+    polygon.vertices().iter().map(|p| p.0).collect();
+  for plane in planes {
+    vertices = clip_vertices(&vertices, plane);
+  }
+  Polygon::new(vertices.into_iter().map(Point)).ok()
+}
+
+/// The polylines along which the plane cuts the triangles of a mesh
+///
+/// The triangles should share their vertices exactly, & be oriented
+/// consistently, so that the segments cut from neighbouring triangles join.
+/// Each polyline then runs anticlockwise around the part of the mesh in front
+/// of the plane, seen from the front. Holes in the mesh give open polylines.
+///
+/// Where several segments start at the same point, e.g. at a vertex where
+/// the mesh passes through the plane more than once, or at an edge shared by
+/// more than two triangles, they're linked in the order of the triangles.
+#[inline]
+pub fn slice_mesh(
+  triangles: &[Triangle],
+  plane: impl Into<Vector>,
+) -> Vec<Polyline> {
+  let plane = plane.into();
+  // the segments, from the point at which each leaves the front of the
+  // plane to the point at which it enters
+  let mut segments: HashMap<Key, Vec<Key>> = HashMap::new();
+  let mut points: HashMap<Key, Trivector> = HashMap::new();
+  let on_plane_in_front = on_plane_in_front(plane);
+  for triangle in triangles {
+    let vertices = triangle.vertices().map(|p| p.0);
+    let sides = vertices.map(|v| plane.meet(v).e0123);
+    let front =
+      sides.map(|side| side > 0. || (side == 0. && on_plane_in_front));
+    let mut crossing = |i: usize| {
+      let j = (i + 1) % 3;
+      // an edge from a vertex on the plane crosses it at that vertex
+      let key = if sides[i] == 0. {
+        Key::Vertex(vertex_key(vertices[i]))
+      } else if sides[j] == 0. {
+        Key::Vertex(vertex_key(vertices[j]))
+      } else {
+        Key::Edge(edge_key(vertices[i], vertices[j]))
+      };
+      points
+        .entry(key)
+        .or_insert_with(|| crossing_point(key, plane));
+      (front[i], key)
+    };
+    let mut leaving = None;
+    let mut entering = None;
+    for i in 0..3 {
+      if front[i] != front[(i + 1) % 3] {
+        match crossing(i) {
+          (true, key) => leaving = Some(key),
+          (false, key) => entering = Some(key),
+        }
+      }
+    }
+    if let (Some(leaving), Some(entering)) = (leaving, entering) {
+      // triangles touching the plane at a vertex give no segment
+      if leaving != entering {
+        segments.entry(leaving).or_default().push(entering);
+      }
+    }
+  }
+  link(segments, &points)
+}
+
+/// One pass of Sutherland–Hodgman clipping, keeping the vertices in front of
+/// the plane
+#[inline]
+fn clip_vertices(vertices: &[Trivector], plane: Vector) -> Vec<Trivector> {
+  let mut clipped = Vec::with_capacity(vertices.len() + 1);
+  let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+  for (&a, &b) in edges {
+    let (a_side, b_side) = (plane.meet(a).e0123, plane.meet(b).e0123);
+    if a_side >= 0. {
+      clipped.push(a);
+    }
+    // vertices on the plane are kept, rather than cut again
+    if (a_side > 0. && b_side < 0.) || (a_side < 0. && b_side > 0.) {
+      clipped.push(unitize(a.join(b).meet(plane)));
+    }
+  }
+  clipped
+}
+
+/// Whether points on the plane are treated as in front when slicing, as
+/// though it were moved slightly towards negative x, y or z
+#[inline]
+fn on_plane_in_front(plane: Vector) -> bool {
+  let normal = [plane.e1, plane.e2, plane.e3];
+  normal
+    .into_iter()
+    .find(|&x| x != 0.)
+    .is_some_and(|x| x > 0.)
+}
+
+#[inline]
+fn unitize(point: Trivector) -> Trivector {
+  point * (1. / point.e123)
+}
+
+/// A point at which a segment starts or ends
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Key {
+  /// A vertex on the plane
+  Vertex(VertexKey),
+  /// The point at which an edge crosses the plane
+  Edge(EdgeKey),
+}
+
+/// A vertex of the mesh, identified by the bits of its coordinates
+type VertexKey = [u32; 3];
+
+/// An edge of the mesh, identified by the keys of its vertices, in order
+type EdgeKey = (VertexKey, VertexKey);
+
+#[inline]
+fn vertex_key(v: Trivector) -> VertexKey {
+  // `+ 0.` makes negative zero positive
+  [v.e032, v.e013, v.e021].map(|x| (x + 0.).to_bits())
+}
+
+#[inline]
+fn edge_key(a: Trivector, b: Trivector) -> EdgeKey {
+  let (a, b) = (vertex_key(a), vertex_key(b));
+  if a <= b {
+    (a, b)
+  } else {
+    (b, a)
+  }
+}
+
+/// The point at which a segment starts or ends, calculated from its key, so
+/// that it's identical for each triangle sharing the edge
+#[inline]
+fn crossing_point(key: Key, plane: Vector) -> Trivector {
+  let point = |[x, y, z]: VertexKey| {
+    Trivector::from(Point::new(
+      f32::from_bits(x),
+      f32::from_bits(y),
+      f32::from_bits(z),
+    ))
+  };
+  match key {
+    Key::Vertex(v) => point(v),
+    Key::Edge((a, b)) => unitize(point(a).join(point(b)).meet(plane)),
+  }
+}
+
+/// Link the segments, from one point to another, into polylines
+#[inline]
+fn link(
+  mut segments: HashMap<Key, Vec<Key>>,
+  points: &HashMap<Key, Trivector>,
+) -> Vec<Polyline> {
+  let mut polylines = Vec::new();
+  // start open polylines at the points which more segments leave than enter
+  let mut balance: HashMap<Key, isize> = HashMap::new();
+  for (start, ends) in &segments {
+    *balance.entry(*start).or_default() += ends.len() as isize;
+    for end in ends {
+      *balance.entry(*end).or_default() -= 1;
+    }
+  }
+  let mut starts: Vec<Key> = balance
+    .into_iter()
+    .filter(|&(_, count)| count > 0)
+    .map(|(key, _)| key)
+    .collect();
+  starts.sort_unstable();
+  let mut cycles: Vec<Key> = segments.keys().copied().collect();
+  cycles.sort_unstable();
+  for start in starts.into_iter().chain(cycles) {
+    while let Some(mut next) = take_segment(&mut segments, start) {
+      let mut keys = Vec::from([start]);
+      while next != start {
+        let Some(after) = take_segment(&mut segments, next) else {
+          break;
+        };
+        keys.push(next);
+        next = after;
+      }
+      let closed = next == start;
+      if !closed {
+        keys.push(next);
+      }
+      polylines.push(Polyline {
+        points: keys.iter().map(|key| Point(points[key])).collect(),
+        closed,
+      });
+    }
+  }
+  polylines
+}
+
+/// Remove a segment starting at `start`, returning its end
+#[inline]
+fn take_segment(
+  segments: &mut HashMap<Key, Vec<Key>>,
+  start: Key,
+) -> Option<Key> {
+  let ends = segments.get_mut(&start)?;
+  let end = ends.remove(0);
+  if ends.is_empty() {
+    segments.remove(&start);
+  }
+  Some(end)
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::geometry::{Direction, Plane};
+  use ::approx::assert_relative_eq;
+
+  fn polygon(vertices: &[[f32; 2]]) -> Polygon {
+    Polygon::new(vertices.iter().map(|&[x, y]| Point::new(x, y, 0.))).unwrap()
+  }
+
+  fn coordinates(polygon: &Polygon) -> Vec<[f32; 3]> {
+    polygon.vertices().iter().map(|p| p.coordinates()).collect()
+  }
+
+  #[test]
+  fn clip_square() {
+    let square = polygon(&[[0., 0.], [2., 0.], [2., 2.], [0., 2.]]);
+    // keep x <= 1
+    let clipped = clip_polygon(&square, Plane::new(-1., 0., 0., 1.)).unwrap();
+    assert_eq!(dbg!(coordinates(&clipped)), [[0., 0., 0.], [1., 0., 0.], [1., 2., 0.], [0., 2., 0.]]);
+    assert_relative_eq!(clipped.area(), 2.);
+    assert_eq!(clipped.normal(), square.normal());
+    // entirely in front, entirely behind, & behind but touching at an edge
+    assert_eq!(clip_polygon(&square, Plane::new(1., 0., 0., 1.)), Some(square.clone()));
+    assert_eq!(clip_polygon(&square, Plane::new(1., 0., 0., -3.)), None);
+    assert_eq!(clip_polygon(&square, Plane::new(-1., 0., 0., 0.)), None);
+    // a diagonal cut leaves a triangle
+    let clipped = clip_polygon(&square, Plane::new(-1., -1., 0., 2.)).unwrap();
+    assert_eq!(coordinates(&clipped), [[0., 0., 0.], [2., 0., 0.], [0., 2., 0.]]);
+  }
+
+  #[test]
+  fn clip_concave() {
+    // a U shape, whose arms are cut off by y <= 1
+    let u = polygon(&[[0., 0.], [3., 0.], [3., 3.], [2., 3.], [2., 1.], [1., 1.], [1., 3.], [0., 3.]]);
+    let clipped = clip_polygon(&u, Plane::new(0., -1., 0., 1.)).unwrap();
+    assert_relative_eq!(clipped.area(), 3.);
+    // cutting off the base leaves both arms, joined along the plane
+    let clipped = clip_polygon(&u, Plane::new(0., 1., 0., -2.)).unwrap();
+    assert_relative_eq!(clipped.area(), 2.);
+    assert_eq!(dbg!(coordinates(&clipped)).len(), 8);
+  }
+
+  #[test]
+  fn clip_to_region() {
+    let square = polygon(&[[-5., -5.], [5., -5.], [5., 5.], [-5., 5.]]);
+    // the region |x| <= 1, |y| <= 2, as inward facing planes
+    let planes = [
+      Plane::new(1., 0., 0., 1.), Plane::new(-1., 0., 0., 1.),
+      Plane::new(0., 1., 0., 2.), Plane::new(0., -1., 0., 2.),
+    ].map(Vector::from);
+    let clipped = clip_polygon_to_planes(&square, planes).unwrap();
+    assert_relative_eq!(clipped.area(), 8.);
+    assert_eq!(clipped.vertices().len(), 4);
+    assert_relative_eq!(Trivector::from(clipped.centroid()), Point::ORIGIN.into());
+    // a region it lies outside of
+    let planes = planes.into_iter().chain([Plane::new(0., 0., 1., -1.).into()]);
+    assert_eq!(clip_polygon_to_planes(&square, planes), None);
+  }
+
+  fn mesh(quads: &[[[f32; 3]; 4]]) -> Vec<Triangle> {
+    quads.iter().flat_map(|quad| {
+      let [a, b, c, d] = quad.map(|[x, y, z]| Point::new(x, y, z));
+      [Triangle::new(a, b, c).unwrap(), Triangle::new(a, c, d).unwrap()]
+    }).collect()
+  }
+
+  /// The unit cube, offset by `x`, with outward facing triangles
+  fn cube(x: f32) -> Vec<Triangle> {
+    let quads = [
+      [[0., 0., 0.], [0., 1., 0.], [1., 1., 0.], [1., 0., 0.]],
+      [[0., 0., 1.], [1., 0., 1.], [1., 1., 1.], [0., 1., 1.]],
+      [[0., 0., 0.], [1., 0., 0.], [1., 0., 1.], [0., 0., 1.]],
+      [[0., 1., 0.], [0., 1., 1.], [1., 1., 1.], [1., 1., 0.]],
+      [[0., 0., 0.], [0., 0., 1.], [0., 1., 1.], [0., 1., 0.]],
+      [[1., 0., 0.], [1., 1., 0.], [1., 1., 1.], [1., 0., 1.]],
+    ].map(|quad| quad.map(|[a, b, c]| [a + x, b, c]));
+    mesh(&quads)
+  }
+
+  #[test]
+  fn slice_cube() {
+    let mesh = cube(0.);
+    for triangle in &mesh {
+      // outward facing
+      let [x, y, z] = triangle.normal().components();
+      let [cx, cy, cz] = triangle.centroid().coordinates();
+      assert!(x * (cx - 0.5) + y * (cy - 0.5) + z * (cz - 0.5) > 0.);
+    }
+    let polylines = slice_mesh(&mesh, Plane::new(0., 0., 1., -0.5));
+    assert_eq!(dbg!(&polylines).len(), 1);
+    let polyline = &polylines[0];
+    assert!(polyline.closed);
+    // the corners, & the diagonals of the sides
+    assert_eq!(polyline.points.len(), 8);
+    assert!(polyline.points.iter().all(|p| p.coordinates()[2] == 0.5));
+    let section = Polygon::new(polyline.points.iter().copied()).unwrap();
+    assert_relative_eq!(section.area(), 1.);
+    // anticlockwise around the part above the plane, seen from above
+    assert_eq!(section.normal(), Direction::new(0., 0., 1.));
+  }
+
+  #[test]
+  fn slice_through_vertices() {
+    // an octahedron, sliced through its four equatorial vertices
+    let [px, nx, py, ny, pz, nz] = [
+      [1., 0., 0.], [-1., 0., 0.], [0., 1., 0.], [0., -1., 0.], [0., 0., 1.], [0., 0., -1.],
+    ].map(|[x, y, z]| Point::new(x, y, z));
+    let mesh: Vec<Triangle> = [
+      [px, py, pz], [py, nx, pz], [nx, ny, pz], [ny, px, pz],
+      [py, px, nz], [nx, py, nz], [ny, nx, nz], [px, ny, nz],
+    ].iter().map(|&[a, b, c]| Triangle::new(a, b, c).unwrap()).collect();
+    let polylines = slice_mesh(&mesh, Plane::new(0., 0., 1., 0.));
+    assert_eq!(dbg!(&polylines).len(), 1);
+    assert!(polylines[0].closed);
+    let section = Polygon::new(polylines[0].points.iter().copied()).unwrap();
+    assert_relative_eq!(section.area(), 2.);
+    assert_eq!(section.normal(), Direction::new(0., 0., 1.));
+    // missing the mesh entirely
+    assert_eq!(slice_mesh(&mesh, Plane::new(0., 0., 1., -2.)), []);
+  }
+
+  #[test]
+  fn slice_through_face() {
+    // the plane of the top face, facing either way
+    let mesh = cube(0.);
+    for (plane, normal) in [
+      (Plane::new(0., 0., 1., -1.), Direction::new(0., 0., 1.)),
+      (Plane::new(0., 0., -1., 1.), Direction::new(0., 0., -1.)),
+    ] {
+      let polylines = slice_mesh(&mesh, plane);
+      assert_eq!(dbg!(&polylines).len(), 1);
+      assert!(polylines[0].closed);
+      // just the corners, without repeats
+      let mut corners: Vec<[f32; 3]> = polylines[0].points.iter().map(|p| p.coordinates()).collect();
+      corners.sort_by(|a, b| a.partial_cmp(b).unwrap());
+      assert_eq!(corners, [[0., 0., 1.], [0., 1., 1.], [1., 0., 1.], [1., 1., 1.]]);
+      let section = Polygon::new(polylines[0].points.iter().copied()).unwrap();
+      assert_relative_eq!(section.area(), 1.);
+      assert_eq!(section.normal(), normal);
+    }
+    // the plane of the bottom face misses, the same either way
+    assert_eq!(slice_mesh(&mesh, Plane::new(0., 0., 1., 0.)), []);
+    assert_eq!(slice_mesh(&mesh, Plane::new(0., 0., -1., 0.)), []);
+  }
+
+  /// A tetrahedron with outward facing triangles
+  fn tetrahedron(vertices: [[f32; 3]; 4]) -> Vec<Triangle> {
+    let vertices = vertices.map(|[x, y, z]| Point::new(x, y, z));
+    (0..4).map(|i| {
+      let [a, b, c] = [1, 2, 3].map(|j| vertices[(i + j) % 4]);
+      let triangle = Triangle::new(a, b, c).unwrap();
+      // facing away from the opposite vertex
+      if triangle.plane().0.meet(vertices[i].0).e0123 > 0. {
+        Triangle::new(a, c, b).unwrap()
+      } else {
+        triangle
+      }
+    }).collect()
+  }
+
+  #[test]
+  fn slice_touching_vertex() {
+    // two tetrahedra meeting at the origin
+    let mesh: Vec<Triangle> = [1., -1.].into_iter().flat_map(|s| tetrahedron(
+      [[0., 0., 0.], [2., -1., -1.], [2., 1., -1.], [2., 0., 1.]].map(|v| v.map(|x| s * x)),
+    )).collect();
+    // a plane touching both at the origin
+    assert_eq!(slice_mesh(&mesh, Plane::new(1., 0., 0., 0.)), []);
+    assert_eq!(slice_mesh(&mesh, Plane::new(-1., 0., 0., 0.)), []);
+    // a plane cutting both through the origin, which two segments start at
+    let polylines = slice_mesh(&mesh, Plane::new(0., 0., 1., 0.));
+    assert_eq!(dbg!(&polylines).len(), 2);
+    for polyline in &polylines {
+      assert!(polyline.closed);
+      assert_eq!(polyline.points.len(), 3);
+      let section = Polygon::new(polyline.points.iter().copied()).unwrap();
+      assert_relative_eq!(section.area(), 1.);
+      assert_eq!(section.normal(), Direction::new(0., 0., 1.));
+    }
+  }
+
+  #[test]
+  fn slice_several() {
+    // two cubes give two loops
+    let mesh: Vec<Triangle> = cube(0.).into_iter().chain(cube(3.)).collect();
+    let polylines = slice_mesh(&mesh, Plane::new(0., 0., -1., 0.25));
+    assert_eq!(polylines.len(), 2);
+    for polyline in &polylines {
+      assert!(polyline.closed);
+      let section = Polygon::new(polyline.points.iter().copied()).unwrap();
+      assert_relative_eq!(section.area(), 1.);
+      // the part in front of the plane is now below it
+      assert_eq!(section.normal(), Direction::new(0., 0., -1.));
+    }
+    // a cube missing a side gives an open polyline
+    let mut mesh = cube(0.);
+    mesh.drain(4..6);
+    let polylines = slice_mesh(&mesh, Plane::new(0., 0., 1., -0.5));
+    assert_eq!(polylines.len(), 1);
+    assert!(!polylines[0].closed);
+    assert_eq!(polylines[0].points.len(), 7);
+    let ends = [polylines[0].points[0], polylines[0].points[6]].map(|p| p.coordinates());
+    assert!(ends.iter().all(|[_, y, _]| *y == 0.));
+  }
+}
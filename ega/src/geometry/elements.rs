@@ -0,0 +1,359 @@
+use crate::*;
+
+/// The plane at infinity
+const PLANE_AT_INFINITY: Vector = Vector {
+  e0: 1.,
+  e1: 0.,
+  e2: 0.,
+  e3: 0.,
+};
+
+/// A finite point, a [`Trivector`] with a non-zero weight
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Point(pub(super) Trivector);
+
+/// A direction, or point at infinity, a [`Trivector`] with no weight
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Direction(pub(super) Trivector);
+
+/// A plane, a [`Vector`] with a non-zero weight
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Plane(pub(super) Vector);
+
+/// A line through finite points, a [`Bivector`] with a non-zero weight
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Line(pub(super) Bivector);
+
+/// A line at infinity, a [`Bivector`] with no weight
+///
+/// The line at infinity of a plane, along which all of the planes parallel to
+/// it meet.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct IdealLine(pub(super) Bivector);
+
+impl Point {
+  /// The point at `(x, y, z)`, with a weight of `1`
+  #[inline]
+  pub const fn new(x: f32, y: f32, z: f32) -> Point {
+    Point(Trivector {
+      e123: 1.,
+      e032: x,
+      e013: y,
+      e021: z,
+    })
+  }
+
+  /// The origin
+  pub const ORIGIN: Point = Point::new(0., 0., 0.);
+
+  /// The weight of the point
+  #[inline]
+  pub const fn weight(self) -> f32 {
+    self.0.e123
+  }
+
+  /// The Euclidean coordinates, `[x, y, z]`
+  #[inline]
+  pub fn coordinates(self) -> [f32; 3] {
+    let Trivector {
+      e032, e013, e021, ..
+    } = self.unitized();
+    [e032, e013, e021]
+  }
+
+  /// The point scaled to a weight of `1`
+  #[inline]
+  pub(super) fn unitized(self) -> Trivector {
+    let scale = 1. / self.0.e123;
+    Trivector {
+      e123: 1.,
+      e032: self.0.e032 * scale,
+      e013: self.0.e013 * scale,
+      e021: self.0.e021 * scale,
+    }
+  }
+}
+
+impl Direction {
+  /// The direction `(x, y, z)`
+  #[inline]
+  pub const fn new(x: f32, y: f32, z: f32) -> Direction {
+    Direction(Trivector {
+      e123: 0.,
+      e032: x,
+      e013: y,
+      e021: z,
+    })
+  }
+
+  /// The components, `[x, y, z]`
+  #[inline]
+  pub const fn components(self) -> [f32; 3] {
+    [self.0.e032, self.0.e013, self.0.e021]
+  }
+}
+
+impl Plane {
+  /// The plane `ax + by + cz + d = 0`
+  ///
+  /// The normal `(a, b, c)` must be non-zero, which is checked with a
+  /// `debug_assert`; use [`TryFrom`] with a [`Vector`] for a checked
+  /// conversion.
+  #[inline]
+  pub const fn new(a: f32, b: f32, c: f32, d: f32) -> Plane {
+    debug_assert!(
+      a != 0. || b != 0. || c != 0.,
+      "a plane needs a non-zero normal"
+    );
+    Plane(Vector {
+      e0: d,
+      e1: a,
+      e2: b,
+      e3: c,
+    })
+  }
+
+  /// The normal direction, `(a, b, c)`
+  #[inline]
+  pub const fn normal(self) -> Direction {
+    Direction::new(self.0.e1, self.0.e2, self.0.e3)
+  }
+
+  /// The line at infinity, shared by all of the planes parallel to this one
+  #[inline]
+  pub fn ideal_line(self) -> IdealLine {
+    IdealLine(self.0.meet(PLANE_AT_INFINITY))
+  }
+}
+
+impl Line {
+  /// The direction of the line, its point at infinity
+  #[inline]
+  pub fn direction(self) -> Direction {
+    Direction(self.0.meet(PLANE_AT_INFINITY))
+  }
+}
+
+// conversions
+
+macro_rules! impl_conversions {
+  ($type:ident($grade:ty): $check:ident) => {
+    impl From<$type> for $grade {
+      #[inline]
+      fn from(value: $type) -> $grade {
+        value.0
+      }
+    }
+
+    impl TryFrom<$grade> for $type {
+      type Error = EgaError;
+
+      #[inline]
+      fn try_from(value: $grade) -> Result<$type, EgaError> {
+        if value == <$grade>::ZERO {
+          return Err(EgaError::ZeroNorm);
+        }
+        $check(value.weight() == <$grade>::ZERO).map(|()| $type(value))
+      }
+    }
+  };
+}
+
+// the weight is compared directly, as its square may underflow
+impl_conversions! { Point(Trivector): euclidean }
+impl_conversions! { Direction(Trivector): ideal }
+impl_conversions! { Plane(Vector): euclidean }
+impl_conversions! { Line(Bivector): euclidean }
+impl_conversions! { IdealLine(Bivector): ideal }
+
+#[inline]
+fn euclidean(weightless: bool) -> Result<(), EgaError> {
+  match weightless {
+    true => Err(EgaError::IdealResult),
+    false => Ok(()),
+  }
+}
+
+#[inline]
+fn ideal(weightless: bool) -> Result<(), EgaError> {
+  match weightless {
+    true => Ok(()),
+    false => Err(EgaError::NotIdeal),
+  }
+}
+
+// products
+//
+// The results are checked, as they leave their type in degenerate cases, e.g.
+// the meet of two parallel planes is an ideal line, & the join of two
+// coincident points is zero.
+
+macro_rules! impl_product {
+  ($trait:ident::$fn:ident: $lhs:ident, $rhs:ident => $output:ident) => {
+    impl $trait<$rhs> for $lhs {
+      type Output = Result<$output, EgaError>;
+
+      #[inline]
+      fn $fn(self, rhs: $rhs) -> Result<$output, EgaError> {
+        $output::try_from(self.0.$fn(rhs.0))
+      }
+    }
+  };
+}
+
+impl_product! { Join::join: Point, Point => Line }
+impl_product! { Join::join: Point, Direction => Line }
+impl_product! { Join::join: Direction, Point => Line }
+impl_product! { Join::join: Direction, Direction => IdealLine }
+impl_product! { Join::join: Point, Line => Plane }
+impl_product! { Join::join: Line, Point => Plane }
+impl_product! { Join::join: Direction, Line => Plane }
+impl_product! { Join::join: Line, Direction => Plane }
+impl_product! { Join::join: Point, IdealLine => Plane }
+impl_product! { Join::join: IdealLine, Point => Plane }
+
+impl_product! { Meet::meet: Plane, Plane => Line }
+impl_product! { Meet::meet: Plane, Line => Point }
+impl_product! { Meet::meet: Line, Plane => Point }
+impl_product! { Meet::meet: Plane, IdealLine => Direction }
+impl_product! { Meet::meet: IdealLine, Plane => Direction }
+
+// affine arithmetic
+
+impl Sub<Point> for Point {
+  type Output = Direction;
+
+  /// The direction from `rhs` to `self`
+  #[inline]
+  fn sub(self, rhs: Point) -> Direction {
+    Direction(self.unitized() - rhs.unitized())
+  }
+}
+
+impl Add<Direction> for Point {
+  type Output = Point;
+
+  #[inline]
+  fn add(self, rhs: Direction) -> Point {
+    Point(self.unitized() + rhs.0)
+  }
+}
+
+impl Sub<Direction> for Point {
+  type Output = Point;
+
+  #[inline]
+  fn sub(self, rhs: Direction) -> Point {
+    Point(self.unitized() - rhs.0)
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn join_points() {
+    let line = Point::ORIGIN.join(Point::new(1., 0., 0.)).unwrap();
+    let expected = Point::ORIGIN.0.join(Trivector { e123: 1., e032: 1., e013: 0., e021: 0. });
+    assert_eq!(dbg!(Bivector::from(line)), dbg!(expected));
+
+    let plane = line.join(Point::new(0., 1., 0.)).unwrap();
+    // the plane z = 0, up to its weight
+    let Vector { e0, e1, e2, e3 } = plane.into();
+    assert_eq!((e0, e1, e2), (0., 0., 0.));
+    assert_ne!(dbg!(e3), 0.);
+  }
+
+  #[test]
+  fn meet_planes() {
+    let x = Plane::new(1., 0., 0., -1.);
+    let y = Plane::new(0., 1., 0., -2.);
+    let z = Plane::new(0., 0., 1., -3.);
+    let point = x.meet(y).unwrap().meet(z).unwrap();
+    assert_eq!(dbg!(point.coordinates()), [1., 2., 3.]);
+  }
+
+  #[test]
+  fn degenerate_products() {
+    let (a, b) = (Plane::new(0., 0., 1., -1.), Plane::new(0., 0., 2., 1.));
+    assert_eq!(dbg!(a.meet(b)), Err(EgaError::IdealResult));
+    let point = Point::new(1., 2., 3.);
+    assert_eq!(dbg!(point.join(point)), Err(EgaError::ZeroNorm));
+    assert_eq!(dbg!(point.join(Direction::new(0., 0., 0.))), Err(EgaError::ZeroNorm));
+    // a line parallel to the plane meets it at infinity
+    let line = Point::new(0., 0., 5.).join(Point::new(1., 0., 5.)).unwrap();
+    assert_eq!(dbg!(a.meet(line)), Err(EgaError::IdealResult));
+    let parallel = Direction::new(1., 0., 0.).join(Direction::new(2., 0., 0.));
+    assert_eq!(dbg!(parallel), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn directions() {
+    let line = Point::ORIGIN.join(Point::new(2., 0., 0.)).unwrap();
+    let [x, y, z] = line.direction().components();
+    assert!(dbg!(x) != 0. && y == 0. && z == 0.);
+
+    let plane = Plane::new(0., 0., 1., -3.);
+    assert_eq!(dbg!(plane.normal()), Direction::new(0., 0., 1.));
+    let ideal_line = Direction::new(1., 0., 0.).join(Direction::new(0., 1., 0.)).unwrap();
+    // the horizon of the plane, which is parallel to z = 0
+    let horizon = plane.ideal_line();
+    assert_eq!(dbg!(ideal_line.0.meet(PLANE_AT_INFINITY)), Trivector::ZERO);
+    assert_eq!(dbg!(horizon.0.meet(PLANE_AT_INFINITY)), Trivector::ZERO);
+    let Bivector { e23, e31, e12, .. } = horizon.into();
+    assert_eq!((e23, e31, e12), (0., 0., 0.));
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "non-zero normal")]
+  fn plane_without_normal() {
+    Plane::new(0., 0., 0., 1.);
+  }
+
+  #[test]
+  fn affine_arithmetic() {
+    let a = Point::new(1., 2., 3.);
+    // a weighted point, at (2, 2, 2)
+    let b = Point::try_from(Trivector { e123: 2., e032: 4., e013: 4., e021: 4. }).unwrap();
+    assert_eq!(dbg!(b - a), Direction::new(1., 0., -1.));
+    assert_eq!(dbg!(a + (b - a)), Point::new(2., 2., 2.));
+    assert_eq!(dbg!(b - (b - a)), a);
+  }
+
+  #[test]
+  fn conversions() {
+    let point = Trivector { e123: 1., e032: 1., e013: 2., e021: 3. };
+    let direction = Trivector { e123: 0., ..point };
+    assert_eq!(Point::try_from(point).map(Trivector::from), Ok(point));
+    assert_eq!(Point::try_from(direction), Err(EgaError::IdealResult));
+    assert_eq!(Point::try_from(Trivector::ZERO), Err(EgaError::ZeroNorm));
+    assert_eq!(Direction::try_from(direction).map(Trivector::from), Ok(direction));
+    assert_eq!(Direction::try_from(point), Err(EgaError::NotIdeal));
+    // weights whose squares underflow
+    let tiny = Trivector { e123: 1e-23, ..point };
+    assert_eq!(Point::try_from(tiny).map(Trivector::from), Ok(tiny));
+    assert_eq!(Direction::try_from(tiny), Err(EgaError::NotIdeal));
+    let tiny = Vector { e3: 1e-30, ..PLANE_AT_INFINITY };
+    assert_eq!(Plane::try_from(tiny).map(Vector::from), Ok(tiny));
+
+    let plane = Vector { e0: 1., e1: 1., e2: 0., e3: 0. };
+    assert_eq!(Plane::try_from(plane).map(Vector::from), Ok(plane));
+    assert_eq!(Plane::try_from(PLANE_AT_INFINITY), Err(EgaError::IdealResult));
+
+    let line = Bivector { e23: 1., e01: 1., ..Bivector::ZERO };
+    let ideal_line = Bivector { e01: 1., ..Bivector::ZERO };
+    assert_eq!(Line::try_from(line).map(Bivector::from), Ok(line));
+    assert_eq!(Line::try_from(ideal_line), Err(EgaError::IdealResult));
+    assert_eq!(IdealLine::try_from(ideal_line).map(Bivector::from), Ok(ideal_line));
+    assert_eq!(IdealLine::try_from(line), Err(EgaError::NotIdeal));
+    assert_eq!(IdealLine::try_from(Bivector::ZERO), Err(EgaError::ZeroNorm));
+  }
+}
@@ -0,0 +1,527 @@
+//! Least-squares fitting of planes, lines & points
+//!
+//! The distance from a unit point to a unit plane is their meet, & to a unit
+//! line the weight of their join, so each fit minimises the sum of the
+//! squares of these. The planes & lines through the centroid of a set of
+//! points are found from the eigenvectors of their covariance, & the points
+//! closest to sets of planes or lines by solving the normal equations with
+//! the same eigen-decomposition.
+//!
+//! The moments themselves are summed in coordinates, rather than from the
+//! algebra's inner products: the inner product of two points depends only on
+//! their weights, as `e0² = 0` removes their positions, & that of two planes
+//! only on their normals. The covariance & the normal equations are 3 × 3
+//! matrices, which no product of the grades forms; the algebra gives the
+//! distances which they minimise.
+//!
+//! The signs of fitted planes & lines are arbitrary, & each [`Fit`] records
+//! the root mean square of the distances which were minimised.
+
+use crate::*;
+
+/// A fitted element, & how closely it fits the data
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Fit<T> {
+  /// The fitted element, normalised
+  pub element: T,
+  /// The root mean square of the distances between the element & the data
+  pub residual: f32,
+}
+
+/// The ratio of the eigenvalues below which the fit is considered degenerate
+const DEGENERATE: f32 = 1e-6;
+
+/// The ratio of the spread of the points to their distance from the origin
+/// below which they're considered coincident, as the rounding of their
+/// centroid spreads them by about `f32::EPSILON` of that
+const COINCIDENT: f32 = DEGENERATE * DEGENERATE;
+
+/// The plane minimising the squared distances to the finite points
+///
+/// Fails with
+/// - [`EgaError::IdealResult`] if a point lies at infinity,
+/// - [`EgaError::NotInvertible`] if there are fewer than three points, or they
+///   are collinear, so the plane isn't unique.
+#[inline]
+pub fn fit_plane(
+  points: &[impl Copy + Into<Trivector>],
+) -> Result<Fit<Vector>, EgaError> {
+  let (centroid, covariance) = moments(points)?;
+  let (values, vectors) = eigen(covariance);
+  if values[1] <= DEGENERATE * values[2] {
+    return Err(EgaError::NotInvertible);
+  }
+  let [x, y, z] = vectors[0];
+  let plane = Vector {
+    e0: -dot(vectors[0], centroid),
+    e1: x,
+    e2: y,
+    e3: z,
+  };
+  let residual = rms(points, |point| plane.meet(unitized(point.into())).e0123);
+  Ok(Fit {
+    element: plane,
+    residual,
+  })
+}
+
+/// The line minimising the squared distances to the finite points
+///
+/// Fails with
+/// - [`EgaError::IdealResult`] if a point lies at infinity,
+/// - [`EgaError::NotInvertible`] if there are no points, or they coincide,
+///   so the line isn't unique.
+#[inline]
+pub fn fit_line(
+  points: &[impl Copy + Into<Trivector>],
+) -> Result<Fit<Bivector>, EgaError> {
+  let (centroid, covariance) = moments(points)?;
+  let (values, vectors) = eigen(covariance);
+  // the sum of the squared distances of the points from the origin
+  let magnitude =
+    values.iter().sum::<f32>() + dot(centroid, centroid) * points.len() as f32;
+  if points.is_empty() || values[2] <= COINCIDENT * magnitude {
+    return Err(EgaError::NotInvertible);
+  }
+  let [x, y, z] = centroid;
+  let [dx, dy, dz] = vectors[2];
+  let point = Trivector {
+    e123: 1.,
+    e032: x,
+    e013: y,
+    e021: z,
+  };
+  let direction = Trivector {
+    e123: 0.,
+    e032: dx,
+    e013: dy,
+    e021: dz,
+  };
+  let line = point.join(direction);
+  let residual =
+    rms(points, |point| unitized(point.into()).join(line).norm().s);
+  Ok(Fit {
+    element: line,
+    residual,
+  })
+}
+
+/// The finite point minimising the squared distances to the planes
+///
+/// Fails with
+/// - [`EgaError::ZeroNorm`] if a plane has no weight,
+/// - [`EgaError::Parallel`] if the planes are all parallel to a line, so the
+///   point isn't unique.
+#[inline]
+pub fn fit_point_to_planes(
+  planes: &[impl Copy + Into<Vector>],
+) -> Result<Fit<Trivector>, EgaError> {
+  let mut matrix = [[0.; 3]; 3];
+  let mut rhs = [0.; 3];
+  for &plane in planes {
+    let plane = plane.into().try_normalise()?;
+    // the squared distance `(n · x + d)²`
+    let normal = [plane.e1, plane.e2, plane.e3];
+    add_outer(&mut matrix, normal, normal, 1.);
+    add_scaled(&mut rhs, normal, -plane.e0);
+  }
+  let point = solve(matrix, rhs)?;
+  let residual = rms(planes, |plane| {
+    let plane = plane.into();
+    plane.meet(point).e0123 / plane.norm().s
+  });
+  Ok(Fit {
+    element: point,
+    residual,
+  })
+}
+
+/// The finite point minimising the squared distances to the lines, e.g.
+/// triangulating the point seen along several rays
+///
+/// Fails with
+/// - [`EgaError::ZeroNorm`] if a line is zero,
+/// - [`EgaError::IdealResult`] if a line lies at infinity,
+/// - [`EgaError::Parallel`] if the lines are all parallel, so the point isn't
+///   unique.
+#[inline]
+pub fn fit_point_to_lines(
+  lines: &[impl Copy + Into<Bivector>],
+) -> Result<Fit<Trivector>, EgaError> {
+  let mut matrix = [[0.; 3]; 3];
+  let mut rhs = [0.; 3];
+  for &line in lines {
+    let line = line.into();
+    let weight = line_weight(line)?;
+    let direction = [-line.e23, -line.e31, -line.e12].map(|x| x / weight);
+    // the point on the line where it meets the plane through the origin
+    // perpendicular to it
+    let [x, y, z] = direction;
+    let foot = line.meet(Vector {
+      e0: 0.,
+      e1: x,
+      e2: y,
+      e3: z,
+    });
+    let foot = [foot.e032, foot.e013, foot.e021].map(|x| x / foot.e123);
+    // the squared distance is `|(I - u uᵀ)(x - p)|²`, & `p` is perpendicular
+    // to `u`
+    for (i, row) in matrix.iter_mut().enumerate() {
+      row[i] += 1.;
+    }
+    add_outer(&mut matrix, direction, direction, -1.);
+    add_scaled(&mut rhs, foot, 1.);
+  }
+  let point = solve(matrix, rhs)?;
+  let residual = rms(lines, |line| {
+    let line = line.into();
+    point.join(line).norm().s / line.norm().s
+  });
+  Ok(Fit {
+    element: point,
+    residual,
+  })
+}
+
+/// The centroid & covariance of the unitized points
+#[inline]
+fn moments(
+  points: &[impl Copy + Into<Trivector>],
+) -> Result<([f32; 3], [[f32; 3]; 3]), EgaError> {
+  let mut centroid = [0.; 3];
+  for &point in points {
+    add_scaled(&mut centroid, coordinates(point.into())?, 1.);
+  }
+  let count = points.len() as f32;
+  let centroid = centroid.map(|x| x / count);
+  let mut covariance = [[0.; 3]; 3];
+  for &point in points {
+    let [x, y, z] = coordinates(point.into())?;
+    let offset = [x - centroid[0], y - centroid[1], z - centroid[2]];
+    add_outer(&mut covariance, offset, offset, 1.);
+  }
+  Ok((centroid, covariance))
+}
+
+#[inline]
+fn coordinates(point: Trivector) -> Result<[f32; 3], EgaError> {
+  if point.e123 == 0. {
+    return Err(EgaError::IdealResult);
+  }
+  Ok([point.e032, point.e013, point.e021].map(|x| x / point.e123))
+}
+
+#[inline]
+fn unitized(point: Trivector) -> Trivector {
+  point * (1. / point.e123)
+}
+
+#[inline]
+fn line_weight(line: Bivector) -> Result<f32, EgaError> {
+  if line == Bivector::ZERO {
+    return Err(EgaError::ZeroNorm);
+  }
+  let weight = line.norm().s;
+  if weight == 0. {
+    return Err(EgaError::IdealResult);
+  }
+  Ok(weight)
+}
+
+/// The root mean square of the distances
+#[inline]
+fn rms<T: Copy>(data: &[T], distance: impl Fn(T) -> f32) -> f32 {
+  let sum: f32 = data.iter().map(|&x| distance(x) * distance(x)).sum();
+  sqrt(sum / data.len() as f32)
+}
+
+/// The point solving the normal equations, `Ax = b`
+#[inline]
+fn solve(matrix: [[f32; 3]; 3], rhs: [f32; 3]) -> Result<Trivector, EgaError> {
+  let (values, vectors) = eigen(matrix);
+  if values[0] <= DEGENERATE * values[2] {
+    return Err(EgaError::Parallel);
+  }
+  let mut point = [0.; 3];
+  for (value, vector) in values.into_iter().zip(vectors) {
+    add_scaled(&mut point, vector, dot(vector, rhs) / value);
+  }
+  let [x, y, z] = point;
+  Ok(Trivector {
+    e123: 1.,
+    e032: x,
+    e013: y,
+    e021: z,
+  })
+}
+
+/// The eigenvalues of the symmetric matrix in ascending order, & their unit
+/// eigenvectors, found by cyclic Jacobi rotations
+#[inline]
+fn eigen(matrix: [[f32; 3]; 3]) -> ([f32; 3], [[f32; 3]; 3]) {
+  let mut a = matrix;
+  // the columns are the eigenvectors
+  let mut v = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+  for _ in 0..32 {
+    let off_diagonal =
+      a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+    let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+    if off_diagonal <= f32::EPSILON * f32::EPSILON * diagonal {
+      break;
+    }
+    for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+      if a[p][q] == 0. {
+        continue;
+      }
+      // the rotation in the pq plane which zeroes `a[p][q]`
+      let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+      let t = theta.signum() / (theta.abs() + sqrt(theta * theta + 1.));
+      let c = 1. / sqrt(t * t + 1.);
+      let s = t * c;
+      for row in a.iter_mut().chain(v.iter_mut()) {
+        let (kp, kq) = (row[p], row[q]);
+        row[p] = c * kp - s * kq;
+        row[q] = s * kp + c * kq;
+      }
+      let (rp, rq) = (a[p], a[q]);
+      for k in 0..3 {
+        a[p][k] = c * rp[k] - s * rq[k];
+        a[q][k] = s * rp[k] + c * rq[k];
+      }
+    }
+  }
+  let mut order = [0, 1, 2];
+  order.sort_unstable_by(|&i, &j| a[i][i].total_cmp(&a[j][j]));
+  let values = order.map(|i| a[i][i]);
+  let vectors = order.map(|i| [v[0][i], v[1][i], v[2][i]]);
+  (values, vectors)
+}
+
+#[inline]
+fn add_outer(matrix: &mut [[f32; 3]; 3], a: [f32; 3], b: [f32; 3], s: f32) {
+  for (row, a) in matrix.iter_mut().zip(a) {
+    add_scaled(row, b, a * s);
+  }
+}
+
+#[inline]
+fn add_scaled(sum: &mut [f32; 3], a: [f32; 3], s: f32) {
+  for (sum, a) in sum.iter_mut().zip(a) {
+    *sum += a * s;
+  }
+}
+
+#[inline]
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+  a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn sqrt(x: f32) -> f32 {
+  Scalar { s: x }.sqrt().s
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use ::approx::{assert_abs_diff_eq, assert_relative_eq};
+
+  /// A deterministic source of noise, uniform in `[-1, 1]`
+  struct Noise(u32);
+
+  impl Noise {
+    fn next(&mut self) -> f32 {
+      // xorshift32
+      self.0 ^= self.0 << 13;
+      self.0 ^= self.0 >> 17;
+      self.0 ^= self.0 << 5;
+      (self.0 as f32 / u32::MAX as f32) * 2. - 1.
+    }
+
+    fn vector(&mut self) -> [f32; 3] {
+      [self.next(), self.next(), self.next()]
+    }
+  }
+
+  fn point([x, y, z]: [f32; 3]) -> Trivector {
+    Trivector { e123: 1., e032: x, e013: y, e021: z }
+  }
+
+  fn direction([x, y, z]: [f32; 3]) -> Trivector {
+    Trivector { e123: 0., e032: x, e013: y, e021: z }
+  }
+
+  fn normalised(a: [f32; 3]) -> [f32; 3] {
+    a.map(|x| x / dot(a, a).sqrt())
+  }
+
+  /// The rms of uniform noise in `[-s, s]`
+  fn rms_noise(s: f32) -> f32 {
+    s / 3_f32.sqrt()
+  }
+
+  #[test]
+  fn eigen_decomposition() {
+    let matrix = [[4., 1., -2.], [1., 2., 0.5], [-2., 0.5, 3.]];
+    let (values, vectors) = eigen(matrix);
+    assert!(values[0] <= values[1] && values[1] <= values[2]);
+    assert_relative_eq!(values.iter().sum::<f32>(), 9., epsilon = 1e-5);
+    for (value, vector) in values.into_iter().zip(vectors) {
+      let product = matrix.map(|row| dot(row, vector));
+      assert_abs_diff_eq!(&product[..], &vector.map(|x| x * value)[..], epsilon = 1e-5);
+      assert_relative_eq!(dot(vector, vector), 1., epsilon = 1e-6);
+    }
+    assert_abs_diff_eq!(dot(vectors[0], vectors[1]), 0., epsilon = 1e-6);
+    assert_abs_diff_eq!(dot(vectors[1], vectors[2]), 0., epsilon = 1e-6);
+    // diagonal & repeated eigenvalues
+    let (values, _) = eigen([[3., 0., 0.], [0., 1., 0.], [0., 0., 3.]]);
+    assert_eq!(values, [1., 3., 3.]);
+  }
+
+  #[test]
+  fn plane() {
+    // the plane 2x - y + 2z = 3, with unit normal n
+    let normal = [2. / 3., -1. / 3., 2. / 3.];
+    let (u, v) = (normalised([1., 2., 0.]), normalised([1., 0., -1.]));
+    assert_abs_diff_eq!(dot(u, normal), 0., epsilon = 1e-6);
+    assert_abs_diff_eq!(dot(v, normal), 0., epsilon = 1e-6);
+    let mut noise = Noise(1);
+    let points: Vec<_> = (0..200).map(|_| {
+      let (a, b, c) = (noise.next() * 10., noise.next() * 10., noise.next() * 0.01);
+      point([0, 1, 2].map(|i| normal[i] + a * u[i] + b * v[i] + c * normal[i]))
+    }).collect();
+    let fit = fit_plane(&points).unwrap();
+    let sign = fit.element.e1.signum();
+    assert_abs_diff_eq!(fit.element * sign, Vector { e0: -1., e1: normal[0], e2: normal[1], e3: normal[2] }, epsilon = 1e-3);
+    assert_relative_eq!(fit.element.norm().s, 1., epsilon = 1e-6);
+    assert_relative_eq!(fit.residual, rms_noise(0.01), max_relative = 0.2);
+    // the weights of the points don't matter
+    let weighted: Vec<_> = points.iter().map(|&p| p * -3.).collect();
+    assert_abs_diff_eq!(fit_plane(&weighted).unwrap().element, fit.element, epsilon = 1e-5);
+  }
+
+  #[test]
+  fn degenerate_plane() {
+    let collinear = [[0., 0., 0.], [1., 1., 1.], [2., 2., 2.], [5., 5., 5.]].map(point);
+    assert_eq!(fit_plane(&collinear), Err(EgaError::NotInvertible));
+    assert_eq!(fit_plane(&collinear[..2]), Err(EgaError::NotInvertible));
+    assert_eq!(fit_plane(&[] as &[Trivector]), Err(EgaError::NotInvertible));
+    let ideal = [point([0., 0., 0.]), point([1., 0., 0.]), direction([0., 1., 0.])];
+    assert_eq!(fit_plane(&ideal), Err(EgaError::IdealResult));
+    // three points fit exactly
+    let fit = fit_plane(&[[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]].map(point)).unwrap();
+    assert_abs_diff_eq!(fit.residual, 0., epsilon = 1e-6);
+    assert_abs_diff_eq!(fit.element.meet(point([1., 1., 1.])).e0123.abs(), 2. / 3_f32.sqrt(), epsilon = 1e-6);
+  }
+
+  #[test]
+  fn line() {
+    let origin = [1., -2., 3.];
+    let along = normalised([1., 2., -2.]);
+    let mut noise = Noise(7);
+    let points: Vec<_> = (0..200).map(|_| {
+      let t = noise.next() * 20.;
+      let offset = noise.vector();
+      point([0, 1, 2].map(|i| origin[i] + t * along[i] + offset[i] * 0.01))
+    }).collect();
+    let fit = fit_line(&points).unwrap();
+    let line = fit.element;
+    assert_relative_eq!(line.norm().s, 1., epsilon = 1e-5);
+    let fitted = [-line.e23, -line.e31, -line.e12];
+    assert_relative_eq!(dot(fitted, along).abs(), 1., epsilon = 1e-5);
+    // the original line passes close to it
+    assert_abs_diff_eq!(point(origin).join(line).norm().s, 0., epsilon = 2e-3);
+    // the noise is spread over two perpendicular directions
+    assert_relative_eq!(fit.residual, rms_noise(0.01) * 2_f32.sqrt(), max_relative = 0.2);
+    let line = fit_line(&[[1., 1., 1.], [1., 1., 4.]].map(point)).unwrap();
+    assert_abs_diff_eq!(line.residual, 0.);
+  }
+
+  #[test]
+  fn degenerate_line() {
+    assert_eq!(fit_line(&[point([1., 2., 3.]); 3]), Err(EgaError::NotInvertible));
+    // the centroid isn't representable, so the points are spread by rounding
+    assert_eq!(fit_line(&[point([0.1, 0.1, 0.1]); 3]), Err(EgaError::NotInvertible));
+    assert_eq!(fit_line(&[point([1e4, -0.3, 7.1]); 7]), Err(EgaError::NotInvertible));
+    // close together, but not coincident
+    assert!(fit_line(&[point([0.1, 0.1, 0.1]), point([0.1, 0.1, 0.1001])]).is_ok());
+    assert_eq!(fit_line(&[] as &[Trivector]), Err(EgaError::NotInvertible));
+    assert_eq!(fit_line(&[point([1., 2., 3.]), direction([1., 0., 0.])]), Err(EgaError::IdealResult));
+  }
+
+  #[test]
+  fn point_to_planes() {
+    let target = [1., 2., 3.];
+    let mut noise = Noise(3);
+    let planes: Vec<_> = (0..50).map(|_| {
+      let normal = normalised(noise.vector());
+      // weighted planes, passing near the target
+      let weight = 1. + noise.next().abs();
+      let offset = -dot(normal, target) + noise.next() * 0.01;
+      Vector { e0: offset, e1: normal[0], e2: normal[1], e3: normal[2] } * weight
+    }).collect();
+    let fit = fit_point_to_planes(&planes).unwrap();
+    assert_eq!(fit.element.e123, 1.);
+    assert_abs_diff_eq!(fit.element, point(target), epsilon = 5e-3);
+    assert!(fit.residual < rms_noise(0.01) * 1.2, "{}", fit.residual);
+    // planes through a common point fit exactly
+    let planes = [
+      Vector { e0: -1., e1: 1., e2: 0., e3: 0. },
+      Vector { e0: -2., e1: 0., e2: 1., e3: 0. },
+      Vector { e0: -6., e1: 0., e2: 0., e3: 2. },
+    ];
+    let fit = fit_point_to_planes(&planes).unwrap();
+    assert_abs_diff_eq!(fit.element, point(target), epsilon = 1e-6);
+    assert_abs_diff_eq!(fit.residual, 0., epsilon = 1e-6);
+  }
+
+  #[test]
+  fn degenerate_point_to_planes() {
+    // the planes all contain lines parallel to the z axis
+    let planes = [
+      Vector { e0: -1., e1: 1., e2: 0., e3: 0. },
+      Vector { e0: -2., e1: 0., e2: 1., e3: 0. },
+      Vector { e0: 0., e1: 1., e2: 1., e3: 0. },
+    ];
+    assert_eq!(fit_point_to_planes(&planes), Err(EgaError::Parallel));
+    assert_eq!(fit_point_to_planes(&[Vector { e0: 1., e1: 0., e2: 0., e3: 0. }]), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn point_to_lines() {
+    // triangulate a target seen from cameras around it, with noisy rays
+    let target = [-1., 4., 2.];
+    let mut noise = Noise(11);
+    let lines: Vec<_> = (0..20).map(|_| {
+      let camera = noise.vector().map(|x| x * 10.);
+      let ray = [0, 1, 2].map(|i| target[i] - camera[i] + noise.next() * 0.01);
+      point(camera).join(direction(ray))
+    }).collect();
+    let fit = fit_point_to_lines(&lines).unwrap();
+    assert_eq!(fit.element.e123, 1.);
+    assert_abs_diff_eq!(fit.element, point(target), epsilon = 1e-2);
+    assert!(fit.residual < 1e-2, "{}", fit.residual);
+    // intersecting lines fit exactly
+    let lines = [
+      point(target).join(direction([1., 0., 0.])),
+      point(target).join(direction([0., 2., 1.])),
+      point([0., 0., 0.]).join(point(target)),
+    ];
+    let fit = fit_point_to_lines(&lines).unwrap();
+    assert_abs_diff_eq!(fit.element, point(target), epsilon = 1e-5);
+    assert_abs_diff_eq!(fit.residual, 0., epsilon = 1e-5);
+  }
+
+  #[test]
+  fn degenerate_point_to_lines() {
+    let parallel = [
+      point([0., 0., 0.]).join(direction([0., 0., 1.])),
+      point([1., 0., 0.]).join(direction([0., 0., -2.])),
+    ];
+    assert_eq!(fit_point_to_lines(&parallel), Err(EgaError::Parallel));
+    assert_eq!(fit_point_to_lines(&[Bivector::ZERO]), Err(EgaError::ZeroNorm));
+    let ideal = direction([1., 0., 0.]).join(direction([0., 1., 0.]));
+    assert_eq!(fit_point_to_lines(&[ideal]), Err(EgaError::IdealResult));
+  }
+}
@@ -0,0 +1,346 @@
+//! Closest points, distances & angles between pairs of lines
+//!
+//! Each function takes Euclidean lines, & fails with
+//! - [`EgaError::ZeroNorm`] if either line is zero,
+//! - [`EgaError::IdealResult`] if either line lies at infinity.
+//!
+//! The common perpendicular of two lines is their commutator, whose
+//! direction is the cross product of theirs. Intersecting lines are at a
+//! distance of zero, with both closest points at their intersection.
+
+#[cfg(feature = "libm")]
+use libm::Libm;
+
+use crate::*;
+
+/// The plane at infinity, `e0`
+const PLANE_AT_INFINITY: Vector = Vector {
+  e0: 1.,
+  e1: 0.,
+  e2: 0.,
+  e3: 0.,
+};
+
+/// The sine of the angle between two lines below which they're treated as
+/// parallel, where the rounding of their directions would dominate the
+/// closest points
+pub const PARALLEL: f32 = 1e-5;
+
+/// The closest points on the lines `a` & `b`, with unit weight
+///
+/// Fails with [`EgaError::Parallel`] if the lines are parallel, to within
+/// [`PARALLEL`], so that every point is a closest point.
+#[inline]
+pub fn closest_points(
+  a: impl Into<Bivector>,
+  b: impl Into<Bivector>,
+) -> Result<(Trivector, Trivector), EgaError> {
+  let (a, b) = (a.into(), b.into());
+  let direction = perpendicular_direction(a, b)?;
+  // each closest point is where the line meets the plane containing the
+  // other line & the common perpendicular
+  let on_a = a.meet(b.join(direction));
+  let on_b = b.meet(a.join(direction));
+  Ok((on_a * (1. / on_a.e123), on_b * (1. / on_b.e123)))
+}
+
+/// The unit line perpendicular to both `a` & `b`, & meeting both, directed
+/// along the cross product of their directions
+///
+/// Fails with [`EgaError::Parallel`] if the lines are parallel, so that the
+/// perpendicular isn't unique.
+#[inline]
+pub fn common_perpendicular(
+  a: impl Into<Bivector>,
+  b: impl Into<Bivector>,
+) -> Result<Bivector, EgaError> {
+  let (a, b) = (a.into(), b.into());
+  let direction = perpendicular_direction(a, b)?;
+  let on_a = a.meet(b.join(direction));
+  // joining the closest points would fail for intersecting lines
+  let line = on_a.join(direction);
+  Ok(line * (1. / line.norm().s))
+}
+
+/// The distance from `a` to `b`, measured along their common perpendicular
+///
+/// Fails with [`EgaError::Parallel`] if the lines are parallel, so that the
+/// direction of the perpendicular, & so the sign, is undefined.
+#[inline]
+pub fn signed_distance(
+  a: impl Into<Bivector>,
+  b: impl Into<Bivector>,
+) -> Result<f32, EgaError> {
+  let (a, b) = (a.into(), b.into());
+  let direction = perpendicular_direction(a, b)?;
+  // the meet of unit lines is the distance times the sine of the angle
+  // between them, & `|a × b| = |a| |b| sin θ`
+  Ok(-a.meet(b).e0123 / direction.ideal_norm().e0123)
+}
+
+/// The distance between the lines `a` & `b`, including parallel lines
+#[inline]
+pub fn distance(
+  a: impl Into<Bivector>,
+  b: impl Into<Bivector>,
+) -> Result<f32, EgaError> {
+  let (a, b) = (a.into(), b.into());
+  match signed_distance(a, b) {
+    Ok(distance) => Ok(distance.abs()),
+    Err(EgaError::Parallel) => {
+      // a point on `a`, where it meets the plane through the origin
+      // perpendicular to it
+      let [x, y, z] = [-a.e23, -a.e31, -a.e12];
+      let point = a.meet(Vector {
+        e0: 0.,
+        e1: x,
+        e2: y,
+        e3: z,
+      });
+      // the plane through a unit point & a unit line has a weight equal to
+      // the distance between them
+      Ok(point.join(b).norm().s / (point.e123 * b.norm().s).abs())
+    },
+    Err(error) => Err(error),
+  }
+}
+
+/// The angle between the directions of the lines `a` & `b`, in `[0, π]`
+#[inline]
+pub fn angle(
+  a: impl Into<Bivector>,
+  b: impl Into<Bivector>,
+) -> Result<f32, EgaError> {
+  let (a, b) = (a.into(), b.into());
+  check_euclidean(a)?;
+  check_euclidean(b)?;
+  let cos = a.e23 * b.e23 + a.e31 * b.e31 + a.e12 * b.e12;
+  let sin = commutator(a, b).meet(PLANE_AT_INFINITY).ideal_norm().e0123;
+  #[cfg(not(feature = "libm"))]
+  let angle = sin.atan2(cos);
+  #[cfg(feature = "libm")]
+  let angle = Libm::<f32>::atan2(sin, cos);
+  Ok(angle)
+}
+
+#[inline]
+fn check_euclidean(line: Bivector) -> Result<(), EgaError> {
+  if line == Bivector::ZERO {
+    Err(EgaError::ZeroNorm)
+  } else if line.norm_squared().s == 0. {
+    Err(EgaError::IdealResult)
+  } else {
+    Ok(())
+  }
+}
+
+/// The commutator `(ab - ba) / 2`, perpendicular to both `a` & `b`, & whose
+/// direction is the cross product of theirs
+#[inline]
+fn commutator(a: Bivector, b: Bivector) -> Bivector {
+  let commutator = a.geometric_product(b) - b.geometric_product(a);
+  commutator.grade_2() * 0.5
+}
+
+/// The ideal point in the direction of the common perpendicular
+#[inline]
+fn perpendicular_direction(
+  a: Bivector,
+  b: Bivector,
+) -> Result<Trivector, EgaError> {
+  check_euclidean(a)?;
+  check_euclidean(b)?;
+  let direction = commutator(a, b).meet(PLANE_AT_INFINITY);
+  // `|a × b| = |a| |b| sin θ`, where the norms are the weights of the lines
+  let cross_squared = direction.ideal_norm_squared().e0123;
+  let weights_squared = a.norm_squared().s * b.norm_squared().s;
+  if cross_squared <= PARALLEL * PARALLEL * weights_squared {
+    return Err(EgaError::Parallel);
+  }
+  Ok(direction)
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::geometry::{Line, Point};
+  use crate::geometry::predicates::point_on_line;
+  use ::approx::{assert_abs_diff_eq, assert_relative_eq};
+  use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+  type V = [f32; 3];
+
+  fn at(p: V, u: V, t: f64) -> [f64; 3] {
+    let [p, u] = [p, u].map(|v| v.map(f64::from));
+    [p[0] + t*u[0], p[1] + t*u[1], p[2] + t*u[2]]
+  }
+
+  fn distance_between(a: [f64; 3], b: [f64; 3]) -> f64 {
+    let d = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+    (d[0]*d[0] + d[1]*d[1] + d[2]*d[2]).sqrt()
+  }
+
+  fn length(a: V, b: V) -> f32 {
+    let d = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+    (d[0]*d[0] + d[1]*d[1] + d[2]*d[2]).sqrt()
+  }
+
+  fn cross(u: V, v: V) -> V {
+    [u[1]*v[2] - u[2]*v[1], u[2]*v[0] - u[0]*v[2], u[0]*v[1] - u[1]*v[0]]
+  }
+
+  fn dot(u: V, v: V) -> f32 {
+    u[0]*v[0] + u[1]*v[1] + u[2]*v[2]
+  }
+
+  /// Minimise the convex `f` over `[-100, 100]` by ternary search
+  fn minimise(f: impl Fn(f64) -> f64) -> f64 {
+    let (mut low, mut high) = (-100f64, 100f64);
+    for _ in 0..100 {
+      let (l, h) = (low + (high - low) / 3., high - (high - low) / 3.);
+      if f(l) < f(h) { high = h } else { low = l }
+    }
+    (low + high) / 2.
+  }
+
+  /// The closest points on the lines `p + t u` & `q + s v`, found by search
+  fn brute_force(p: V, u: V, q: V, v: V) -> (V, V) {
+    let closest_s = |t| {
+      minimise(|s| distance_between(at(p, u, t), at(q, v, s)))
+    };
+    let t = minimise(|t| {
+      distance_between(at(p, u, t), at(q, v, closest_s(t)))
+    });
+    let [a, b] = [at(p, u, t), at(q, v, closest_s(t))];
+    (a.map(|x| x as f32), b.map(|x| x as f32))
+  }
+
+  fn line(p: V, u: V) -> Bivector {
+    let q = [p[0] + u[0], p[1] + u[1], p[2] + u[2]];
+    Point::new(p[0], p[1], p[2]).join(Point::new(q[0], q[1], q[2])).unwrap().into()
+  }
+
+  fn coordinates(point: Trivector) -> V {
+    [point.e032, point.e013, point.e021]
+  }
+
+  const SKEW: [(V, V, V, V); 4] = [
+    ([0., 0., 0.], [1., 0., 0.], [3., 0., 2.], [0., 1., 0.]),
+    ([1., 2., 3.], [1., 1., 0.], [-2., 0., 1.], [0., 1., 1.]),
+    ([0., -1., 4.], [2., 1., -1.], [5., 3., 0.], [-1., 0., 3.]),
+    ([7., 0., -2.], [0., 0., 3.], [0., 1., 1.], [1., -2., 0.5]),
+  ];
+
+  #[test]
+  fn skew_lines() {
+    for (p, u, q, v) in SKEW {
+      let (a, b) = (line(p, u), line(q, v));
+      let (expected_a, expected_b) = dbg!(brute_force(p, u, q, v));
+      let (on_a, on_b) = dbg!(closest_points(a, b).unwrap());
+      assert_abs_diff_eq!(on_a.e123, 1.);
+      assert_abs_diff_eq!(on_b.e123, 1.);
+      assert_abs_diff_eq!(&coordinates(on_a)[..], &expected_a[..], epsilon = 1e-3);
+      assert_abs_diff_eq!(&coordinates(on_b)[..], &expected_b[..], epsilon = 1e-3);
+
+      let expected = length(expected_a, expected_b);
+      assert_relative_eq!(distance(a, b).unwrap(), expected, max_relative = 1e-4);
+      // positive when `b` is reached from `a` along `u × v`, which is
+      // symmetric, as swapping the lines reverses both
+      let offset = [expected_b[0] - expected_a[0], expected_b[1] - expected_a[1], expected_b[2] - expected_a[2]];
+      let sign = dot(offset, cross(u, v)).signum();
+      assert_relative_eq!(signed_distance(a, b).unwrap(), sign * expected, max_relative = 1e-4);
+      assert_relative_eq!(signed_distance(b, a).unwrap(), sign * expected, max_relative = 1e-4);
+
+      let perpendicular = common_perpendicular(a, b).unwrap();
+      assert_abs_diff_eq!(perpendicular.norm().s, 1., epsilon = 1e-6);
+      assert!(point_on_line(on_a, perpendicular, 1e-4));
+      assert!(point_on_line(on_b, perpendicular, 1e-4));
+      let direction = Line::try_from(perpendicular).unwrap().direction().components();
+      let expected = cross(u, v);
+      assert_relative_eq!(dot(direction, expected), length(expected, [0.; 3]), max_relative = 1e-4);
+    }
+  }
+
+  #[test]
+  fn weights() {
+    let (p, u, q, v) = SKEW[1];
+    let (a, b) = (line(p, u), line(q, v));
+    let (a_weighted, b_weighted) = (a * 3., b * -0.5);
+    assert_eq!(closest_points(a_weighted, b_weighted), closest_points(a, b));
+    assert_relative_eq!(distance(a_weighted, b_weighted).unwrap(), distance(a, b).unwrap());
+    assert_relative_eq!(angle(a_weighted, b).unwrap(), angle(a, b).unwrap());
+  }
+
+  #[test]
+  fn intersecting_lines() {
+    let a = line([1., 2., 3.], [1., 1., 0.]);
+    let b = line([1., 2., 3.], [0., -2., 1.]);
+    let (on_a, on_b) = closest_points(a, b).unwrap();
+    assert_abs_diff_eq!(&coordinates(on_a)[..], &[1., 2., 3.][..], epsilon = 1e-5);
+    assert_abs_diff_eq!(&coordinates(on_b)[..], &[1., 2., 3.][..], epsilon = 1e-5);
+    assert_abs_diff_eq!(distance(a, b).unwrap(), 0.);
+    assert_abs_diff_eq!(signed_distance(a, b).unwrap(), 0.);
+    // the perpendicular through the intersection
+    let perpendicular = common_perpendicular(a, b).unwrap();
+    assert!(point_on_line(Point::new(1., 2., 3.), perpendicular, 1e-5));
+    assert!(point_on_line(Point::new(2., 1., 1.), perpendicular, 1e-5));
+  }
+
+  #[test]
+  fn parallel_lines() {
+    let a = line([0., 0., 0.], [1., 2., 0.]);
+    let b = line([0., 0., 5.], [-2., -4., 0.]);
+    assert_eq!(closest_points(a, b), Err(EgaError::Parallel));
+    assert_eq!(common_perpendicular(a, b), Err(EgaError::Parallel));
+    assert_eq!(signed_distance(a, b), Err(EgaError::Parallel));
+    assert_relative_eq!(distance(a, b).unwrap(), 5.);
+    let c = line([2., -1., 0.], [1., 2., 0.]);
+    assert_relative_eq!(distance(a, c).unwrap(), 5f32.sqrt());
+    // coincident lines
+    assert_abs_diff_eq!(distance(a, a).unwrap(), 0.);
+    assert_abs_diff_eq!(angle(a, b).unwrap(), PI);
+  }
+
+  #[test]
+  fn nearly_parallel_lines() {
+    // parallel, but the rounding of the points leaves their joins slightly
+    // skew
+    let join = |p: V, q: V| Bivector::from(Point::new(p[0], p[1], p[2]).join(Point::new(q[0], q[1], q[2])).unwrap());
+    let a = join([0.1, 0.2, 0.3], [1.1, 0.7, 0.9]);
+    let b = join([0.1, 0.2, 5.3], [2.1, 1.2, 6.5]);
+    assert_eq!(closest_points(a, b), Err(EgaError::Parallel));
+    assert_eq!(signed_distance(a, b), Err(EgaError::Parallel));
+    let (expected_a, expected_b) = brute_force([0.1, 0.2, 0.3], [1., 0.5, 0.6], [0.1, 0.2, 5.3], [2., 1., 1.2]);
+    let expected = length(expected_a, expected_b);
+    assert_relative_eq!(dbg!(distance(a, b).unwrap()), dbg!(expected), max_relative = 1e-4);
+
+    // skew at a small angle, but not within `PARALLEL`
+    let a = line([0., 0., 0.], [1., 0., 0.]);
+    let b = line([0., 1., 1.], [1., 1e-3, 0.]);
+    let (on_a, on_b) = closest_points(a, b).unwrap();
+    assert_relative_eq!(&coordinates(dbg!(on_a))[..], &[-1000., 0., 0.][..], epsilon = 1e-3, max_relative = 1e-3);
+    assert_relative_eq!(&coordinates(dbg!(on_b))[..], &[-1000., 0., 1.][..], epsilon = 1e-3, max_relative = 1e-3);
+    assert_relative_eq!(distance(a, b).unwrap(), 1., max_relative = 1e-3);
+  }
+
+  #[test]
+  fn angles() {
+    let x = line([0., 0., 0.], [1., 0., 0.]);
+    assert_abs_diff_eq!(angle(x, x).unwrap(), 0.);
+    assert_abs_diff_eq!(angle(x, line([4., 1., -2.], [0., 2., 0.])).unwrap(), FRAC_PI_2);
+    assert_abs_diff_eq!(angle(x, line([0., 0., 3.], [1., 1., 0.])).unwrap(), FRAC_PI_4);
+    assert_abs_diff_eq!(angle(x, line([0., 0., 3.], [-1., 1., 0.])).unwrap(), 3. * FRAC_PI_4);
+  }
+
+  #[test]
+  fn degenerate_lines() {
+    let a = line([0., 0., 0.], [1., 0., 0.]);
+    let ideal = Bivector { e23: 0., e31: 0., e12: 0., e01: 1., e02: 2., e03: 0. };
+    assert_eq!(closest_points(a, ideal), Err(EgaError::IdealResult));
+    assert_eq!(distance(ideal, a), Err(EgaError::IdealResult));
+    assert_eq!(angle(a, ideal), Err(EgaError::IdealResult));
+    assert_eq!(common_perpendicular(Bivector::ZERO, a), Err(EgaError::ZeroNorm));
+    assert_eq!(signed_distance(a, Bivector::ZERO), Err(EgaError::ZeroNorm));
+  }
+}
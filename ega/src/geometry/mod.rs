@@ -0,0 +1,65 @@
+//! Types carrying the geometric meaning of the grades
+//!
+//! The grade types say nothing about what they represent: a [`Trivector`]
+//! might be a finite point, a direction, or zero. The types here are thin
+//! wrappers which record that meaning, & whose products return the
+//! appropriate types, or an [`EgaError`](crate::EgaError) when the result is
+//! degenerate, e.g. the meet of two parallel planes.
+//!
+//! [`Unit`] records that a value is normalised, for the algorithms which
+//! assume it, & [`predicates`] tests the relationships between elements.
+//! [`lines`] finds the closest points & distances between pairs of lines.
+//! [`robust`] evaluates the orientation tests exactly, for algorithms which
+//! can't tolerate a wrong sign. [`fitting`] finds the planes, lines &
+//! points which best fit noisy data.
+//!
+//! The bounded primitives, [`Segment`], [`Ray`], [`Triangle`], `Polygon` &
+//! the boxes, store their points alongside their carrier lines or planes.
+//! Rays find the points at which they hit them with [`Raycast`], & planes
+//! clip polygons & slice meshes with `clipping`. A `ConvexPolyhedron` is
+//! the region in front of a set of planes, such as a view frustum.
+//!
+//! [`Trivector`]: crate::Trivector
+
+#[cfg(feature = "std")]
+pub mod clipping;
+mod elements;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod fitting;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod lines;
+#[cfg(feature = "std")]
+mod polyhedron;
+// needs sqrt function, so relies on std or libm
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod predicates;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod primitives;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod raycast;
+pub mod robust;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod unit;
+
+pub use elements::{Direction, IdealLine, Line, Plane, Point};
+#[cfg(feature = "std")]
+pub use polyhedron::{ConvexPolyhedron, Mesh};
+#[cfg(feature = "std")]
+pub use primitives::Polygon;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use primitives::{AxisAlignedBox, OrientedBox, Ray, Segment, Triangle};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use raycast::{RayHit, Raycast, TriangleHit};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use unit::{IsUnit, Unit, UnitMotor, UnitPlane, UnitPoint};
+
+/// Which side of a plane a point lies on
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Side {
+  /// On the side which the plane's normal points towards
+  Front,
+  /// On the plane, or within the tolerance of it
+  On,
+  /// On the side which the plane's normal points away from
+  Back,
+}
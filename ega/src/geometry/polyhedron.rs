@@ -0,0 +1,432 @@
+//! Convex polyhedra, as intersections of half-spaces
+
+#[cfg(feature = "libm")]
+use libm::Libm;
+use std::vec::Vec;
+
+use super::primitives::Transform;
+use super::{Plane, Point, Triangle, UnitMotor};
+use crate::*;
+
+/// A convex region, the intersection of the half-spaces in front of planes
+///
+/// The planes face inwards, & are normalised so that their meets with unit
+/// points are signed distances. The region may be unbounded, or empty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvexPolyhedron {
+  planes: Vec<Vector>,
+}
+
+/// A mesh of polygonal faces, indexing a shared list of vertices
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mesh {
+  /// The vertices
+  pub vertices: Vec<Point>,
+  /// The indices of the vertices of each face, anticlockwise seen from
+  /// outside
+  pub faces: Vec<Vec<usize>>,
+}
+
+impl ConvexPolyhedron {
+  /// The region in front of all of the planes
+  ///
+  /// Fails with [`EgaError::ZeroNorm`] if a plane has no weight.
+  #[inline]
+  pub fn new(
+    planes: impl IntoIterator<Item = Vector>,
+  ) -> Result<ConvexPolyhedron, EgaError> {
+    let planes = planes
+      .into_iter()
+      .map(TryNormalise::try_normalise)
+      .collect::<Result<_, _>>()?;
+    Ok(ConvexPolyhedron { planes })
+  }
+
+  /// The view frustum of a camera at the origin looking along `-z`, with `y`
+  /// up, between the `near` & `far` distances
+  ///
+  /// `vertical_fov` is the angle between the top & bottom planes, & `aspect`
+  /// the ratio of the width to the height. Transform the frustum by the
+  /// camera's motor to place it.
+  ///
+  /// Checks that `0 < near < far`, `0 < vertical_fov < π` & `0 < aspect`
+  /// with a `debug_assert`; otherwise the region may be empty.
+  #[inline]
+  pub fn frustum(
+    vertical_fov: f32,
+    aspect: f32,
+    near: f32,
+    far: f32,
+  ) -> ConvexPolyhedron {
+    debug_assert!(0. < near && near < far, "expected 0 < near < far");
+    debug_assert!(
+      0. < vertical_fov && vertical_fov < core::f32::consts::PI,
+      "expected 0 < vertical_fov < π"
+    );
+    debug_assert!(0. < aspect, "expected 0 < aspect");
+    #[cfg(not(feature = "libm"))]
+    let y = (vertical_fov * 0.5).tan();
+    #[cfg(feature = "libm")]
+    let y = Libm::<f32>::tan(vertical_fov * 0.5);
+    let x = y * aspect;
+    let planes = [
+      Plane::new(0., 0., -1., -near),
+      Plane::new(0., 0., 1., far),
+      Plane::new(1., 0., -x, 0.),
+      Plane::new(-1., 0., -x, 0.),
+      Plane::new(0., 1., -y, 0.),
+      Plane::new(0., -1., -y, 0.),
+    ];
+    let planes = planes.iter().map(|&plane| Vector::from(plane).normalise());
+    ConvexPolyhedron {
+      planes: planes.collect(),
+    }
+  }
+
+  /// The bounding planes, facing inwards
+  #[inline]
+  pub fn planes(&self) -> &[Vector] {
+    &self.planes
+  }
+
+  /// Whether the finite point lies inside, or within `epsilon` of the
+  /// boundary
+  #[inline]
+  pub fn contains(&self, point: Point, epsilon: f32) -> bool {
+    let point = point.unitized();
+    self
+      .planes
+      .iter()
+      .all(|plane| plane.meet(point).e0123 >= -epsilon)
+  }
+
+  /// The corners, where three or more planes meet on the boundary
+  ///
+  /// Corners closer than `epsilon` are merged.
+  #[inline]
+  pub fn vertices(&self, epsilon: f32) -> Vec<Point> {
+    let mut vertices: Vec<Point> = Vec::new();
+    let planes = &self.planes;
+    for i in 0..planes.len() {
+      for j in i + 1..planes.len() {
+        for k in j + 1..planes.len() {
+          let meet = planes[i].meet(planes[j]).meet(planes[k]);
+          // parallel planes meet at infinity
+          let Ok(point) = Point::try_from(meet) else {
+            continue;
+          };
+          let point = Point(point.unitized());
+          let duplicate = vertices.iter().any(|&vertex| {
+            let [x, y, z] = (vertex - point).components();
+            x * x + y * y + z * z <= epsilon * epsilon
+          });
+          if !duplicate && self.contains(point, epsilon) {
+            vertices.push(point);
+          }
+        }
+      }
+    }
+    vertices
+  }
+
+  /// The polyhedron transformed by the motor
+  #[inline]
+  pub fn transform(&self, motor: UnitMotor) -> ConvexPolyhedron {
+    let planes = self
+      .planes
+      .iter()
+      .map(|&plane| Plane(plane).transform(motor));
+    ConvexPolyhedron {
+      planes: planes.map(Vector::from).collect(),
+    }
+  }
+
+  /// The region inside both polyhedra
+  ///
+  /// This is bounded by the planes of both, some of which may be redundant,
+  /// or coincide where the polyhedra share a face.
+  #[inline]
+  pub fn intersection(&self, other: &ConvexPolyhedron) -> ConvexPolyhedron {
+    let planes = self.planes.iter().chain(&other.planes).copied();
+    ConvexPolyhedron {
+      planes: planes.collect(),
+    }
+  }
+
+  /// The boundary of a bounded polyhedron, with a face for each plane which
+  /// touches the region over an area
+  ///
+  /// Vertices within `epsilon` of a plane lie on its face. Coincident planes
+  /// have the same vertices, & make a single face.
+  #[inline]
+  pub fn to_mesh(&self, epsilon: f32) -> Mesh {
+    let vertices = self.vertices(epsilon);
+    let mut faces = Vec::new();
+    // the vertices of each face so far, in increasing order
+    let mut found: Vec<Vec<usize>> = Vec::new();
+    for &plane in &self.planes {
+      let mut face: Vec<usize> = (0..vertices.len())
+        .filter(|&i| plane.meet(vertices[i].0).e0123.abs() <= epsilon)
+        .collect();
+      if face.len() < 3 || found.contains(&face) {
+        continue;
+      }
+      found.push(face.clone());
+      // order the vertices by their angle around the centre of the face,
+      // anticlockwise about the outward normal, `-plane`
+      let centre = face
+        .iter()
+        .fold(Trivector::ZERO, |sum, &i| sum + vertices[i].0);
+      let centre = Point(centre);
+      let normal = [-plane.e1, -plane.e2, -plane.e3];
+      let u = (vertices[face[0]] - centre).components();
+      let v = cross(normal, u);
+      let angle = |i: usize| {
+        let offset = (vertices[i] - centre).components();
+        pseudo_angle(dot(offset, u), dot(offset, v))
+      };
+      face.sort_by(|&a, &b| angle(a).total_cmp(&angle(b)));
+      faces.push(face);
+    }
+    Mesh { vertices, faces }
+  }
+}
+
+impl Mesh {
+  /// The triangles fanning out from the first vertex of each face
+  ///
+  /// Faces with fewer than three vertices have no triangles.
+  #[inline]
+  pub fn triangles(&self) -> Vec<Triangle> {
+    let mut triangles = Vec::new();
+    for face in &self.faces {
+      let vertex = |i: usize| self.vertices[face[i]];
+      for i in 1..face.len().saturating_sub(1) {
+        if let Ok(triangle) =
+          Triangle::new(vertex(0), vertex(i), vertex(i + 1))
+        {
+          triangles.push(triangle);
+        }
+      }
+    }
+    triangles
+  }
+}
+
+#[inline]
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+  a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+  [
+    a[1] * b[2] - a[2] * b[1],
+    a[2] * b[0] - a[0] * b[2],
+    a[0] * b[1] - a[1] * b[0],
+  ]
+}
+
+/// A value in `[0, 4)` increasing with the angle of `(x, y)` from the
+/// x axis, like `atan2` but cheaper
+#[inline]
+fn pseudo_angle(x: f32, y: f32) -> f32 {
+  let p = y / (x.abs() + y.abs());
+  match (x < 0., y < 0.) {
+    (true, _) => 2. - p,
+    (false, true) => 4. + p,
+    (false, false) => p,
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::geometry::clipping::slice_mesh;
+  use crate::geometry::Unit;
+  use ::approx::{assert_abs_diff_eq, assert_relative_eq};
+
+  /// The box between `min` & `max`
+  fn cuboid(min: [f32; 3], max: [f32; 3]) -> ConvexPolyhedron {
+    let [x0, y0, z0] = min;
+    let [x1, y1, z1] = max;
+    let planes = [
+      Plane::new(1., 0., 0., -x0), Plane::new(-1., 0., 0., x1),
+      Plane::new(0., 1., 0., -y0), Plane::new(0., -1., 0., y1),
+      Plane::new(0., 0., 1., -z0), Plane::new(0., 0., -1., z1),
+    ];
+    ConvexPolyhedron::new(planes.map(Vector::from)).unwrap()
+  }
+
+  fn sorted(points: &[Point]) -> Vec<[f32; 3]> {
+    let mut coordinates: Vec<_> = points.iter().map(|p| p.coordinates()).collect();
+    coordinates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    coordinates
+  }
+
+  /// The volume enclosed by the mesh, by the divergence theorem
+  fn volume(mesh: &Mesh) -> f32 {
+    mesh.triangles().iter().map(|triangle| {
+      let centroid = triangle.centroid().coordinates();
+      let normal = triangle.normal().components();
+      triangle.area() * dot(centroid, normal) / 3.
+    }).sum()
+  }
+
+  #[test]
+  fn new() {
+    let cube = cuboid([0., 0., 0.], [2., 2., 2.]);
+    // the planes are normalised
+    assert_eq!(cube.planes()[1], Vector { e0: 2., e1: -1., e2: 0., e3: 0. });
+    let scaled = ConvexPolyhedron::new([Vector { e0: 0., e1: 4., e2: 0., e3: 0. }]).unwrap();
+    assert_eq!(scaled.planes(), [Vector { e0: 0., e1: 1., e2: 0., e3: 0. }]);
+    assert_eq!(ConvexPolyhedron::new([Vector { e0: 1., e1: 0., e2: 0., e3: 0. }]), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn contains() {
+    let cube = cuboid([0., 0., 0.], [2., 2., 2.]);
+    assert!(cube.contains(Point::new(1., 1., 1.), 0.));
+    assert!(cube.contains(Point::new(0., 2., 1.), 0.));
+    assert!(!cube.contains(Point::new(2.1, 1., 1.), 0.));
+    assert!(cube.contains(Point::new(2.1, 1., 1.), 0.2));
+    // the weight of the point doesn't matter
+    assert!(!cube.contains(Point(Trivector { e123: -1., e032: -3., e013: -1., e021: -1. }), 0.));
+    assert!(cube.contains(Point(Trivector { e123: -2., e032: -2., e013: -2., e021: -2. }), 0.));
+  }
+
+  #[test]
+  fn vertices() {
+    let cube = cuboid([0., 0., 0.], [1., 2., 3.]);
+    let vertices = cube.vertices(1e-5);
+    assert_eq!(sorted(&vertices), [
+      [0., 0., 0.], [0., 0., 3.], [0., 2., 0.], [0., 2., 3.],
+      [1., 0., 0.], [1., 0., 3.], [1., 2., 0.], [1., 2., 3.],
+    ]);
+    // four planes meet at the apex of a pyramid, which is found once
+    let planes = [
+      Plane::new(0., 0., 1., 0.), Plane::new(1., 0., -1., 1.), Plane::new(-1., 0., -1., 1.),
+      Plane::new(0., 1., -1., 1.), Plane::new(0., -1., -1., 1.),
+    ];
+    let pyramid = ConvexPolyhedron::new(planes.map(Vector::from)).unwrap();
+    let vertices = pyramid.vertices(1e-5);
+    assert_eq!(vertices.len(), 5);
+    let apex = vertices.iter().map(|p| p.coordinates()).find(|&[_, _, z]| z > 0.5);
+    assert_abs_diff_eq!(&apex.unwrap()[..], &[0., 0., 1.][..], epsilon = 1e-6);
+    // a slab between two planes has no corners
+    let slab = ConvexPolyhedron::new(cube.planes()[..2].iter().copied()).unwrap();
+    assert_eq!(slab.vertices(1e-5), []);
+  }
+
+  #[test]
+  fn transform() {
+    let cube = cuboid([0., 0., 0.], [1., 1., 1.]);
+    // a quarter turn anticlockwise about the z axis
+    let (a, b) = (Plane::new(-1., 1., 0., 0.), Plane::new(1., 0., 0., 0.));
+    let motor = Unit::new(Motor::from_even(Vector::from(b) * Vector::from(a)));
+    let turned = cube.transform(motor);
+    assert!(turned.contains(Point::new(-0.5, 0.5, 0.5), 1e-6));
+    assert!(!turned.contains(Point::new(0.5, 0.5, 0.5), 1e-6));
+    let expected = sorted(&cuboid([-1., 0., 0.], [0., 1., 1.]).vertices(1e-5));
+    for (vertex, expected) in sorted(&turned.vertices(1e-5)).iter().zip(&expected) {
+      assert_abs_diff_eq!(&vertex[..], &expected[..], epsilon = 1e-6);
+    }
+  }
+
+  #[test]
+  fn intersection() {
+    let a = cuboid([0., 0., 0.], [2., 2., 2.]);
+    let b = cuboid([1., 1., -1.], [3., 3., 1.]);
+    let both = a.intersection(&b);
+    assert!(both.contains(Point::new(1.5, 1.5, 0.5), 0.));
+    assert!(!both.contains(Point::new(0.5, 0.5, 0.5), 0.));
+    assert_eq!(sorted(&both.vertices(1e-5)), sorted(&cuboid([1., 1., 0.], [2., 2., 1.]).vertices(1e-5)));
+    // the redundant planes don't make faces
+    let mesh = both.to_mesh(1e-5);
+    assert_eq!(mesh.faces.len(), 6);
+    assert_relative_eq!(volume(&mesh), 1., epsilon = 1e-5);
+    // a polyhedron with itself, & with one sharing a face, where the planes
+    // coincide
+    let mesh = a.intersection(&a).to_mesh(1e-5);
+    assert_eq!(mesh.faces.len(), 6);
+    assert_eq!(mesh.triangles().len(), 12);
+    assert_relative_eq!(volume(&mesh), 8., epsilon = 1e-5);
+    let d = cuboid([0., 0., 1.], [2., 2., 3.]);
+    let mesh = a.intersection(&d).to_mesh(1e-5);
+    assert_eq!(mesh.faces.len(), 6);
+    assert_relative_eq!(volume(&mesh), 4., epsilon = 1e-5);
+    let slices = slice_mesh(&mesh.triangles(), Plane::new(0., 0., 1., -1.5));
+    assert_eq!(slices.len(), 1);
+    assert!(slices[0].closed && slices[0].points.len() == 8);
+    // disjoint polyhedra leave nothing
+    let c = cuboid([5., 5., 5.], [6., 6., 6.]);
+    assert_eq!(a.intersection(&c).vertices(1e-5), []);
+  }
+
+  #[test]
+  fn to_mesh() {
+    let cube = cuboid([0., 0., 0.], [1., 2., 3.]);
+    let mesh = cube.to_mesh(1e-5);
+    assert_eq!(mesh.vertices.len(), 8);
+    assert_eq!(mesh.faces.len(), 6);
+    assert!(mesh.faces.iter().all(|face| face.len() == 4));
+    // the faces are anticlockwise seen from outside, so their normals face out
+    for triangle in mesh.triangles() {
+      let offset = (triangle.centroid() - Point::new(0.5, 1., 1.5)).components();
+      assert!(dot(offset, triangle.normal().components()) > 0., "{triangle:?}");
+    }
+    assert_relative_eq!(volume(&mesh), 6., epsilon = 1e-5);
+    // the mesh is closed, so slicing it gives a loop
+    let slices = slice_mesh(&mesh.triangles(), Plane::new(0., 0., 1., -1.));
+    assert_eq!(slices.len(), 1);
+    assert!(slices[0].closed);
+  }
+
+  #[test]
+  fn degenerate_faces() {
+    let vertices = vec![Point::ORIGIN, Point::new(1., 0., 0.), Point::new(0., 1., 0.)];
+    let mesh = Mesh { vertices, faces: vec![vec![], vec![0], vec![0, 1], vec![0, 1, 2]] };
+    assert_eq!(mesh.triangles().len(), 1);
+    let mesh = Mesh { vertices: vec![Point::ORIGIN], faces: vec![vec![]] };
+    assert!(mesh.triangles().is_empty());
+  }
+
+  #[test]
+  fn frustum() {
+    let fov = core::f32::consts::FRAC_PI_2;
+    let frustum = ConvexPolyhedron::frustum(fov, 2., 1., 10.);
+    assert!(frustum.contains(Point::new(0., 0., -5.), 0.));
+    assert!(frustum.contains(Point::new(9.9, 4.9, -5.), 0.));
+    assert!(!frustum.contains(Point::new(0., 0., 5.), 0.));
+    assert!(!frustum.contains(Point::new(0., 0., -0.5), 0.));
+    assert!(!frustum.contains(Point::new(0., 0., -11.), 0.));
+    assert!(!frustum.contains(Point::new(0., 5.1, -5.), 0.));
+    assert!(!frustum.contains(Point::new(10.1, 0., -5.), 0.));
+    let expected = [
+      [-20., -10., -10.], [-20., 10., -10.], [-2., -1., -1.], [-2., 1., -1.],
+      [2., -1., -1.], [2., 1., -1.], [20., -10., -10.], [20., 10., -10.],
+    ];
+    for (vertex, expected) in sorted(&frustum.vertices(1e-4)).iter().zip(&expected) {
+      assert_abs_diff_eq!(&vertex[..], &expected[..], epsilon = 1e-4);
+    }
+    // a truncated pyramid, (h / 3) (A + √(A a) + a)
+    let mesh = frustum.to_mesh(1e-4);
+    assert_eq!(mesh.faces.len(), 6);
+    assert_relative_eq!(volume(&mesh), 3. * (800. + 80. + 8.), max_relative = 1e-5);
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "near < far")]
+  fn frustum_empty() {
+    ConvexPolyhedron::frustum(1., 1., 5., 1.);
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "vertical_fov")]
+  fn frustum_wide() {
+    ConvexPolyhedron::frustum(4., 1., 1., 5.);
+  }
+}
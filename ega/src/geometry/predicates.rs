@@ -0,0 +1,255 @@
+//! Incidence & relationship predicates, with tolerances
+//!
+//! Each predicate takes an explicit `epsilon`, a distance for positional
+//! tests, or the sine of an angle for directional tests. The elements are
+//! normalised first, so the results don't depend on their weights.
+
+use super::Side;
+use crate::*;
+
+/// The signed distance from a plane to a finite point, positive on the side
+/// which the plane's normal points towards
+#[inline]
+pub fn signed_distance(
+  point: impl Into<Trivector>,
+  plane: impl Into<Vector>,
+) -> f32 {
+  let (point, plane) = (point.into(), plane.into());
+  plane.meet(point).e0123 / (plane.norm().s * point.e123)
+}
+
+/// Which side of the plane the finite point lies on
+#[inline]
+pub fn side(
+  point: impl Into<Trivector>,
+  plane: impl Into<Vector>,
+  epsilon: f32,
+) -> Side {
+  let distance = signed_distance(point, plane);
+  if distance > epsilon {
+    Side::Front
+  } else if distance < -epsilon {
+    Side::Back
+  } else {
+    Side::On
+  }
+}
+
+/// Whether the finite point lies within `epsilon` of the plane
+#[inline]
+pub fn point_on_plane(
+  point: impl Into<Trivector>,
+  plane: impl Into<Vector>,
+  epsilon: f32,
+) -> bool {
+  side(point, plane, epsilon) == Side::On
+}
+
+/// Whether the finite point lies within `epsilon` of the Euclidean line
+#[inline]
+pub fn point_on_line(
+  point: impl Into<Trivector>,
+  line: impl Into<Bivector>,
+  epsilon: f32,
+) -> bool {
+  let (point, line) = (point.into(), line.into());
+  // the plane through a unit point & a unit line has a weight equal to the
+  // distance between them
+  let distance = point.join(line).norm().s / (point.e123 * line.norm().s);
+  distance.abs() <= epsilon
+}
+
+/// Whether the Euclidean line lies within `epsilon` of the plane, i.e. is
+/// parallel to the plane & no further than `epsilon` from it
+#[inline]
+pub fn line_in_plane(
+  line: impl Into<Bivector>,
+  plane: impl Into<Vector>,
+  epsilon: f32,
+) -> bool {
+  let (line, plane) = (line.into(), plane.into());
+  // for a unit line & plane the weight of the meet is the sine of the angle
+  // between them, & when they're parallel its bulk is the distance
+  let meet = line.meet(plane) * (1. / (line.norm().s * plane.norm().s));
+  let Trivector {
+    e123,
+    e032,
+    e013,
+    e021,
+  } = meet;
+  let distance = sqrt(e032 * e032 + e013 * e013 + e021 * e021);
+  e123.abs() <= epsilon && distance <= epsilon
+}
+
+/// Whether the directions of the Euclidean lines are parallel, or
+/// antiparallel, to within `epsilon`
+#[inline]
+pub fn lines_parallel(
+  a: impl Into<Bivector>,
+  b: impl Into<Bivector>,
+  epsilon: f32,
+) -> bool {
+  let (a, b) = (a.into(), b.into());
+  // `|a × b| = |a| |b| sin θ`
+  let norms_squared = a.norm_squared().s * b.norm_squared().s;
+  cross_squared(a, b) <= epsilon * epsilon * norms_squared
+}
+
+/// Whether the Euclidean lines meet at a single finite point, i.e. they're
+/// coplanar to within `epsilon`, & not parallel
+#[inline]
+pub fn lines_intersect(
+  a: impl Into<Bivector>,
+  b: impl Into<Bivector>,
+  epsilon: f32,
+) -> bool {
+  let (a, b) = (a.into(), b.into());
+  if lines_parallel(a, b, epsilon) {
+    return false;
+  }
+  // the meet is the distance between the lines times `|a × b|`
+  let distance = a.meet(b).e0123.abs() / sqrt(cross_squared(a, b));
+  distance <= epsilon
+}
+
+/// The squared length of the cross product of the lines' directions
+#[inline]
+fn cross_squared(a: Bivector, b: Bivector) -> f32 {
+  let cross = [
+    a.e31 * b.e12 - a.e12 * b.e31,
+    a.e12 * b.e23 - a.e23 * b.e12,
+    a.e23 * b.e31 - a.e31 * b.e23,
+  ];
+  cross.iter().map(|c| c * c).sum()
+}
+
+#[inline]
+fn sqrt(x: f32) -> f32 {
+  Scalar { s: x }.sqrt().s
+}
+
+/// Whether the directions of the planes' normals are parallel, or
+/// antiparallel, to within `epsilon`
+#[inline]
+pub fn planes_parallel(
+  a: impl Into<Vector>,
+  b: impl Into<Vector>,
+  epsilon: f32,
+) -> bool {
+  let (a, b) = (a.into(), b.into());
+  // the weight of the meet of unit planes is the sine of the angle between
+  let sine = a.meet(b).norm().s / (a.norm().s * b.norm().s);
+  sine <= epsilon
+}
+
+/// Whether the element lies at infinity, i.e. its weight is no more than
+/// `epsilon` of its magnitude
+#[inline]
+pub fn is_ideal<T>(value: T, epsilon: f32) -> bool
+where
+  T: Copy + NormSquared + IdealNormSquared,
+{
+  let weight = value.norm_squared().s;
+  let bulk = value.ideal_norm_squared().e0123;
+  weight <= epsilon * epsilon * (weight + bulk)
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::geometry::{Direction, Plane, Point};
+
+  const EPSILON: f32 = 1e-5;
+
+  #[test]
+  fn sides() {
+    // the plane x = 1, weighted by 2
+    let plane = Plane::new(2., 0., 0., -2.);
+    assert_eq!(signed_distance(Point::new(3., 1., 1.), plane), 2.);
+    assert_eq!(signed_distance(Point::new(-1., 5., 0.), plane), -2.);
+    // a weighted point at (3, 0, 0)
+    let point = Trivector { e123: -2., e032: -6., e013: 0., e021: 0. };
+    assert_eq!(signed_distance(point, plane), 2.);
+
+    assert_eq!(side(Point::new(3., 1., 1.), plane, EPSILON), Side::Front);
+    assert_eq!(side(Point::new(0., 1., 1.), plane, EPSILON), Side::Back);
+    assert_eq!(side(Point::new(1., 7., 1.), plane, EPSILON), Side::On);
+    assert!(point_on_plane(Point::new(1.000001, 7., 1.), plane, EPSILON));
+    assert!(!point_on_plane(Point::new(1.1, 7., 1.), plane, EPSILON));
+  }
+
+  #[test]
+  fn points_on_lines() {
+    let line = Point::new(1., 1., 1.).join(Point::new(3., 3., 3.)).unwrap();
+    assert!(point_on_line(Point::new(-2., -2., -2.), line, EPSILON));
+    assert!(!point_on_line(Point::new(-2., -2., -1.9), line, EPSILON));
+    assert!(point_on_line(Point::new(-2., -2., -1.9), line, 0.1));
+  }
+
+  #[test]
+  fn lines_in_planes() {
+    let plane = Plane::new(0., 0., 1., -2.);
+    let inside = Point::new(0., 0., 2.).join(Point::new(1., 5., 2.)).unwrap();
+    let parallel = Point::new(0., 0., 3.).join(Point::new(1., 5., 3.)).unwrap();
+    let crossing = Point::new(0., 0., 2.).join(Point::new(1., 5., 3.)).unwrap();
+    assert!(line_in_plane(inside, plane, EPSILON));
+    assert!(!line_in_plane(parallel, plane, EPSILON));
+    assert!(line_in_plane(parallel, plane, 1.));
+    assert!(!line_in_plane(crossing, plane, EPSILON));
+    // the distance doesn't depend on the line's direction
+    let diagonal = Point::new(0., 0., 1.2).join(Point::new(1., 1., 1.2)).unwrap();
+    let along_x = Point::new(0., 0., 1.2).join(Point::new(1., 0., 1.2)).unwrap();
+    let plane = Plane::new(0., 0., 1., 0.);
+    assert!(!line_in_plane(diagonal, plane, 1.));
+    assert!(!line_in_plane(along_x, plane, 1.));
+    assert!(line_in_plane(diagonal, plane, 1.3));
+    assert!(line_in_plane(along_x, plane, 1.3));
+  }
+
+  #[test]
+  fn line_relationships() {
+    let x_axis = Point::new(0., 0., 0.).join(Point::new(1., 0., 0.)).unwrap();
+    let crossing = Point::new(2., -1., 0.).join(Point::new(2., 1., 0.)).unwrap();
+    let skew = Point::new(2., -1., 1.).join(Point::new(2., 1., 1.)).unwrap();
+    let parallel = Point::new(0., 0., 1.).join(Point::new(-4., 0., 1.)).unwrap();
+
+    assert!(lines_intersect(x_axis, crossing, EPSILON));
+    assert!(!lines_intersect(x_axis, skew, EPSILON));
+    assert!(!lines_intersect(x_axis, parallel, EPSILON));
+    // the tolerance is a distance, however small the angle between them
+    let shallow = Point::new(0., 0., 0.5).join(Point::new(1., 2e-5, 0.5)).unwrap();
+    assert!(!lines_intersect(x_axis, shallow, EPSILON));
+    let through = Point::new(-1., -2e-5, 0.).join(Point::new(1., 2e-5, 0.)).unwrap();
+    assert!(lines_intersect(x_axis, through, EPSILON));
+    let near = Point::new(2., -1., 0.5).join(Point::new(2., 1., 0.5)).unwrap();
+    assert!(!lines_intersect(x_axis, near, 0.4));
+    assert!(lines_intersect(x_axis, near, 0.6));
+
+    assert!(lines_parallel(x_axis, parallel, EPSILON));
+    assert!(!lines_parallel(x_axis, skew, EPSILON));
+  }
+
+  #[test]
+  fn parallel_planes() {
+    let a = Plane::new(1., 1., 0., 3.);
+    let b = Plane::new(-2., -2., 0., 1.);
+    let c = Plane::new(1., 1., 0.01, 3.);
+    assert!(planes_parallel(a, b, EPSILON));
+    assert!(!planes_parallel(a, c, EPSILON));
+    assert!(planes_parallel(a, c, 0.01));
+  }
+
+  #[test]
+  fn ideal() {
+    assert!(is_ideal(Trivector::from(Direction::new(1., 2., 3.)), EPSILON));
+    assert!(!is_ideal(Trivector::from(Point::new(1., 2., 3.)), EPSILON));
+    // nearly at infinity
+    let far = Trivector { e123: 1e-7, e032: 1., e013: 0., e021: 0. };
+    assert!(is_ideal(far, EPSILON));
+    assert!(is_ideal(Vector { e0: 1., e1: 0., e2: 0., e3: 0. }, EPSILON));
+    assert!(!is_ideal(Vector::from(Plane::new(0., 0., 1., 100.)), EPSILON));
+    let ideal_line = Direction::new(1., 0., 0.).join(Direction::new(0., 1., 0.)).unwrap();
+    assert!(is_ideal(Bivector::from(ideal_line), EPSILON));
+  }
+}
@@ -0,0 +1,565 @@
+//! Bounded primitives: segments, rays, triangles, polygons & boxes
+//!
+//! Each primitive stores its points with a weight of `1`, alongside its
+//! carrier line or plane, so lengths & areas are the weights of joins.
+//! Triangles & polygons are oriented by the right-hand rule, so their normal
+//! points towards the side from which their vertices are anticlockwise.
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use super::{Direction, Line, Plane, Point, UnitMotor};
+use crate::*;
+
+/// The segment of a line between two points
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Segment {
+  start: Point,
+  end: Point,
+  line: Line,
+}
+
+/// The half of a line starting at a point
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray {
+  origin: Point,
+  direction: Direction,
+  line: Line,
+}
+
+/// A triangle, bounded by three points
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle {
+  vertices: [Point; 3],
+  plane: Plane,
+}
+
+/// A planar polygon, bounded by a loop of points
+///
+/// The vertices are assumed to be coplanar; the carrier plane passes through
+/// the first vertex, with the normal of the polygon's area.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon {
+  vertices: Vec<Point>,
+  plane: Plane,
+}
+
+/// A box whose faces are perpendicular to the axes
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AxisAlignedBox {
+  min: Point,
+  max: Point,
+}
+
+/// A box centred on the origin, placed by a motor
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OrientedBox {
+  motor: UnitMotor,
+  half_extents: [f32; 3],
+}
+
+impl Segment {
+  /// The segment from `start` to `end`
+  ///
+  /// Fails with [`EgaError::ZeroNorm`] if the points coincide.
+  #[inline]
+  pub fn new(start: Point, end: Point) -> Result<Segment, EgaError> {
+    let (start, end) = (Point(start.unitized()), Point(end.unitized()));
+    let line = start.join(end)?;
+    Ok(Segment { start, end, line })
+  }
+
+  /// The start point
+  #[inline]
+  pub fn start(&self) -> Point {
+    self.start
+  }
+
+  /// The end point
+  #[inline]
+  pub fn end(&self) -> Point {
+    self.end
+  }
+
+  /// The carrier line, directed from the start to the end
+  #[inline]
+  pub fn line(&self) -> Line {
+    self.line
+  }
+
+  /// The direction from the start to the end, with the segment's length
+  #[inline]
+  pub fn direction(&self) -> Direction {
+    self.end - self.start
+  }
+
+  /// The length
+  #[inline]
+  pub fn length(&self) -> f32 {
+    // the weight of the join of two unit points is the distance between them
+    self.line.0.norm().s
+  }
+
+  /// The midpoint
+  #[inline]
+  pub fn centroid(&self) -> Point {
+    centroid(self.start.0 + self.end.0)
+  }
+
+  /// The segment transformed by the motor
+  #[inline]
+  pub fn transform(&self, motor: UnitMotor) -> Segment {
+    Segment {
+      start: self.start.transform(motor),
+      end: self.end.transform(motor),
+      line: self.line.transform(motor),
+    }
+  }
+}
+
+impl Ray {
+  /// The ray from `origin` along `direction`
+  ///
+  /// Fails with [`EgaError::ZeroNorm`] if the direction is zero.
+  #[inline]
+  pub fn new(origin: Point, direction: Direction) -> Result<Ray, EgaError> {
+    let origin = Point(origin.unitized());
+    let line = origin.join(direction)?;
+    Ok(Ray {
+      origin,
+      direction,
+      line,
+    })
+  }
+
+  /// The origin
+  #[inline]
+  pub fn origin(&self) -> Point {
+    self.origin
+  }
+
+  /// The direction
+  #[inline]
+  pub fn direction(&self) -> Direction {
+    self.direction
+  }
+
+  /// The carrier line, directed along the ray
+  #[inline]
+  pub fn line(&self) -> Line {
+    self.line
+  }
+
+  /// The point `origin + t * direction`
+  #[inline]
+  pub fn at(&self, t: f32) -> Point {
+    Point(self.origin.0 + self.direction.0 * t)
+  }
+
+  /// The ray transformed by the motor
+  #[inline]
+  pub fn transform(&self, motor: UnitMotor) -> Ray {
+    Ray {
+      origin: self.origin.transform(motor),
+      direction: self.direction.transform(motor),
+      line: self.line.transform(motor),
+    }
+  }
+}
+
+impl Triangle {
+  /// The triangle with the vertices `a`, `b` & `c`
+  ///
+  /// Fails with [`EgaError::ZeroNorm`] if the vertices are collinear.
+  #[inline]
+  pub fn new(a: Point, b: Point, c: Point) -> Result<Triangle, EgaError> {
+    let vertices = [a, b, c].map(|p| Point(p.unitized()));
+    let plane = Plane::try_from(area_plane(vertices))?;
+    Ok(Triangle { vertices, plane })
+  }
+
+  /// The vertices
+  #[inline]
+  pub fn vertices(&self) -> [Point; 3] {
+    self.vertices
+  }
+
+  /// The carrier plane, whose weight is twice the area
+  #[inline]
+  pub fn plane(&self) -> Plane {
+    self.plane
+  }
+
+  /// The unit normal
+  #[inline]
+  pub fn normal(&self) -> Direction {
+    unit_normal(self.plane)
+  }
+
+  /// The area
+  #[inline]
+  pub fn area(&self) -> f32 {
+    // the weight of the join of three unit points is twice the area of
+    // their triangle
+    self.plane.0.norm().s * 0.5
+  }
+
+  /// The centroid
+  #[inline]
+  pub fn centroid(&self) -> Point {
+    let [a, b, c] = self.vertices;
+    centroid(a.0 + b.0 + c.0)
+  }
+
+  /// The triangle transformed by the motor
+  #[inline]
+  pub fn transform(&self, motor: UnitMotor) -> Triangle {
+    Triangle {
+      vertices: self.vertices.map(|p| p.transform(motor)),
+      plane: self.plane.transform(motor),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl Polygon {
+  /// The polygon with the vertices in order
+  ///
+  /// Fails with [`EgaError::ZeroNorm`] if the polygon has no area, e.g. it
+  /// has fewer than three vertices, or they're collinear.
+  #[inline]
+  pub fn new(
+    vertices: impl IntoIterator<Item = Point>,
+  ) -> Result<Polygon, EgaError> {
+    let vertices: Vec<Point> =
+      vertices.into_iter().map(|p| Point(p.unitized())).collect();
+    // the sum of the planes of a fan of triangles, all through the first
+    // vertex
+    let plane = fan(&vertices).map(area_plane).fold(Vector::ZERO, Add::add);
+    let plane = Plane::try_from(plane)?;
+    Ok(Polygon { vertices, plane })
+  }
+
+  /// The vertices
+  #[inline]
+  pub fn vertices(&self) -> &[Point] {
+    &self.vertices
+  }
+
+  /// The carrier plane, whose weight is twice the area
+  #[inline]
+  pub fn plane(&self) -> Plane {
+    self.plane
+  }
+
+  /// The unit normal
+  #[inline]
+  pub fn normal(&self) -> Direction {
+    unit_normal(self.plane)
+  }
+
+  /// The area
+  #[inline]
+  pub fn area(&self) -> f32 {
+    self.plane.0.norm().s * 0.5
+  }
+
+  /// The centroid of the area
+  #[inline]
+  pub fn centroid(&self) -> Point {
+    let normal = self.plane.0;
+    // the centroids of the triangles of the fan, weighted by their signed
+    // areas, as the weights of their planes along the polygon's normal
+    let weighted = fan(&self.vertices).map(|triangle| {
+      let plane = area_plane(triangle);
+      let area =
+        plane.e1 * normal.e1 + plane.e2 * normal.e2 + plane.e3 * normal.e3;
+      let [a, b, c] = triangle;
+      (a.0 + b.0 + c.0) * area
+    });
+    centroid(weighted.fold(Trivector::ZERO, Add::add))
+  }
+
+  /// The polygon transformed by the motor
+  #[inline]
+  pub fn transform(&self, motor: UnitMotor) -> Polygon {
+    Polygon {
+      vertices: self.vertices.iter().map(|p| p.transform(motor)).collect(),
+      plane: self.plane.transform(motor),
+    }
+  }
+}
+
+impl AxisAlignedBox {
+  /// The box with opposite corners `a` & `b`
+  #[inline]
+  pub fn new(a: Point, b: Point) -> AxisAlignedBox {
+    let (a, b) = (a.coordinates(), b.coordinates());
+    let [x, y, z] = [0, 1, 2].map(|i| a[i].min(b[i]));
+    let min = Point::new(x, y, z);
+    let [x, y, z] = [0, 1, 2].map(|i| a[i].max(b[i]));
+    AxisAlignedBox {
+      min,
+      max: Point::new(x, y, z),
+    }
+  }
+
+  /// The corner with the least coordinates
+  #[inline]
+  pub fn min(&self) -> Point {
+    self.min
+  }
+
+  /// The corner with the greatest coordinates
+  #[inline]
+  pub fn max(&self) -> Point {
+    self.max
+  }
+
+  /// The centre
+  #[inline]
+  pub fn centroid(&self) -> Point {
+    centroid(self.min.0 + self.max.0)
+  }
+}
+
+impl OrientedBox {
+  /// The box spanning `[-x, x]`, `[-y, y]` & `[-z, z]` for the half extents
+  /// `[x, y, z]`, transformed by the motor
+  #[inline]
+  pub fn new(motor: UnitMotor, half_extents: [f32; 3]) -> OrientedBox {
+    OrientedBox {
+      motor,
+      half_extents: half_extents.map(f32::abs),
+    }
+  }
+
+  /// The motor placing the box
+  #[inline]
+  pub fn motor(&self) -> UnitMotor {
+    self.motor
+  }
+
+  /// The half extents along the box's axes
+  #[inline]
+  pub fn half_extents(&self) -> [f32; 3] {
+    self.half_extents
+  }
+
+  /// The box before it's placed by its motor, centred on the origin
+  #[inline]
+  pub fn local(&self) -> AxisAlignedBox {
+    let [x, y, z] = self.half_extents;
+    AxisAlignedBox {
+      min: Point::new(-x, -y, -z),
+      max: Point::new(x, y, z),
+    }
+  }
+
+  /// The centre
+  #[inline]
+  pub fn centroid(&self) -> Point {
+    Point::ORIGIN.transform(self.motor)
+  }
+
+  /// The box transformed by the motor
+  #[inline]
+  pub fn transform(&self, motor: UnitMotor) -> OrientedBox {
+    OrientedBox {
+      motor: motor * self.motor,
+      ..*self
+    }
+  }
+}
+
+/// The triangles `[v0, vi, vi+1]` fanning out from the first vertex
+#[cfg(feature = "std")]
+#[inline]
+fn fan(vertices: &[Point]) -> impl Iterator<Item = [Point; 3]> + '_ {
+  let first = vertices.first().copied();
+  vertices
+    .windows(2)
+    .skip(1)
+    .filter_map(move |pair| Some([first?, pair[0], pair[1]]))
+}
+
+/// The plane of the unit points, oriented by the right-hand rule, with a
+/// weight of twice the area of their triangle
+#[inline]
+fn area_plane([a, b, c]: [Point; 3]) -> Vector {
+  // the join of anticlockwise points faces away from them, so reverse it; a
+  // degenerate triangle gives zero, so join the grades
+  c.0.join(b.0).join(a.0)
+}
+
+#[inline]
+fn unit_normal(plane: Plane) -> Direction {
+  let [x, y, z] = plane.normal().components();
+  let scale = 1. / plane.0.norm().s;
+  Direction::new(x * scale, y * scale, z * scale)
+}
+
+/// The point of the weighted sum of points
+#[inline]
+fn centroid(sum: Trivector) -> Point {
+  Point(Point(sum).unitized())
+}
+
+/// Elements which motors map to elements of the same kind
+pub(super) trait Transform {
+  fn transform(self, motor: UnitMotor) -> Self;
+}
+
+macro_rules! impl_transform {
+  ($type:ident: $grade:ident) => {
+    impl Transform for $type {
+      #[inline]
+      fn transform(self, motor: UnitMotor) -> $type {
+        $type(motor.sandwich(self.0.into()).$grade())
+      }
+    }
+  };
+}
+
+impl_transform! { Point: grade_3 }
+impl_transform! { Direction: grade_3 }
+impl_transform! { Line: grade_2 }
+impl_transform! { Plane: grade_1 }
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::geometry::Unit;
+  use ::approx::{assert_abs_diff_eq, assert_relative_eq};
+
+  /// The motor reflecting in the planes `x = 0` then `x = 1`
+  fn translation() -> UnitMotor {
+    let (a, b) = (Plane::new(1., 0., 0., 0.), Plane::new(1., 0., 0., -1.));
+    Unit::new(Motor::from_even(Vector::from(b) * Vector::from(a)))
+  }
+
+  /// The motor reflecting in the planes `y = x` then `x = 0`, a quarter turn
+  /// anticlockwise about the z axis
+  fn rotation() -> UnitMotor {
+    let (a, b) = (Plane::new(-1., 1., 0., 0.), Plane::new(1., 0., 0., 0.));
+    Unit::new(Motor::from_even(Vector::from(b) * Vector::from(a)))
+  }
+
+  fn assert_point_eq(point: Point, expected: [f32; 3]) {
+    assert_abs_diff_eq!(&dbg!(point.coordinates())[..], &expected[..], epsilon = 1e-5);
+  }
+
+  #[test]
+  fn motors() {
+    assert_point_eq(Point::ORIGIN.transform(translation()), [2., 0., 0.]);
+    assert_point_eq(Point::new(1., 0., 3.).transform(rotation()), [0., 1., 3.]);
+  }
+
+  #[test]
+  fn segments() {
+    let weighted = Point(Trivector { e123: 2., e032: 8., e013: 6., e021: 0. });
+    let segment = Segment::new(Point::new(1., -1., 0.), weighted).unwrap();
+    assert_eq!(segment.start(), Point::new(1., -1., 0.));
+    assert_eq!(segment.end(), Point::new(4., 3., 0.));
+    assert_eq!(segment.direction(), Direction::new(3., 4., 0.));
+    assert_relative_eq!(segment.length(), 5.);
+    assert_point_eq(segment.centroid(), [2.5, 1., 0.]);
+    assert_eq!(Ok(segment.line()), Point::new(1., -1., 0.).join(Point::new(4., 3., 0.)));
+    let point = Point::new(1., 1., 1.);
+    assert_eq!(Segment::new(point, point), Err(EgaError::ZeroNorm));
+
+    let moved = segment.transform(rotation());
+    assert_point_eq(moved.start(), [1., 1., 0.]);
+    assert_point_eq(moved.end(), [-3., 4., 0.]);
+    assert_relative_eq!(moved.length(), 5., max_relative = 1e-6);
+    let expected = Segment::new(moved.start(), moved.end()).unwrap().line();
+    assert_relative_eq!(Bivector::from(moved.line()), expected.into(), epsilon = 1e-5);
+  }
+
+  #[test]
+  fn rays() {
+    let ray = Ray::new(Point::new(1., 2., 3.), Direction::new(0., 0., -2.)).unwrap();
+    assert_eq!(ray.origin(), Point::new(1., 2., 3.));
+    assert_eq!(ray.direction(), Direction::new(0., 0., -2.));
+    assert_point_eq(ray.at(0.5), [1., 2., 2.]);
+    assert_eq!(ray.line().direction(), Direction::new(0., 0., -2.));
+    let zero = Direction::new(0., 0., 0.);
+    assert_eq!(Ray::new(Point::ORIGIN, zero), Err(EgaError::ZeroNorm));
+
+    let moved = ray.transform(translation());
+    assert_point_eq(moved.origin(), [3., 2., 3.]);
+    assert_point_eq(moved.at(1.), [3., 2., 1.]);
+    let [x, y, z] = moved.direction().components();
+    assert_abs_diff_eq!(&[x, y, z][..], &[0., 0., -2.][..], epsilon = 1e-6);
+    let expected = moved.origin().join(moved.at(1.)).unwrap();
+    assert_relative_eq!(Bivector::from(moved.line()), Bivector::from(expected), epsilon = 1e-5);
+  }
+
+  #[test]
+  fn triangles() {
+    let [a, b, c] = [Point::new(0., 0., 1.), Point::new(4., 0., 1.), Point::new(0., 3., 1.)];
+    let triangle = Triangle::new(a, b, c).unwrap();
+    assert_eq!(triangle.vertices(), [a, b, c]);
+    assert_relative_eq!(triangle.area(), 6.);
+    assert_eq!(triangle.normal(), Direction::new(0., 0., 1.));
+    assert_eq!(triangle.plane(), Plane::new(0., 0., 12., -12.));
+    assert_point_eq(triangle.centroid(), [4. / 3., 1., 1.]);
+    // clockwise vertices face the other way
+    assert_eq!(Triangle::new(a, c, b).unwrap().normal(), Direction::new(0., 0., -1.));
+    let collinear = Point::new(8., 0., 1.);
+    assert_eq!(Triangle::new(a, b, collinear), Err(EgaError::ZeroNorm));
+
+    let moved = triangle.transform(rotation());
+    assert_relative_eq!(moved.area(), 6., max_relative = 1e-6);
+    assert_point_eq(moved.vertices()[1], [0., 4., 1.]);
+    assert_point_eq(moved.centroid(), [-1., 4. / 3., 1.]);
+    let [x, y, z] = moved.normal().components();
+    assert_abs_diff_eq!(&[x, y, z][..], &[0., 0., 1.][..], epsilon = 1e-6);
+  }
+
+  #[test]
+  fn boxes() {
+    let aabb = AxisAlignedBox::new(Point::new(1., -2., 3.), Point::new(-1., 4., 0.));
+    assert_eq!(aabb.min(), Point::new(-1., -2., 0.));
+    assert_eq!(aabb.max(), Point::new(1., 4., 3.));
+    assert_point_eq(aabb.centroid(), [0., 1., 1.5]);
+
+    let obb = OrientedBox::new(translation(), [1., -2., 3.]);
+    assert_eq!(obb.half_extents(), [1., 2., 3.]);
+    assert_eq!(obb.local(), AxisAlignedBox::new(Point::new(-1., -2., -3.), Point::new(1., 2., 3.)));
+    assert_point_eq(obb.centroid(), [2., 0., 0.]);
+    let moved = obb.transform(rotation());
+    assert_point_eq(moved.centroid(), [0., 2., 0.]);
+    assert_eq!(moved.half_extents(), obb.half_extents());
+  }
+
+  #[test]
+  fn polygons() {
+    // an L shape, of area 3, in the plane y = 2
+    let vertices = [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]]
+      .map(|[x, z]| Point::new(x, 2., -z));
+    let polygon = Polygon::new(vertices).unwrap();
+    assert_eq!(polygon.vertices(), &vertices[..]);
+    assert_relative_eq!(polygon.area(), 3.);
+    assert_eq!(polygon.normal(), Direction::new(0., 1., 0.));
+    assert_eq!(polygon.plane(), Plane::new(0., 6., 0., -12.));
+    // the centroid of the two rectangles, weighted by their areas
+    assert_point_eq(polygon.centroid(), [5. / 6., 2., -5. / 6.]);
+    // a triangle as a polygon
+    let [a, b, c] = [vertices[0], vertices[1], vertices[5]];
+    let triangle = Triangle::new(a, b, c).unwrap();
+    let polygon = Polygon::new([a, b, c]).unwrap();
+    assert_eq!(polygon.plane(), triangle.plane());
+    assert_eq!(polygon.centroid(), triangle.centroid());
+
+    assert_eq!(Polygon::new([a, b]), Err(EgaError::ZeroNorm));
+    assert_eq!(Polygon::new([]), Err(EgaError::ZeroNorm));
+    assert_eq!(Polygon::new([a, b, Point::new(4., 2., 0.)]), Err(EgaError::ZeroNorm));
+
+    let moved = Polygon::new(vertices).unwrap().transform(translation());
+    assert_relative_eq!(moved.area(), 3., max_relative = 1e-6);
+    assert_point_eq(moved.centroid(), [2. + 5. / 6., 2., -5. / 6.]);
+  }
+}
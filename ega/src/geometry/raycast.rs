@@ -0,0 +1,396 @@
+//! Intersections of rays with planes & bounded primitives
+//!
+//! A ray hits the first point of a primitive at which `t >= 0`, where
+//! `origin + t * direction` is the point. Rays parallel to a plane, or lying
+//! within it, don't hit it; rays grazing an edge or a face of a bounded
+//! primitive do.
+
+use super::primitives::Transform;
+#[cfg(feature = "std")]
+use super::Polygon;
+use super::{AxisAlignedBox, OrientedBox, Plane, Point, Ray, Triangle};
+use crate::*;
+
+/// The point at which a ray hits a primitive
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit {
+  /// The point hit, with a weight of `1`
+  pub point: Trivector,
+  /// The parameter of the point along the ray, `origin + t * direction`
+  pub t: f32,
+}
+
+/// The point at which a ray hits a triangle
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TriangleHit {
+  /// The point hit, with a weight of `1`
+  pub point: Trivector,
+  /// The parameter of the point along the ray, `origin + t * direction`
+  pub t: f32,
+  /// The barycentric coordinates of the point, the weights of the vertices
+  /// which sum to it
+  pub barycentric: [f32; 3],
+}
+
+/// Primitives which rays can hit
+pub trait Raycast {
+  type Hit;
+
+  /// The first point at which the ray hits the primitive, if any
+  fn raycast(&self, ray: &Ray) -> Option<Self::Hit>;
+}
+
+impl Raycast for Plane {
+  type Hit = RayHit;
+
+  #[inline]
+  fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+    let t = plane_parameter(ray, self.0)?;
+    // the meet of the ray's line & the plane
+    let point = ray.line().0.meet(self.0);
+    Some(RayHit {
+      point: point * (1. / point.e123),
+      t,
+    })
+  }
+}
+
+/// The distance outside an edge within which a hit counts as grazing it,
+/// relative to the edge's length for a [`Polygon`], & to the height over it
+/// for a [`Triangle`]
+const EDGE: f32 = 1e-5;
+
+impl Raycast for Triangle {
+  type Hit = TriangleHit;
+
+  #[inline]
+  fn raycast(&self, ray: &Ray) -> Option<TriangleHit> {
+    let line = ray.line().0;
+    let [a, b, c] = self.vertices().map(|p| p.0);
+    // the meet of two lines is proportional to the volume of the tetrahedron
+    // spanned by them, so the meets with the edges are proportional to the
+    // areas of the triangles between the hit & each edge
+    let meets =
+      [b.join(c), c.join(a), a.join(b)].map(|edge| line.meet(edge).e0123);
+    let sum = meets[0] + meets[1] + meets[2];
+    if sum == 0. {
+      return None;
+    }
+    // each weight is the distance from an edge, relative to the triangle's
+    // height over it
+    let barycentric = meets.map(|meet| meet / sum);
+    if barycentric.iter().any(|&weight| weight < -EDGE) {
+      return None;
+    }
+    let t = plane_parameter(ray, self.plane().0)?;
+    let [u, v, w] = barycentric;
+    Some(TriangleHit {
+      point: a * u + b * v + c * w,
+      t,
+      barycentric,
+    })
+  }
+}
+
+#[cfg(feature = "std")]
+impl Raycast for Polygon {
+  type Hit = RayHit;
+
+  #[inline]
+  fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+    let hit = self.plane().raycast(ray)?;
+    // project onto the axis plane in which the polygon has the most area
+    let [x, y, z] = self.plane().normal().components();
+    let (u, v) = match (x.abs(), y.abs(), z.abs()) {
+      (x, y, z) if x >= y && x >= z => (1, 2),
+      (_, y, z) if y >= z => (2, 0),
+      _ => (0, 1),
+    };
+    let project = |point: Trivector| {
+      let coordinates = [point.e032, point.e013, point.e021];
+      [coordinates[u], coordinates[v]]
+    };
+    let [px, py] = project(hit.point);
+    let vertices = self.vertices();
+    let edges = || {
+      let ends = vertices.iter().zip(vertices.iter().cycle().skip(1));
+      ends.map(|(a, b)| (project(a.0), project(b.0)))
+    };
+    // the crossing test below is half-open, so hits on the boundary are
+    // found explicitly
+    let on_edge = edges().any(|([ax, ay], [bx, by])| {
+      let (ex, ey, dx, dy) = (bx - ax, by - ay, px - ax, py - ay);
+      let length_squared = ex * ex + ey * ey;
+      // the closest point on the edge, clamped to its ends
+      let s = ((dx * ex + dy * ey) / length_squared).clamp(0., 1.);
+      let (rx, ry) = (dx - s * ex, dy - s * ey);
+      rx * rx + ry * ry <= EDGE * EDGE * length_squared
+    });
+    if on_edge {
+      return Some(hit);
+    }
+    // count the edges crossing the ray from the hit along the first axis
+    let crossings = edges()
+      .filter(|&([ax, ay], [bx, by])| {
+        (ay > py) != (by > py) && px < ax + (py - ay) * (bx - ax) / (by - ay)
+      })
+      .count();
+    (crossings % 2 == 1).then_some(hit)
+  }
+}
+
+impl Raycast for AxisAlignedBox {
+  type Hit = RayHit;
+
+  #[inline]
+  fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+    let origin = ray.origin().coordinates();
+    let direction = ray.direction().components();
+    let (min, max) = (self.min().coordinates(), self.max().coordinates());
+    // the range of `t` within each pair of opposite faces
+    let (mut enter, mut exit) = (f32::NEG_INFINITY, f32::INFINITY);
+    for i in 0..3 {
+      if direction[i] == 0. {
+        if origin[i] < min[i] || origin[i] > max[i] {
+          return None;
+        }
+        continue;
+      }
+      let near = (min[i] - origin[i]) / direction[i];
+      let far = (max[i] - origin[i]) / direction[i];
+      enter = enter.max(near.min(far));
+      exit = exit.min(near.max(far));
+    }
+    if enter > exit || exit < 0. {
+      return None;
+    }
+    // from inside the box, the ray hits the face it leaves through
+    let t = if enter >= 0. { enter } else { exit };
+    Some(RayHit {
+      point: ray.at(t).0,
+      t,
+    })
+  }
+}
+
+impl Raycast for OrientedBox {
+  type Hit = RayHit;
+
+  #[inline]
+  fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+    // motors preserve distances, so `t` is the same in the box's frame
+    let local = ray.transform(self.motor().reverse());
+    let hit = self.local().raycast(&local)?;
+    let point = Point(hit.point).transform(self.motor());
+    Some(RayHit {
+      point: point.unitized(),
+      t: hit.t,
+    })
+  }
+}
+
+/// The parameter at which the ray meets the plane
+///
+/// The meet of a plane & a point along the ray, `origin + t * direction`, is
+/// linear in `t`.
+#[inline]
+fn plane_parameter(ray: &Ray, plane: Vector) -> Option<f32> {
+  let towards = plane.meet(ray.direction().0).e0123;
+  if towards == 0. {
+    return None;
+  }
+  let t = -plane.meet(ray.origin().0).e0123 / towards;
+  (t >= 0.).then_some(t)
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::geometry::{Direction, Unit, UnitMotor};
+  use ::approx::{assert_abs_diff_eq, assert_relative_eq};
+
+  fn ray(origin: [f32; 3], direction: [f32; 3]) -> Ray {
+    let ([x, y, z], [dx, dy, dz]) = (origin, direction);
+    Ray::new(Point::new(x, y, z), Direction::new(dx, dy, dz)).unwrap()
+  }
+
+  fn assert_hit(hit: Option<RayHit>, point: [f32; 3], t: f32) {
+    let hit = dbg!(hit).unwrap();
+    let [x, y, z] = point;
+    assert_relative_eq!(hit.point, Trivector { e123: 1., e032: x, e013: y, e021: z }, epsilon = 1e-5);
+    assert_relative_eq!(hit.t, t, epsilon = 1e-5);
+  }
+
+  #[test]
+  fn planes() {
+    // the plane z = 2, weighted by -3
+    let plane = Plane::new(0., 0., -3., 6.);
+    assert_hit(plane.raycast(&ray([1., 1., 0.], [0., 0., 1.])), [1., 1., 2.], 2.);
+    assert_hit(plane.raycast(&ray([1., 1., 5.], [1., 0., -2.])), [2.5, 1., 2.], 1.5);
+    // the origin on the plane
+    assert_hit(plane.raycast(&ray([0., 0., 2.], [0., 1., 1.])), [0., 0., 2.], 0.);
+    // pointing away
+    assert_eq!(plane.raycast(&ray([1., 1., 0.], [0., 0., -1.])), None);
+    // parallel, & lying within the plane
+    assert_eq!(plane.raycast(&ray([1., 1., 0.], [1., 0., 0.])), None);
+    assert_eq!(plane.raycast(&ray([1., 1., 2.], [1., 0., 0.])), None);
+  }
+
+  fn triangle() -> Triangle {
+    Triangle::new(Point::new(0., 0., 1.), Point::new(4., 0., 1.), Point::new(0., 4., 1.)).unwrap()
+  }
+
+  #[test]
+  fn triangles() {
+    let triangle = triangle();
+    let hit = triangle.raycast(&ray([1., 2., 3.], [0., 0., -1.])).unwrap();
+    assert_abs_diff_eq!(&hit.barycentric[..], &[0.25, 0.25, 0.5][..], epsilon = 1e-6);
+    assert_relative_eq!(hit.point, Trivector { e123: 1., e032: 1., e013: 2., e021: 1. }, epsilon = 1e-6);
+    assert_relative_eq!(hit.t, 2.);
+    // from behind, with a slanted ray
+    let hit = triangle.raycast(&ray([0., 1., -1.], [1., 0., 1.])).unwrap();
+    assert_relative_eq!(hit.point, Trivector { e123: 1., e032: 2., e013: 1., e021: 1. }, epsilon = 1e-6);
+    assert_relative_eq!(hit.t, 2.);
+    // misses, & the triangle behind the ray
+    assert_eq!(triangle.raycast(&ray([3., 3., 3.], [0., 0., -1.])), None);
+    assert_eq!(triangle.raycast(&ray([1., 1., 3.], [0., 0., 1.])), None);
+  }
+
+  #[test]
+  fn triangles_grazing() {
+    let triangle = triangle();
+    // through an edge, & a vertex
+    let hit = triangle.raycast(&ray([2., 0., 3.], [0., 0., -1.])).unwrap();
+    assert_abs_diff_eq!(&hit.barycentric[..], &[0.5, 0.5, 0.][..], epsilon = 1e-6);
+    let hit = triangle.raycast(&ray([4., 0., 3.], [0., 0., -1.])).unwrap();
+    assert_abs_diff_eq!(&hit.barycentric[..], &[0., 1., 0.][..], epsilon = 1e-6);
+    assert_relative_eq!(hit.t, 2.);
+    // through the hypotenuse, at an angle
+    let hit = triangle.raycast(&ray([0., 0., 2.], [2., 2., -1.])).unwrap();
+    assert_abs_diff_eq!(&hit.barycentric[..], &[0., 0.5, 0.5][..], epsilon = 1e-6);
+    // parallel to the triangle, & lying within it
+    assert_eq!(triangle.raycast(&ray([1., 1., 2.], [1., 0., 0.])), None);
+    assert_eq!(triangle.raycast(&ray([-1., 1., 1.], [1., 0., 0.])), None);
+  }
+
+  #[test]
+  fn triangles_grazing_rounded() {
+    // hits on the edges of random triangles, whose coordinates are rounded
+    let mut state = 0x2545_f491_u32;
+    let mut next = || {
+      state ^= state << 13;
+      state ^= state >> 17;
+      state ^= state << 5;
+      (state >> 8) as f32 / (1 << 24) as f32 * 2. - 1.
+    };
+    for _ in 0..2000 {
+      let mut point = || Point::new(next(), next(), next());
+      let Ok(triangle) = Triangle::new(point(), point(), point()) else { continue };
+      if triangle.area() < 1e-2 {
+        continue;
+      }
+      let vertices = triangle.vertices().map(Point::coordinates);
+      let [nx, ny, nz] = triangle.normal().components();
+      let s = next().abs();
+      for i in 0..3 {
+        let (a, b) = (vertices[i], vertices[(i + 1) % 3]);
+        let [x, y, z] = core::array::from_fn(|j| a[j] + s * (b[j] - a[j]));
+        let hit = triangle.raycast(&ray([x + nx, y + ny, z + nz], [-nx, -ny, -nz]));
+        assert!(dbg!(hit).is_some(), "{triangle:?} {s}");
+      }
+    }
+    // but not those clearly outside an edge
+    let triangle = triangle();
+    assert_eq!(triangle.raycast(&ray([2., -1e-3, 3.], [0., 0., -1.])), None);
+    assert!(triangle.raycast(&ray([2., -1e-6, 3.], [0., 0., -1.])).is_some());
+  }
+
+  #[test]
+  fn polygons() {
+    // an L shape in the plane y = 2
+    let polygon = Polygon::new(
+      [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]].map(|[x, z]| Point::new(x, 2., z)),
+    ).unwrap();
+    assert_hit(polygon.raycast(&ray([0.5, 0., 1.5], [0., 1., 0.])), [0.5, 2., 1.5], 2.);
+    assert_hit(polygon.raycast(&ray([1.5, 5., 0.5], [0., -3., 0.])), [1.5, 2., 0.5], 1.);
+    // the notch, & outside
+    assert_eq!(polygon.raycast(&ray([1.5, 0., 1.5], [0., 1., 0.])), None);
+    assert_eq!(polygon.raycast(&ray([-0.5, 0., 0.5], [0., 1., 0.])), None);
+    // parallel
+    assert_eq!(polygon.raycast(&ray([0.5, 2., 0.5], [0., 0., 1.])), None);
+  }
+
+  #[test]
+  fn polygons_grazing() {
+    let polygon = Polygon::new(
+      [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]].map(|[x, z]| Point::new(x, 2., z)),
+    ).unwrap();
+    // through the edges on each side, which the half-open crossing test treats
+    // differently
+    assert_hit(polygon.raycast(&ray([2., 0., 0.5], [0., 1., 0.])), [2., 2., 0.5], 2.);
+    assert_hit(polygon.raycast(&ray([0., 0., 0.5], [0., 1., 0.])), [0., 2., 0.5], 2.);
+    assert_hit(polygon.raycast(&ray([0.5, 0., 2.], [0., 1., 0.])), [0.5, 2., 2.], 2.);
+    assert_hit(polygon.raycast(&ray([0.5, 0., 0.], [0., 1., 0.])), [0.5, 2., 0.], 2.);
+    // through vertices, including the inner corner, at an angle
+    assert_hit(polygon.raycast(&ray([2., 0., 1.], [0., 1., 0.])), [2., 2., 1.], 2.);
+    assert_hit(polygon.raycast(&ray([0., 1., 0.], [1., 1., 1.])), [1., 2., 1.], 1.);
+    assert_hit(polygon.raycast(&ray([2., 3., 3.], [-1., -1., -1.])), [1., 2., 2.], 1.);
+    // just outside an edge
+    assert_eq!(polygon.raycast(&ray([2.001, 0., 0.5], [0., 1., 0.])), None);
+  }
+
+  fn aabb() -> AxisAlignedBox {
+    AxisAlignedBox::new(Point::new(0., 0., 0.), Point::new(2., 4., 6.))
+  }
+
+  #[test]
+  fn axis_aligned_boxes() {
+    let aabb = aabb();
+    assert_hit(aabb.raycast(&ray([-2., 1., 1.], [1., 0., 0.])), [0., 1., 1.], 2.);
+    assert_hit(aabb.raycast(&ray([1., 1., 10.], [0., 0., -2.])), [1., 1., 6.], 2.);
+    assert_hit(aabb.raycast(&ray([3., 5., 7.], [-1., -1., -1.])), [2., 4., 6.], 1.);
+    // from inside, through the face it leaves
+    assert_hit(aabb.raycast(&ray([1., 1., 1.], [0., 1., 0.])), [1., 4., 1.], 3.);
+    // misses, & behind
+    assert_eq!(aabb.raycast(&ray([-2., 1., 1.], [1., 3., 0.])), None);
+    assert_eq!(aabb.raycast(&ray([-2., 1., 1.], [-1., 0., 0.])), None);
+  }
+
+  #[test]
+  fn axis_aligned_boxes_grazing() {
+    let aabb = aabb();
+    // along a face, & an edge
+    assert_hit(aabb.raycast(&ray([-1., 4., 1.], [1., 0., 0.])), [0., 4., 1.], 1.);
+    assert_hit(aabb.raycast(&ray([-1., 4., 6.], [1., 0., 0.])), [0., 4., 6.], 1.);
+    // touching a corner
+    assert_hit(aabb.raycast(&ray([-1., 5., 3.], [1., -1., 0.])), [0., 4., 3.], 1.);
+    assert_hit(aabb.raycast(&ray([-1., -1., -1.], [1., 1., 1.])), [0., 0., 0.], 1.);
+    // parallel to a face, just outside it
+    assert_eq!(aabb.raycast(&ray([-1., 4.001, 1.], [1., 0., 0.])), None);
+  }
+
+  /// A quarter turn about the z axis, then a translation by 2 along x
+  fn motor() -> UnitMotor {
+    // the products of reflections in pairs of planes
+    let motor = |a: [f32; 4], b: [f32; 4]| {
+      let [a, b] = [a, b].map(|[a, b, c, d]| Vector::from(Plane::new(a, b, c, d)));
+      Motor::from_even(b * a)
+    };
+    let rotation = motor([-1., 1., 0., 0.], [1., 0., 0., 0.]);
+    let translation = motor([1., 0., 0., 0.], [1., 0., 0., -1.]);
+    Unit::new(translation * rotation)
+  }
+
+  #[test]
+  fn oriented_boxes() {
+    // spanning `[0, 4] × [-1, 1] × [-3, 3]`
+    let obb = OrientedBox::new(motor(), [1., 2., 3.]);
+    assert_hit(obb.raycast(&ray([-1., 0.5, 0.], [1., 0., 0.])), [0., 0.5, 0.], 1.);
+    assert_hit(obb.raycast(&ray([3., 5., 2.], [0., -2., 0.])), [3., 1., 2.], 2.);
+    assert_hit(obb.raycast(&ray([1., 0., 0.], [0., 0., 1.])), [1., 0., 3.], 3.);
+    assert_eq!(obb.raycast(&ray([-1., 1.5, 0.], [1., 0., 0.])), None);
+    // grazing the face `y = 1`
+    assert_hit(obb.raycast(&ray([-1., 1., 0.], [1., 0., 0.])), [0., 1., 0.], 1.);
+  }
+}
@@ -0,0 +1,528 @@
+//! Exact orientation predicates
+//!
+//! The products of the algebra are evaluated in `f32`, so for nearly
+//! degenerate configurations the sign of a join may be wrong. These
+//! predicates first evaluate in `f64` with an error bound, & fall back to
+//! exact arithmetic when the bound can't decide the sign, so the result is
+//! correct for any finite input.
+//!
+//! The exact arithmetic sums expansions of `f64` components, as in
+//! Shewchuk's *Adaptive Precision Floating-Point Arithmetic & Fast Robust
+//! Geometric Predicates*. The products of two `f32`s are exact in `f64`, &
+//! the products of up to five stay within its normal range, so no
+//! intermediate value is ever rounded; see [`insphere`] for its range.
+
+use super::Side;
+use crate::*;
+use core::cmp::Ordering;
+
+/// The sign of the join of four points, `a ∨ b ∨ c ∨ d`
+///
+/// This is `Greater` when `d` lies behind the plane `a ∨ b ∨ c`, `Less` when
+/// it lies in front, & `Equal` when the four points are coplanar. The points
+/// may be weighted, or ideal.
+#[inline]
+pub fn orient3d(
+  a: impl Into<Trivector>,
+  b: impl Into<Trivector>,
+  c: impl Into<Trivector>,
+  d: impl Into<Trivector>,
+) -> Ordering {
+  let rows = [a.into(), b.into(), c.into(), d.into()]
+    .map(|p| [p.e123, p.e032, p.e013, p.e021].map(f64::from));
+
+  // each term is rounded once, & then summed with 23 more roundings
+  let (mut determinant, mut permanent) = (0., 0.);
+  for_each_permutation(|[i, j, k, l]: [usize; 4], sign| {
+    let term = (rows[i][0] * rows[j][1]) * (rows[k][2] * rows[l][3]);
+    determinant += sign * term;
+    permanent += term.abs();
+  });
+  if determinant.abs() > 1e-14 * permanent {
+    return sign_of(determinant);
+  }
+
+  let mut exact = Expansion::<48>::new();
+  for_each_permutation(|[i, j, k, l]: [usize; 4], sign| {
+    exact.add_product(sign * rows[i][0] * rows[j][1], rows[k][2] * rows[l][3]);
+  });
+  exact.sign()
+}
+
+/// Whether `e` lies inside the sphere through the finite points `a`, `b`,
+/// `c` & `d`
+///
+/// When `orient3d(a, b, c, d)` is `Greater` this is `Greater` inside the
+/// sphere, `Less` outside, & `Equal` when the five points are cospherical;
+/// the sign is reversed when the orientation is `Less`. The points may have
+/// any non-zero weight, & are compared by their unitized coordinates.
+///
+/// Each point is lifted homogeneously, to `(xw, yw, zw, x² + y² + z², w²)`,
+/// which is its lift onto the paraboloid scaled by `w²`, so the points are
+/// never unitized. Scaling a point by a positive factor doesn't change the
+/// sign, so each is first scaled by a power of two, exactly, to bring its
+/// largest component near `1`. The result is then exact while each point's
+/// non-zero components are within a factor of `2^100`, about `1e30`, of its
+/// largest, whatever their magnitude.
+#[inline]
+pub fn insphere(
+  a: impl Into<Trivector>,
+  b: impl Into<Trivector>,
+  c: impl Into<Trivector>,
+  d: impl Into<Trivector>,
+  e: impl Into<Trivector>,
+) -> Ordering {
+  // the products of two `f32`s are exact in `f64`
+  let rows = [a.into(), b.into(), c.into(), d.into(), e.into()].map(|p| {
+    let components = [p.e123, p.e032, p.e013, p.e021].map(f64::from);
+    let largest = components.iter().fold(0., |max: f64, c| max.max(c.abs()));
+    let scale = inverse_power_of_two(largest);
+    let [w, x, y, z] = components.map(|c| c * scale);
+    let mut lift = Expansion::<3>::new();
+    for square in [x * x, y * y, z * z] {
+      lift.add(square);
+    }
+    ([x * w, y * w, z * w], lift, w * w)
+  });
+
+  // each term is rounded at most six times, including the estimate of the
+  // lift, & then summed with 119 more roundings
+  let (mut determinant, mut permanent) = (0., 0.);
+  for_each_permutation(|[i, j, k, l, m]: [usize; 5], sign| {
+    let term = rows[i].0[0]
+      * rows[j].0[1]
+      * rows[k].0[2]
+      * rows[l].1.estimate()
+      * rows[m].2;
+    determinant += sign * term;
+    permanent += term.abs();
+  });
+  if determinant.abs() > 1e-13 * permanent {
+    return sign_of(-determinant);
+  }
+
+  // there are at most 2098 non-overlapping components between the smallest &
+  // largest `f64`s
+  let mut exact = Expansion::<2098>::new();
+  for_each_permutation(|[i, j, k, l, m]: [usize; 5], sign| {
+    let mut term = Expansion::<1>::new();
+    term.add(-sign * rows[i].0[0]);
+    let term = term
+      .scale::<2>(rows[j].0[1])
+      .scale::<4>(rows[k].0[2])
+      .scale::<8>(rows[m].2);
+    for &lift in rows[l].1.components() {
+      for &component in term.components() {
+        exact.add_product(component, lift);
+      }
+    }
+  });
+  exact.sign()
+}
+
+/// Which side of the plane the finite point lies on, exactly
+///
+/// Unlike [`side`] there's no tolerance: the point is only `On` the plane
+/// when it lies exactly on it. Ideal points are always `On`.
+///
+/// [`side`]: crate::geometry::predicates::side
+#[inline]
+pub fn exact_side(
+  point: impl Into<Trivector>,
+  plane: impl Into<Vector>,
+) -> Side {
+  let (x, p) = (point.into(), plane.into());
+  let mut meet = Expansion::<8>::new();
+  for (a, b) in [
+    (p.e0, x.e123),
+    (p.e1, x.e032),
+    (p.e2, x.e013),
+    (p.e3, x.e021),
+  ] {
+    meet.add_product(a.into(), b.into());
+  }
+  // the meet is scaled by the weight of the point
+  let sign = match sign_of(x.e123.into()) {
+    Ordering::Greater => meet.sign(),
+    Ordering::Less => meet.sign().reverse(),
+    Ordering::Equal => Ordering::Equal,
+  };
+  match sign {
+    Ordering::Greater => Side::Front,
+    Ordering::Less => Side::Back,
+    Ordering::Equal => Side::On,
+  }
+}
+
+/// A value represented exactly by the sum of its non-overlapping, non-zero
+/// components, in order of increasing magnitude
+#[derive(Copy, Clone, Debug)]
+struct Expansion<const N: usize> {
+  components: [f64; N],
+  len: usize,
+}
+
+impl<const N: usize> Expansion<N> {
+  #[inline]
+  const fn new() -> Self {
+    Self {
+      components: [0.; N],
+      len: 0,
+    }
+  }
+
+  #[inline]
+  fn components(&self) -> &[f64] {
+    &self.components[..self.len]
+  }
+
+  /// Add `value` exactly, keeping the components in order & dropping those
+  /// which are zero
+  #[inline]
+  fn add(&mut self, mut value: f64) {
+    let mut len = 0;
+    for i in 0..self.len {
+      let (sum, error) = two_sum(value, self.components[i]);
+      if error != 0. {
+        self.components[len] = error;
+        len += 1;
+      }
+      value = sum;
+    }
+    if value != 0. {
+      self.components[len] = value;
+      len += 1;
+    }
+    self.len = len;
+  }
+
+  /// Add the product `a * b` exactly
+  #[inline]
+  fn add_product(&mut self, a: f64, b: f64) {
+    let (product, error) = two_product(a, b);
+    self.add(error);
+    self.add(product);
+  }
+
+  /// The product with `b`, exactly, which has at most twice as many
+  /// components
+  #[inline]
+  fn scale<const M: usize>(&self, b: f64) -> Expansion<M> {
+    let mut product = Expansion::new();
+    for &component in self.components() {
+      product.add_product(component, b);
+    }
+    product
+  }
+
+  /// The sum of the components, rounded
+  #[inline]
+  fn estimate(&self) -> f64 {
+    self.components().iter().sum()
+  }
+
+  /// The sign of the value, which is that of its largest component
+  #[inline]
+  fn sign(&self) -> Ordering {
+    self
+      .components()
+      .last()
+      .map_or(Ordering::Equal, |&c| sign_of(c))
+  }
+}
+
+/// The inverse of the largest power of two no greater than `value`, which
+/// is positive & normal
+#[inline]
+fn inverse_power_of_two(value: f64) -> f64 {
+  const BIAS: u64 = 1023;
+  let exponent = value.to_bits() >> 52;
+  if exponent == 0 {
+    return 1.;
+  }
+  f64::from_bits((2 * BIAS - exponent) << 52)
+}
+
+#[inline]
+fn sign_of(value: f64) -> Ordering {
+  if value > 0. {
+    Ordering::Greater
+  } else if value < 0. {
+    Ordering::Less
+  } else {
+    Ordering::Equal
+  }
+}
+
+/// The rounded sum of `a` & `b`, & its rounding error
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+  let sum = a + b;
+  let b_virtual = sum - a;
+  let a_virtual = sum - b_virtual;
+  (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+/// Split `a` into two halves of 26 significant bits, whose products are
+/// exact
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+  // 2^27 + 1
+  const SPLITTER: f64 = 134_217_729.;
+  let c = SPLITTER * a;
+  let high = c - (c - a);
+  (high, a - high)
+}
+
+/// The rounded product of `a` & `b`, & its rounding error
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+  let product = a * b;
+  let ((a_high, a_low), (b_high, b_low)) = (split(a), split(b));
+  let error = product - a_high * b_high - a_low * b_high - a_high * b_low;
+  (product, a_low * b_low - error)
+}
+
+/// Call `f` with each permutation of `0..N` & its sign
+///
+/// Uses Heap's algorithm, in which each permutation swaps a single pair of
+/// the previous one, so flips the sign.
+#[inline]
+fn for_each_permutation<const N: usize>(mut f: impl FnMut([usize; N], f64)) {
+  let mut permutation = core::array::from_fn(|i| i);
+  let mut counters = [0; N];
+  let mut sign = 1.;
+  f(permutation, sign);
+  let mut i = 1;
+  while i < N {
+    if counters[i] < i {
+      let j = if i % 2 == 0 { 0 } else { counters[i] };
+      permutation.swap(j, i);
+      sign = -sign;
+      f(permutation, sign);
+      counters[i] += 1;
+      i = 1;
+    } else {
+      counters[i] = 0;
+      i += 1;
+    }
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::geometry::Point;
+
+  /// The exact sign of the determinant of integer rows
+  fn exact_determinant<const N: usize>(rows: [[i128; N]; N]) -> Ordering {
+    let mut determinant = 0;
+    for_each_permutation(|permutation: [usize; N], sign| {
+      let term: i128 = (0..N).map(|c| rows[permutation[c]][c]).product();
+      determinant += sign as i128 * term;
+    });
+    determinant.cmp(&0)
+  }
+
+  fn exact_orient3d(points: [[i32; 3]; 4]) -> Ordering {
+    exact_determinant(points.map(|[x, y, z]| {
+      [1, x as i128, y as i128, z as i128]
+    }))
+  }
+
+  fn exact_insphere(points: [[i32; 3]; 5]) -> Ordering {
+    exact_determinant(points.map(|[x, y, z]| {
+      let [x, y, z] = [x as i128, y as i128, z as i128];
+      [x, y, z, x*x + y*y + z*z, 1]
+    })).reverse()
+  }
+
+  fn point([x, y, z]: [i32; 3]) -> Point {
+    Point::new(x as f32, y as f32, z as f32)
+  }
+
+  #[test]
+  fn permutations() {
+    let mut count = 0;
+    let mut sum = 0.;
+    for_each_permutation(|_: [usize; 4], sign| { count += 1; sum += sign; });
+    assert_eq!(count, 24);
+    assert_eq!(sum, 0.);
+    assert_eq!(exact_determinant([[2, 0, 0], [0, 3, 0], [0, 0, 5]]), Ordering::Greater);
+    assert_eq!(exact_determinant([[0, 1, 0], [1, 0, 0], [0, 0, 1]]), Ordering::Less);
+  }
+
+  #[test]
+  fn orient3d_agrees_with_join() {
+    let [a, b, c, d] = [
+      Point::new(0., 0., 0.),
+      Point::new(1., 0., 0.),
+      Point::new(0., 1., 0.),
+      Point::new(0., 0., 1.),
+    ];
+    let join = |a: Point, b, c, d| {
+      Trivector::from(a).join(Trivector::from(b)).join(Trivector::from(c))
+        .join(Trivector::from(d)).s
+    };
+    assert_eq!(dbg!(orient3d(a, b, c, d)), sign_of(join(a, b, c, d).into()));
+    assert_eq!(orient3d(a, b, c, d), Ordering::Greater);
+    assert_eq!(orient3d(b, a, c, d), Ordering::Less);
+    assert_eq!(orient3d(a, b, c, Point::new(3., -1., -2.)), Ordering::Less);
+    assert_eq!(orient3d(a, b, c, Point::new(-7., 4., 0.)), Ordering::Equal);
+    // behind the plane `a ∨ b ∨ c`
+    let plane = Trivector::from(a).join(Trivector::from(b))
+      .join(Trivector::from(c));
+    assert_eq!(exact_side(d, plane), Side::Back);
+    // weights scale the join, so a negative weight flips it
+    let d = -Trivector::from(d) * Scalar { s: 2. };
+    assert_eq!(orient3d(a, b, c, d), Ordering::Less);
+    // an ideal point, the direction +z
+    let d = Trivector { e123: 0., e032: 0., e013: 0., e021: 1. };
+    assert_eq!(orient3d(a, b, c, d), Ordering::Greater);
+  }
+
+  #[test]
+  fn orient3d_degenerate() {
+    // coplanar points far from the origin, where the `f32` join is rounded
+    let offset = 1 << 22;
+    let points = [[0, 0, 0], [3, 5, 8], [7, 2, 9], [-4, 11, 7]]
+      .map(|[x, y, z]| [x + offset, y + offset, z + 2 * offset]);
+    assert_eq!(orient3d(point(points[0]), point(points[1]), point(points[2]), point(points[3])), Ordering::Equal);
+    // perturbing the last point by one unit, in each direction
+    for dx in -1..=1 { for dy in -1..=1 { for dz in -1..=1 {
+      let [x, y, z] = points[3];
+      let mut points = points;
+      points[3] = [x + dx, y + dy, z + dz];
+      let [a, b, c, d] = points.map(point);
+      assert_eq!(orient3d(a, b, c, d), exact_orient3d(points));
+      assert_eq!(orient3d(d, c, b, a), exact_orient3d(points));
+      assert_eq!(orient3d(b, a, c, d), exact_orient3d(points).reverse());
+    }}}
+    // a tiny offset from a huge triangle
+    let a = Point::new(1e30, 0., 0.);
+    let b = Point::new(0., 1e30, 0.);
+    let c = Point::new(-1e30, -1e30, 0.);
+    assert_eq!(orient3d(a, b, c, Point::new(1e-30, 3e-30, 1e-45)), Ordering::Greater);
+    assert_eq!(orient3d(a, b, c, Point::new(1e-30, 3e-30, -1e-45)), Ordering::Less);
+    assert_eq!(orient3d(a, b, c, Point::new(1e-30, 3e-30, 0.)), Ordering::Equal);
+    // collinear & coincident points
+    assert_eq!(orient3d(a, a, b, c), Ordering::Equal);
+    let d = Point::new(2e30, -1e30, 0.);
+    assert_eq!(orient3d(a, b, d, Point::new(0., 0., 1.)), Ordering::Equal);
+  }
+
+  #[test]
+  fn insphere_signs() {
+    let [a, b, c, d] = [
+      Point::new(0., 0., 0.),
+      Point::new(1., 0., 0.),
+      Point::new(0., 1., 0.),
+      Point::new(0., 0., 1.),
+    ];
+    assert_eq!(orient3d(a, b, c, d), Ordering::Greater);
+    assert_eq!(insphere(a, b, c, d, Point::new(0.25, 0.25, 0.25)), Ordering::Greater);
+    assert_eq!(insphere(a, b, c, d, Point::new(2., 2., 2.)), Ordering::Less);
+    // the circumsphere has its centre at (0.5, 0.5, 0.5)
+    assert_eq!(insphere(a, b, c, d, Point::new(1., 1., 1.)), Ordering::Equal);
+    assert_eq!(insphere(a, b, c, d, Point::new(1., 1., 0.)), Ordering::Equal);
+    // reversing the orientation reverses the sign
+    assert_eq!(insphere(b, a, c, d, Point::new(0.25, 0.25, 0.25)), Ordering::Less);
+    // the coordinates of weighted points are used
+    let e = Trivector::from(Point::new(0.25, 0.25, 0.25)) * Scalar { s: -4. };
+    assert_eq!(insphere(a, b, c, d, e), Ordering::Greater);
+  }
+
+  #[test]
+  fn insphere_degenerate() {
+    // cospherical points far from the origin, on a sphere of radius 9
+    let offset = 1 << 20;
+    let points = [[9, 0, 0], [0, 9, 0], [0, 0, 9], [-1, 4, 8], [4, -8, 1]]
+      .map(|[x, y, z]| [x + offset, y - offset, z + offset]);
+    let [a, b, c, d, e] = points.map(point);
+    assert_eq!(insphere(a, b, c, d, e), Ordering::Equal);
+    for dx in -1..=1 { for dy in -1..=1 { for dz in -1..=1 {
+      let [x, y, z] = points[4];
+      let mut points = points;
+      points[4] = [x + dx, y + dy, z + dz];
+      let [a, b, c, d, e] = points.map(point);
+      assert_eq!(insphere(a, b, c, d, e), exact_insphere(points));
+      assert_eq!(insphere(b, a, c, d, e), exact_insphere(points).reverse());
+    }}}
+    // four coplanar points have no sphere
+    let [a, b, c] = [[0, 0, 0], [1, 0, 0], [0, 1, 0]].map(point);
+    let d = point([1, 1, 0]);
+    assert_eq!(insphere(a, b, c, d, point([5, 5, 5])), Ordering::Equal);
+  }
+
+  #[test]
+  fn insphere_weighted() {
+    // points on the sphere of radius 1/3 about the origin, whose coordinates
+    // aren't representable, so would be rounded by unitizing them
+    let weighted = |[x, y, z, w]: [f32; 4]| Trivector { e123: w, e032: x, e013: y, e021: z };
+    let [a, b, c, d, e] = [
+      [1., 0., 0., 3.],
+      [0., 1., 0., 3.],
+      [0., 0., 1., 3.],
+      [2., 2., 1., 9.],
+      [-2., 1., -2., 9.],
+    ].map(weighted);
+    assert_eq!(orient3d(a, b, c, d), Ordering::Greater);
+    assert_eq!(insphere(a, b, c, d, e), Ordering::Equal);
+    assert_eq!(insphere(a, b, c, d, weighted([0., 0., 0., 1.])), Ordering::Greater);
+    assert_eq!(insphere(a, b, c, d, weighted([1., 0., 0., 2.])), Ordering::Less);
+    assert_eq!(insphere(a, b, c, d, weighted([1., 0., 0., 4.])), Ordering::Greater);
+    // the weights' scale & signs don't matter
+    let e = weighted([-6., 3., -6., 27.]) * Scalar { s: -1. };
+    assert_eq!(insphere(a, b, c, d, e), Ordering::Equal);
+    let [a, b] = [a, b].map(|p| p * Scalar { s: 0.5 });
+    assert_eq!(insphere(a, b, c, d, weighted([1., 0., 0., 2.])), Ordering::Less);
+  }
+
+  #[test]
+  fn insphere_scaled() {
+    // the points of `insphere_weighted`, whose products would underflow or
+    // overflow `f64` unless they were scaled back
+    for exponent in [-120, -126, 100, 120] {
+      let scale = f32::from_bits(((127 + exponent) as u32) << 23);
+      let weighted = |[x, y, z, w]: [f32; 4]| Trivector { e123: w, e032: x, e013: y, e021: z } * Scalar { s: scale };
+      let [a, b, c, d, e] = [
+        [1., 0., 0., 3.],
+        [0., 1., 0., 3.],
+        [0., 0., 1., 3.],
+        [2., 2., 1., 9.],
+        [-2., 1., -2., 9.],
+      ].map(weighted);
+      assert_eq!(insphere(a, b, c, d, e), Ordering::Equal, "{scale}");
+      assert_eq!(insphere(a, b, c, d, weighted([0., 0., 0., 1.])), Ordering::Greater, "{scale}");
+      assert_eq!(insphere(a, b, c, d, weighted([1., 0., 0., 2.])), Ordering::Less, "{scale}");
+    }
+    assert_eq!(inverse_power_of_two(1.), 1.);
+    assert_eq!(inverse_power_of_two(3.), 0.5);
+    assert_eq!(inverse_power_of_two(0.3), 4.);
+  }
+
+  #[test]
+  fn exact_sides() {
+    // the plane x + y + z = 0.3, whose coefficient isn't representable, so
+    // the plane is `x + y + z = d` for the rounded `d`
+    let d = 0.3f32;
+    let plane = Vector { e0: -d, e1: 1., e2: 1., e3: 1. };
+    let on = Point::new(d, 0., 0.);
+    assert_eq!(exact_side(on, plane), Side::On);
+    let above = Point::new(f32::from_bits(d.to_bits() + 1), 0., 0.);
+    let below = Point::new(f32::from_bits(d.to_bits() - 1), 0., 0.);
+    assert_eq!(exact_side(above, plane), Side::Front);
+    assert_eq!(exact_side(below, plane), Side::Back);
+    // cancellation the `f32` meet gets wrong
+    let plane = Vector { e0: 1e-30, e1: 1., e2: -1., e3: 0. };
+    let point = Point::new(1e30, 1e30, 0.);
+    assert_eq!(plane.meet(Trivector::from(point)).e0123, 0.);
+    assert_eq!(exact_side(point, plane), Side::Front);
+    // weights don't change the side
+    let point = Trivector::from(point) * Scalar { s: -3. };
+    assert_eq!(exact_side(point, plane), Side::Front);
+    let ideal = Trivector { e123: 0., e032: 1., e013: 0., e021: 0. };
+    assert_eq!(exact_side(ideal, plane), Side::On);
+  }
+}
@@ -0,0 +1,261 @@
+use crate::*;
+use ::core::ops::Deref;
+
+/// The tolerance on the squared norm of a [`Unit`], beyond which it's
+/// considered to have drifted
+const DRIFT_EPSILON: f32 = 1e-3;
+
+/// A normalised value
+///
+/// Can only be constructed by normalising a value, or by checking that it's
+/// already normalised. The operations which preserve normalisation, such as
+/// the composition of motors, return a `Unit` again, & check for drift with a
+/// `debug_assert`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct Unit<T>(T);
+
+/// A normalised [`Motor`], with `M M̃ = 1`
+pub type UnitMotor = Unit<Motor>;
+/// A normalised point, with a weight of `±1`
+pub type UnitPoint = Unit<Trivector>;
+/// A normalised plane, with a unit normal
+pub type UnitPlane = Unit<Vector>;
+
+/// Whether a value is normalised
+pub trait IsUnit {
+  /// Whether the squared norm is within `epsilon` of `1`
+  fn is_unit(&self, epsilon: f32) -> bool;
+}
+
+impl IsUnit for Scalar {
+  #[inline]
+  fn is_unit(&self, epsilon: f32) -> bool {
+    (self.norm_squared().s - 1.).abs() <= epsilon
+  }
+}
+
+impl IsUnit for Vector {
+  #[inline]
+  fn is_unit(&self, epsilon: f32) -> bool {
+    (self.norm_squared().s - 1.).abs() <= epsilon
+  }
+}
+
+impl IsUnit for Trivector {
+  #[inline]
+  fn is_unit(&self, epsilon: f32) -> bool {
+    (self.norm_squared().s - 1.).abs() <= epsilon
+  }
+}
+
+impl IsUnit for Motor {
+  /// Whether `M M̃` is within `epsilon` of `1`
+  #[inline]
+  fn is_unit(&self, epsilon: f32) -> bool {
+    let squared = self
+      .to_multivector()
+      .mul_multivector(self.reverse().to_multivector());
+    (squared.s - 1.).abs() <= epsilon && squared.e0123.abs() <= epsilon
+  }
+}
+
+impl<T: Normalise + IsUnit> Unit<T> {
+  /// Normalise the value
+  ///
+  /// A value with a norm of zero gives NaNs; see [`try_new`](Self::try_new).
+  #[inline]
+  pub fn new(value: T) -> Unit<T> {
+    Unit(value.normalise())
+  }
+
+  /// Normalise the value, or [`EgaError::ZeroNorm`] if its norm is zero
+  #[inline]
+  pub fn try_new(value: T) -> Result<Unit<T>, EgaError>
+  where
+    T: TryNormalise,
+  {
+    value.try_normalise().map(Unit)
+  }
+
+  /// Wrap a value which is already normalised, to within `epsilon`, or
+  /// [`EgaError::NotUnit`] if it isn't
+  #[inline]
+  pub fn new_checked(value: T, epsilon: f32) -> Result<Unit<T>, EgaError> {
+    match value.is_unit(epsilon) {
+      true => Ok(Unit(value)),
+      false => Err(EgaError::NotUnit),
+    }
+  }
+
+  /// Normalise the value again, correcting any accumulated drift
+  #[inline]
+  pub fn renormalise(self) -> Unit<T> {
+    Unit(self.0.normalise())
+  }
+
+  /// Wrap the result of an operation which preserves normalisation
+  #[inline]
+  fn preserved(value: T) -> Unit<T> {
+    debug_assert!(
+      value.is_unit(DRIFT_EPSILON),
+      "normalisation has drifted; consider `renormalise`"
+    );
+    Unit(value)
+  }
+}
+
+impl<T> Unit<T> {
+  /// The normalised value
+  #[inline]
+  pub fn into_inner(self) -> T {
+    self.0
+  }
+}
+
+impl<T> Deref for Unit<T> {
+  type Target = T;
+
+  #[inline]
+  fn deref(&self) -> &T {
+    &self.0
+  }
+}
+
+impl<T: Neg<Output = T>> Neg for Unit<T> {
+  type Output = Unit<T>;
+
+  #[inline]
+  fn neg(self) -> Unit<T> {
+    Unit(-self.0)
+  }
+}
+
+impl From<UnitMotor> for Motor {
+  #[inline]
+  fn from(unit: UnitMotor) -> Motor {
+    unit.0
+  }
+}
+
+impl From<UnitPoint> for Trivector {
+  #[inline]
+  fn from(unit: UnitPoint) -> Trivector {
+    unit.0
+  }
+}
+
+impl From<UnitPlane> for Vector {
+  #[inline]
+  fn from(unit: UnitPlane) -> Vector {
+    unit.0
+  }
+}
+
+impl UnitMotor {
+  /// The identity motor
+  pub const IDENTITY: UnitMotor = Unit(Motor::identity());
+
+  /// The reverse, which is also the inverse
+  #[inline]
+  pub fn reverse(self) -> UnitMotor {
+    Unit(self.0.reverse())
+  }
+
+  /// The logarithm, see [`Motor::log`]
+  #[inline]
+  pub fn log(self) -> Bivector {
+    self.0.log()
+  }
+
+  /// Transform a point by the motor
+  #[inline]
+  pub fn transform_point(self, point: UnitPoint) -> UnitPoint {
+    Unit::preserved(self.0.sandwich(point.0.into()).grade_3())
+  }
+
+  /// Transform a plane by the motor
+  #[inline]
+  pub fn transform_plane(self, plane: UnitPlane) -> UnitPlane {
+    Unit::preserved(self.0.sandwich(plane.0.into()).grade_1())
+  }
+}
+
+impl Mul<UnitMotor> for UnitMotor {
+  type Output = UnitMotor;
+
+  /// The composition of the motors
+  #[inline]
+  fn mul(self, rhs: UnitMotor) -> UnitMotor {
+    Unit::preserved(self.0 * rhs.0)
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+  use ::approx::assert_relative_eq;
+
+  fn motor() -> Motor {
+    Motor::from_even(
+      (VECTOR_A * VECTOR_B) * (VECTOR_C * Vector { e0: 1., e1: 2., e2: 0., e3: 0. }),
+    )
+  }
+
+  #[test]
+  fn new() {
+    let unit = Unit::new(VECTOR_A);
+    assert_eq!(dbg!(*unit), VECTOR_A.normalise());
+    assert!(unit.is_unit(1e-6));
+    assert!(Unit::new(motor()).is_unit(1e-5));
+  }
+
+  #[test]
+  fn try_new() {
+    assert_eq!(Unit::try_new(TRIVECTOR_A).map(Unit::into_inner), Ok(TRIVECTOR_A.normalise()));
+    assert_eq!(Unit::try_new(Vector::ZERO), Err(EgaError::ZeroNorm));
+    assert_eq!(Unit::try_new(Motor::ZERO), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn new_checked() {
+    let plane = Vector { e0: 5., e1: 0.6, e2: 0.8, e3: 0. };
+    assert_eq!(Unit::new_checked(plane, 1e-6).map(Vector::from), Ok(plane));
+    assert_eq!(Unit::new_checked(VECTOR_A, 1e-6), Err(EgaError::NotUnit));
+    assert_eq!(Unit::new_checked(Motor::identity(), 0.), Ok(UnitMotor::IDENTITY));
+    assert_eq!(Unit::new_checked(motor(), 1e-6), Err(EgaError::NotUnit));
+  }
+
+  #[test]
+  fn compose() {
+    let a = Unit::new(motor());
+    let b = Unit::new(Motor { s: 0.6, e12: 0.8, e03: 1., e0123: 0., ..Motor::ZERO });
+    let result = a * b * a.reverse();
+    assert!(result.is_unit(1e-5));
+    assert_relative_eq!(*(a * a.reverse()), Motor::identity(), epsilon = 1e-5);
+  }
+
+  #[test]
+  fn transform() {
+    let motor = Unit::new(motor());
+    let point = Unit::new(TRIVECTOR_A);
+    let result = motor.transform_point(point);
+    assert!(result.is_unit(1e-5));
+    let mut expected = [Trivector::ZERO];
+    motor.transform_points(&[*point], &mut expected);
+    assert_relative_eq!(*result, expected[0], max_relative = 1e-5);
+
+    let plane = Unit::new(VECTOR_A);
+    assert!(motor.transform_plane(plane).is_unit(1e-5));
+  }
+
+  #[test]
+  fn log() {
+    // a rotation by twice atan(0.8 / 0.6) about the z axis
+    let motor = Unit::new(Motor { s: 0.6, e12: 0.8, ..Motor::ZERO });
+    let expected = Bivector { e12: 0.8f32.atan2(0.6), ..Bivector::ZERO };
+    assert_relative_eq!(motor.log(), expected, epsilon = 1e-6);
+  }
+}
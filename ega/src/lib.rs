@@ -3,10 +3,19 @@
 #[cfg(feature = "libm")]
 use libm::Libm;
 
+pub mod bulk;
+pub mod conventions;
+mod error;
+pub mod geometry;
 mod operators;
 mod optional_features;
+pub mod simd;
 mod values;
+#[cfg(feature = "std")]
+pub mod visualise;
+pub mod wide;
 
+pub use error::EgaError;
 pub use operators::*;
 pub use values::*;
 
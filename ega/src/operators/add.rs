@@ -1,7 +1,8 @@
 use super::{return_lhs, return_rhs};
 use crate::*;
+use ::core::iter::Sum;
 
-pub use ::core::ops::Add;
+pub use ::core::ops::{Add, AddAssign};
 
 macro_rules! impl_add {
   ($add_fn:ident: $lhs:ty, $rhs:ty => $output:ty) => {
@@ -13,6 +14,8 @@ macro_rules! impl_add {
         $add_fn(self, rhs)
       }
     }
+
+    impl_ref_ops! { Add::add: $lhs, $rhs }
   };
 }
 
@@ -72,6 +75,30 @@ impl_add! { return_rhs: Empty, Bivector => Bivector }
 impl_add! { return_rhs: Empty, Trivector => Trivector }
 impl_add! { return_rhs: Empty, Pseudoscalar => Pseudoscalar }
 
+impl_assign_ops! { Add::add, AddAssign::add_assign: Multivector, Scalar, Vector, Bivector, Trivector, Pseudoscalar, Empty }
+
+macro_rules! impl_sum {
+  ($($type:ty),*) => {
+    $(
+      impl Sum for $type {
+        #[inline]
+        fn sum<I: Iterator<Item = $type>>(iter: I) -> $type {
+          iter.fold(zero(), |acc, value| acc + value)
+        }
+      }
+
+      impl<'a> Sum<&'a $type> for $type {
+        #[inline]
+        fn sum<I: Iterator<Item = &'a $type>>(iter: I) -> $type {
+          iter.fold(zero(), |acc, value| acc + value)
+        }
+      }
+    )*
+  };
+}
+
+impl_sum! { Multivector, Scalar, Vector, Bivector, Trivector, Pseudoscalar, Empty }
+
 // Multivector
 
 #[inline]
@@ -540,3 +567,40 @@ fn pseudoscalar_add_pseudoscalar(
 
   lhs
 }
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  #[test]
+  #[allow(clippy::op_ref)]
+  fn references() {
+    let expected = MULTIVECTOR_A + VECTOR_A;
+    assert_eq!(dbg!(&MULTIVECTOR_A + VECTOR_A), dbg!(expected));
+    assert_eq!(dbg!(MULTIVECTOR_A + &VECTOR_A), dbg!(expected));
+    assert_eq!(dbg!(&MULTIVECTOR_A + &VECTOR_A), dbg!(expected));
+  }
+
+  #[test]
+  fn add_assign() {
+    let mut result = MULTIVECTOR_A;
+    result += VECTOR_A;
+    result += &BIVECTOR_A;
+    assert_eq!(dbg!(result), dbg!(MULTIVECTOR_A + VECTOR_A + BIVECTOR_A));
+
+    let mut result = VECTOR_A;
+    result += VECTOR_B;
+    assert_eq!(dbg!(result), dbg!(VECTOR_A + VECTOR_B));
+  }
+
+  #[test]
+  fn sum() {
+    let values = [VECTOR_A, VECTOR_B, VECTOR_C];
+    let expected = VECTOR_A + VECTOR_B + VECTOR_C;
+    assert_eq!(dbg!(values.iter().sum::<Vector>()), dbg!(expected));
+    assert_eq!(dbg!(values.into_iter().sum::<Vector>()), dbg!(expected));
+    assert_eq!(dbg!(core::iter::empty::<Vector>().sum::<Vector>()), Vector::zero());
+  }
+}
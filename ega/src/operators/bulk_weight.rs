@@ -0,0 +1,300 @@
+use crate::*;
+
+// In the plane-based PGA implemented here the degenerate basis vector is
+// `e0`. Following the conventions of rigidgeometricalgebra.org, the bulk of a
+// value is made up of the components that contain `e0` (i.e. those measured
+// by the `IdealNorm`) and the weight is made up of the components that don't
+// (i.e. those measured by the Euclidean `Norm`).
+
+/// The bulk, the components which contain the degenerate basis vector `e0`
+pub trait Bulk {
+  /// The bulk, the components which contain the degenerate basis vector `e0`
+  fn bulk(self) -> Self;
+}
+
+/// The weight, the components which don't contain the degenerate basis vector
+/// `e0`
+pub trait Weight {
+  /// The weight, the components which don't contain the degenerate basis
+  /// vector `e0`
+  fn weight(self) -> Self;
+}
+
+/// The bulk norm, ||A||•
+///
+/// Equivalent to the [`IdealNorm`]
+pub trait BulkNorm {
+  /// The bulk norm, ||A||•
+  fn bulk_norm(self) -> Pseudoscalar;
+}
+
+impl<T: IdealNorm> BulkNorm for T {
+  #[inline]
+  fn bulk_norm(self) -> Pseudoscalar {
+    self.ideal_norm()
+  }
+}
+
+/// The weight norm, ||A||○
+///
+/// Equivalent to the Euclidean [`Norm`]
+pub trait WeightNorm {
+  /// The weight norm, ||A||○
+  fn weight_norm(self) -> Scalar;
+}
+
+impl<T: Norm> WeightNorm for T {
+  #[inline]
+  fn weight_norm(self) -> Scalar {
+    self.norm()
+  }
+}
+
+/// The geometric norm, ||A||
+///
+/// The homogeneous magnitude `||A||• + ||A||○`, evaluated as the ratio of the
+/// bulk norm to the weight norm. For a point, plane or line this is its
+/// Euclidean distance from the origin.
+pub trait GeometricNorm {
+  /// The geometric norm, ||A||
+  fn geometric_norm(self) -> Scalar;
+}
+
+impl<T: Copy + BulkNorm + WeightNorm> GeometricNorm for T {
+  #[inline]
+  fn geometric_norm(self) -> Scalar {
+    Scalar {
+      s: self.bulk_norm().e0123 / self.weight_norm().s,
+    }
+  }
+}
+
+impl Bulk for Multivector {
+  #[inline]
+  fn bulk(self) -> Multivector {
+    Multivector {
+      e0: self.e0,
+      e01: self.e01,
+      e02: self.e02,
+      e03: self.e03,
+      e032: self.e032,
+      e013: self.e013,
+      e021: self.e021,
+      e0123: self.e0123,
+      ..zero()
+    }
+  }
+}
+
+impl Weight for Multivector {
+  #[inline]
+  fn weight(self) -> Multivector {
+    Multivector {
+      s: self.s,
+      e1: self.e1,
+      e2: self.e2,
+      e3: self.e3,
+      e23: self.e23,
+      e31: self.e31,
+      e12: self.e12,
+      e123: self.e123,
+      ..zero()
+    }
+  }
+}
+
+impl Bulk for Empty {
+  #[inline]
+  fn bulk(self) -> Empty {
+    Empty
+  }
+}
+
+impl Weight for Empty {
+  #[inline]
+  fn weight(self) -> Empty {
+    Empty
+  }
+}
+
+impl Bulk for Scalar {
+  #[inline]
+  fn bulk(self) -> Scalar {
+    zero()
+  }
+}
+
+impl Weight for Scalar {
+  #[inline]
+  fn weight(self) -> Scalar {
+    self
+  }
+}
+
+impl Bulk for Vector {
+  #[inline]
+  fn bulk(self) -> Vector {
+    Vector {
+      e0: self.e0,
+      ..zero()
+    }
+  }
+}
+
+impl Weight for Vector {
+  #[inline]
+  fn weight(self) -> Vector {
+    Vector { e0: 0., ..self }
+  }
+}
+
+impl Bulk for Bivector {
+  #[inline]
+  fn bulk(self) -> Bivector {
+    Bivector {
+      e01: self.e01,
+      e02: self.e02,
+      e03: self.e03,
+      ..zero()
+    }
+  }
+}
+
+impl Weight for Bivector {
+  #[inline]
+  fn weight(self) -> Bivector {
+    Bivector {
+      e23: self.e23,
+      e31: self.e31,
+      e12: self.e12,
+      ..zero()
+    }
+  }
+}
+
+impl Bulk for Trivector {
+  #[inline]
+  fn bulk(self) -> Trivector {
+    Trivector { e123: 0., ..self }
+  }
+}
+
+impl Weight for Trivector {
+  #[inline]
+  fn weight(self) -> Trivector {
+    Trivector {
+      e123: self.e123,
+      ..zero()
+    }
+  }
+}
+
+impl Bulk for Pseudoscalar {
+  #[inline]
+  fn bulk(self) -> Pseudoscalar {
+    self
+  }
+}
+
+impl Weight for Pseudoscalar {
+  #[inline]
+  fn weight(self) -> Pseudoscalar {
+    zero()
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  mod bulk_weight {
+    use super::*;
+
+    #[test]
+    fn multivector() {
+      let result = MULTIVECTOR_A.bulk() + MULTIVECTOR_A.weight();
+      assert_eq!(dbg!(result), dbg!(MULTIVECTOR_A));
+
+      let expected = Multivector {
+          e0:  2.,   e1:  0.,   e2:  0.,    e3:  0.,
+           s:  0.,  e23:  0.,  e31:  0.,   e12:  0.,
+         e01: 23.,  e02: 29.,  e03: 31., e0123: 37.,
+        e123:  0., e032: 43., e013: 47.,  e021: 53.,
+      };
+      assert_eq!(dbg!(MULTIVECTOR_A.bulk()), dbg!(expected));
+    }
+
+    #[test]
+    fn vector() {
+      let result = VECTOR_A.bulk() + VECTOR_A.weight();
+      assert_eq!(dbg!(result), dbg!(VECTOR_A));
+
+      let expected = Vector { e0: 151., e1: 0., e2: 0., e3: 0. };
+      assert_eq!(dbg!(VECTOR_A.bulk()), dbg!(expected));
+    }
+
+    #[test]
+    fn bivector() {
+      let result = BIVECTOR_A.bulk() + BIVECTOR_A.weight();
+      assert_eq!(dbg!(result), dbg!(BIVECTOR_A));
+
+      let expected = Bivector {
+        e23: 223., e31: 227., e12: 229.,
+        e01:   0., e02:   0., e03:   0.,
+      };
+      assert_eq!(dbg!(BIVECTOR_A.weight()), dbg!(expected));
+    }
+
+    #[test]
+    fn trivector() {
+      let result = TRIVECTOR_A.bulk() + TRIVECTOR_A.weight();
+      assert_eq!(dbg!(result), dbg!(TRIVECTOR_A));
+
+      let expected = Trivector { e123: 317., e032: 0., e013: 0., e021: 0. };
+      assert_eq!(dbg!(TRIVECTOR_A.weight()), dbg!(expected));
+    }
+
+    #[test]
+    fn norms() {
+      assert_eq!(MULTIVECTOR_A.bulk_norm(), MULTIVECTOR_A.bulk().ideal_norm());
+      assert_eq!(MULTIVECTOR_A.weight_norm(), MULTIVECTOR_A.weight().norm());
+      assert_eq!(BIVECTOR_A.bulk_norm(), BIVECTOR_A.ideal_norm());
+      assert_eq!(BIVECTOR_A.weight_norm(), BIVECTOR_A.norm());
+    }
+  }
+
+  mod geometric_norm {
+    use super::*;
+
+    #[test]
+    fn point() {
+      // the point (2, 3, 6), at a distance of 7 from the origin
+      let point = Trivector { e123: 2., e032: 4., e013: 6., e021: 12. };
+      let result = point.geometric_norm();
+      let expected = Scalar { s: 7. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn plane() {
+      // the plane z = 5
+      let plane = Vector { e0: -10., e1: 0., e2: 0., e3: 2. };
+      let result = plane.geometric_norm();
+      let expected = Scalar { s: 5. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn line() {
+      // the line through (0, 3, 0) parallel to the x axis
+      let p = Trivector { e123: 1., e032: 0., e013: 3., e021: 0. };
+      let q = Trivector { e123: 1., e032: 1., e013: 3., e021: 0. };
+      let line = p.join(q);
+      let result = line.geometric_norm();
+      let expected = Scalar { s: 3. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+  }
+}
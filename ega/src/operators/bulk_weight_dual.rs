@@ -0,0 +1,148 @@
+use crate::*;
+
+/// The bulk dual, A★
+///
+/// The [`RightComplement`] of the [`Bulk`]
+pub trait BulkDual {
+  type Output;
+
+  /// The bulk dual, A★
+  fn bulk_dual(self) -> Self::Output;
+}
+
+impl<T: Bulk + RightComplement> BulkDual for T {
+  type Output = <T as RightComplement>::Output;
+
+  #[inline]
+  fn bulk_dual(self) -> Self::Output {
+    self.bulk().right_complement()
+  }
+}
+
+/// The weight dual, A☆
+///
+/// The [`RightComplement`] of the [`Weight`]
+pub trait WeightDual {
+  type Output;
+
+  /// The weight dual, A☆
+  fn weight_dual(self) -> Self::Output;
+}
+
+impl<T: Weight + RightComplement> WeightDual for T {
+  type Output = <T as RightComplement>::Output;
+
+  #[inline]
+  fn weight_dual(self) -> Self::Output {
+    self.weight().right_complement()
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  mod bulk_dual {
+    use super::*;
+
+    #[test]
+    fn multivector() {
+      let result = MULTIVECTOR_A.bulk_dual();
+      let expected = Multivector {
+          e0: 0.,   e1: -43.,   e2: -47.,    e3: -53.,
+           s: 37.,  e23:  23.,  e31:  29.,   e12:  31.,
+         e01: 0.,  e02:   0.,  e03:   0., e0123:   0.,
+        e123: 2., e032:   0., e013:   0.,  e021:   0.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn vector() {
+      // the bulk dual of a plane is the origin, weighted by the plane's
+      // distance component
+      let result = VECTOR_A.bulk_dual();
+      let expected = Trivector { e123: 151., e032: 0., e013: 0., e021: 0. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn bivector() {
+      let result = BIVECTOR_A.bulk_dual();
+      let expected = Bivector {
+        e23: 233., e31: 239., e12: 241.,
+        e01:   0., e02:   0., e03:   0.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn trivector() {
+      let result = TRIVECTOR_A.bulk_dual();
+      let expected = Vector { e0: 0., e1: -331., e2: -337., e3: -347. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn pseudoscalar() {
+      let result = PSEUDOSCALAR_A.bulk_dual();
+      let expected = Scalar { s: 397. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+  }
+
+  mod weight_dual {
+    use super::*;
+
+    #[test]
+    fn multivector() {
+      let result = MULTIVECTOR_A.weight_dual();
+      let expected = Multivector {
+          e0: -41.,   e1: 0.,   e2: 0.,    e3: 0.,
+           s:   0.,  e23: 0.,  e31: 0.,   e12: 0.,
+         e01:  13.,  e02: 17.,  e03: 19., e0123: 11.,
+        e123:   0., e032:  3., e013:  5.,  e021:  7.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn vector() {
+      let result = VECTOR_A.weight_dual();
+      let expected = Trivector { e123: 0., e032: 157., e013: 163., e021: 167. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn bivector() {
+      let result = BIVECTOR_A.weight_dual();
+      let expected = Bivector {
+        e23:   0., e31:   0., e12:   0.,
+        e01: 223., e02: 227., e03: 229.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn trivector() {
+      let result = TRIVECTOR_A.weight_dual();
+      let expected = Vector { e0: -317., e1: 0., e2: 0., e3: 0. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn pseudoscalar() {
+      let result = PSEUDOSCALAR_A.weight_dual();
+      let expected = Scalar { s: 0. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+  }
+
+  #[test]
+  fn sum_of_duals_is_the_right_complement() {
+    let result = MULTIVECTOR_A.bulk_dual() + MULTIVECTOR_A.weight_dual();
+    assert_eq!(dbg!(result), dbg!(MULTIVECTOR_A.right_complement()));
+  }
+}
@@ -0,0 +1,235 @@
+use crate::*;
+
+/// The kind of geometric object a value represents
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GeometricObject {
+  /// All components are zero
+  Zero,
+  /// Only a scalar component
+  Scalar,
+  /// Only a pseudoscalar component
+  Pseudoscalar,
+  /// A vector with a Euclidean normal
+  Plane,
+  /// A vector with only an `e0` component, the plane at infinity
+  IdealPlane,
+  /// A simple bivector with a Euclidean direction
+  EuclideanLine,
+  /// A bivector with only `e01`, `e02`, `e03` components, a line at infinity
+  IdealLine,
+  /// A bivector that isn't simple, i.e. the sum of a Euclidean line & a
+  /// commuting ideal line
+  Bivector,
+  /// A trivector with an `e123` component
+  EuclideanPoint,
+  /// A trivector without an `e123` component, a direction
+  IdealPoint,
+  /// A scalar plus a Euclidean line, a rotation about that line
+  Rotor,
+  /// A scalar plus an ideal line, a translation
+  Translator,
+  /// A general even element, a screw motion
+  Motor,
+  /// A vector plus a trivector, a reflection composed with a motion
+  Flector,
+  /// Any other combination of grades
+  Mixed,
+}
+
+/// Classify a value into a [`GeometricObject`]
+pub trait Classify {
+  /// Classify the value into a [`GeometricObject`]
+  ///
+  /// `epsilon` is relative, so the classification doesn't depend on the scale
+  /// of the value: a grade is treated as zero when its magnitude is no more
+  /// than `epsilon` times that of the whole value, its weight when no more
+  /// than `epsilon` times that of the grade, & a bivector as simple when
+  /// `B ∧ B` is no more than `epsilon` times `|B|²`.
+  fn classify(self, epsilon: f32) -> GeometricObject;
+}
+
+impl Classify for Multivector {
+  fn classify(self, epsilon: f32) -> GeometricObject {
+    let eps2 = epsilon * epsilon;
+    // the squared magnitudes, including the bulk
+    let magnitude = |weight: Scalar, bulk: Pseudoscalar| weight.s + bulk.e0123;
+
+    let scalar = self.grade_0();
+    let vector = self.grade_1();
+    let bivector = self.grade_2();
+    let trivector = self.grade_3();
+    let pseudoscalar = self.grade_4();
+
+    let grades = [
+      magnitude(scalar.norm_squared(), scalar.ideal_norm_squared()),
+      magnitude(vector.norm_squared(), vector.ideal_norm_squared()),
+      magnitude(bivector.norm_squared(), bivector.ideal_norm_squared()),
+      magnitude(trivector.norm_squared(), trivector.ideal_norm_squared()),
+      magnitude(
+        pseudoscalar.norm_squared(),
+        pseudoscalar.ideal_norm_squared(),
+      ),
+    ];
+    let total: f32 = grades.iter().sum();
+    let [has_0, has_1, has_2, has_3, has_4] =
+      grades.map(|grade| grade > eps2 * total);
+    // whether a grade has no weight, relative to its magnitude
+    let is_ideal =
+      |grade: usize, weight: Scalar| weight.s <= eps2 * grades[grade];
+
+    // whether the bivector part satisfies the Plücker condition, B ∧ B = 0
+    let bivector_is_simple = bivector.is_simple(epsilon);
+    let bivector_is_ideal = is_ideal(2, bivector.norm_squared());
+
+    use GeometricObject as G;
+    match (has_0, has_1, has_2, has_3, has_4) {
+      (false, false, false, false, false) => G::Zero,
+      (true, false, false, false, false) => G::Scalar,
+      (false, false, false, false, true) => G::Pseudoscalar,
+      (false, true, false, false, false)
+        if is_ideal(1, vector.norm_squared()) =>
+      {
+        G::IdealPlane
+      },
+      (false, true, false, false, false) => G::Plane,
+      (false, false, true, false, false) if !bivector_is_simple => G::Bivector,
+      (false, false, true, false, false) if bivector_is_ideal => G::IdealLine,
+      (false, false, true, false, false) => G::EuclideanLine,
+      (false, false, false, true, false)
+        if is_ideal(3, trivector.norm_squared()) =>
+      {
+        G::IdealPoint
+      },
+      (false, false, false, true, false) => G::EuclideanPoint,
+      (true, false, true, false, false) if bivector_is_ideal => G::Translator,
+      (true, false, true, false, false) if bivector_is_simple => G::Rotor,
+      (_, false, _, false, _) => G::Motor,
+      (false, true, false, true, false) => G::Flector,
+      _ => G::Mixed,
+    }
+  }
+}
+
+macro_rules! impl_classify {
+  ($type:ty) => {
+    impl Classify for $type {
+      #[inline]
+      fn classify(self, epsilon: f32) -> GeometricObject {
+        Multivector::from(self).classify(epsilon)
+      }
+    }
+  };
+}
+
+impl_classify! { Scalar }
+impl_classify! { Vector }
+impl_classify! { Bivector }
+impl_classify! { Trivector }
+impl_classify! { Pseudoscalar }
+
+impl Classify for Empty {
+  #[inline]
+  fn classify(self, _: f32) -> GeometricObject {
+    GeometricObject::Zero
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+  use GeometricObject as G;
+
+  const EPSILON: f32 = 1e-4;
+
+  // small values, so that products of them are exact
+  const PLANE_X: Vector = Vector { e0: -1., e1: 1., e2: 0., e3: 0. };
+  const PLANE_X_2: Vector = Vector { e0: -2., e1: 1., e2: 0., e3: 0. };
+  const PLANE_Y: Vector = Vector { e0: 0., e1: 0., e2: 1., e3: 0. };
+  const PLANE_XYZ: Vector = Vector { e0: 3., e1: 1., e2: 2., e3: 2. };
+
+  #[test]
+  fn classify_zero() {
+    assert_eq!(Multivector::zero().classify(EPSILON), G::Zero);
+    assert_eq!(Empty.classify(EPSILON), G::Zero);
+    // a part which is tiny relative to the rest is ignored
+    let tiny = SCALAR_A + Vector { e0: 1e-6, ..zero() };
+    assert_eq!(tiny.classify(EPSILON), G::Scalar);
+  }
+
+  #[test]
+  fn scale_invariant() {
+    let values = [
+      Multivector::from(VECTOR_A), VECTOR_A.bulk().into(), TRIVECTOR_A.into(), TRIVECTOR_A.bulk().into(),
+      TRIVECTOR_A.join(TRIVECTOR_B).into(), BIVECTOR_A.into(), BIVECTOR_A.bulk().into(),
+      PLANE_XYZ * PLANE_Y, PLANE_X * PLANE_X_2, PLANE_X * PLANE_Y * PLANE_XYZ,
+    ];
+    for value in values {
+      let expected = value.classify(EPSILON);
+      for scale in [1e-6, 1e-3, 1e3, 1e6] {
+        assert_eq!((value * scale).classify(EPSILON), expected, "{value:?} * {scale}");
+      }
+    }
+    // a line with a tiny weight is still Euclidean, relative to its bulk
+    let line = Bivector { e01: 1., e12: 1e-3, ..zero() };
+    assert_eq!(line.classify(EPSILON), G::EuclideanLine);
+    assert_eq!(line.bulk().classify(EPSILON), G::IdealLine);
+  }
+
+  #[test]
+  fn scalar_and_pseudoscalar() {
+    assert_eq!(SCALAR_A.classify(EPSILON), G::Scalar);
+    assert_eq!(PSEUDOSCALAR_A.classify(EPSILON), G::Pseudoscalar);
+  }
+
+  #[test]
+  fn planes() {
+    assert_eq!(VECTOR_A.classify(EPSILON), G::Plane);
+    assert_eq!(VECTOR_A.bulk().classify(EPSILON), G::IdealPlane);
+  }
+
+  #[test]
+  fn points() {
+    assert_eq!(TRIVECTOR_A.classify(EPSILON), G::EuclideanPoint);
+    assert_eq!(TRIVECTOR_A.bulk().classify(EPSILON), G::IdealPoint);
+  }
+
+  #[test]
+  fn lines() {
+    let line = TRIVECTOR_A.join(TRIVECTOR_B);
+    assert_eq!(line.classify(EPSILON), G::EuclideanLine);
+    assert_eq!(VECTOR_A.meet(VECTOR_B).classify(EPSILON), G::EuclideanLine);
+    assert_eq!(BIVECTOR_A.bulk().classify(EPSILON), G::IdealLine);
+    assert_eq!(BIVECTOR_A.classify(EPSILON), G::Bivector);
+  }
+
+  #[test]
+  fn rotor() {
+    let rotor = PLANE_XYZ * PLANE_Y;
+    assert_eq!(rotor.classify(EPSILON), G::Rotor);
+  }
+
+  #[test]
+  fn translator() {
+    let translator = PLANE_X * PLANE_X_2;
+    assert_eq!(translator.classify(EPSILON), G::Translator);
+  }
+
+  #[test]
+  fn motor() {
+    let motor = (PLANE_X * PLANE_Y) * (PLANE_XYZ * PLANE_X_2);
+    assert_eq!(motor.classify(EPSILON), G::Motor);
+  }
+
+  #[test]
+  fn flector() {
+    let flector = PLANE_X * PLANE_Y * PLANE_XYZ;
+    assert_eq!(flector.classify(EPSILON), G::Flector);
+  }
+
+  #[test]
+  fn mixed() {
+    assert_eq!(MULTIVECTOR_A.classify(EPSILON), G::Mixed);
+  }
+}
@@ -0,0 +1,342 @@
+use crate::*;
+
+/// The right complement
+///
+/// For each basis element `b`, `b.meet(b.right_complement())` is the unit
+/// pseudoscalar `e0123`
+pub trait RightComplement {
+  type Output;
+
+  /// The right complement
+  fn right_complement(self) -> Self::Output;
+}
+
+/// The left complement
+///
+/// For each basis element `b`, `b.left_complement().meet(b)` is the unit
+/// pseudoscalar `e0123`
+pub trait LeftComplement {
+  type Output;
+
+  /// The left complement
+  fn left_complement(self) -> Self::Output;
+}
+
+impl RightComplement for Multivector {
+  type Output = Multivector;
+
+  #[inline]
+  fn right_complement(self) -> Multivector {
+    Multivector {
+      e0: -self.e123,
+      e1: -self.e032,
+      e2: -self.e013,
+      e3: -self.e021,
+      s: self.e0123,
+      e23: self.e01,
+      e31: self.e02,
+      e12: self.e03,
+      e01: self.e23,
+      e02: self.e31,
+      e03: self.e12,
+      e0123: self.s,
+      e123: self.e0,
+      e032: self.e1,
+      e013: self.e2,
+      e021: self.e3,
+    }
+  }
+}
+
+impl LeftComplement for Multivector {
+  type Output = Multivector;
+
+  #[inline]
+  fn left_complement(self) -> Multivector {
+    Multivector {
+      e0: self.e123,
+      e1: self.e032,
+      e2: self.e013,
+      e3: self.e021,
+      s: self.e0123,
+      e23: self.e01,
+      e31: self.e02,
+      e12: self.e03,
+      e01: self.e23,
+      e02: self.e31,
+      e03: self.e12,
+      e0123: self.s,
+      e123: -self.e0,
+      e032: -self.e1,
+      e013: -self.e2,
+      e021: -self.e3,
+    }
+  }
+}
+
+impl RightComplement for Empty {
+  type Output = Empty;
+
+  #[inline]
+  fn right_complement(self) -> Empty {
+    Empty
+  }
+}
+
+impl LeftComplement for Empty {
+  type Output = Empty;
+
+  #[inline]
+  fn left_complement(self) -> Empty {
+    Empty
+  }
+}
+
+impl RightComplement for Scalar {
+  type Output = Pseudoscalar;
+
+  #[inline]
+  fn right_complement(self) -> Pseudoscalar {
+    Pseudoscalar { e0123: self.s }
+  }
+}
+
+impl LeftComplement for Scalar {
+  type Output = Pseudoscalar;
+
+  #[inline]
+  fn left_complement(self) -> Pseudoscalar {
+    Pseudoscalar { e0123: self.s }
+  }
+}
+
+impl RightComplement for Vector {
+  type Output = Trivector;
+
+  #[inline]
+  fn right_complement(self) -> Trivector {
+    Trivector {
+      e123: self.e0,
+      e032: self.e1,
+      e013: self.e2,
+      e021: self.e3,
+    }
+  }
+}
+
+impl LeftComplement for Vector {
+  type Output = Trivector;
+
+  #[inline]
+  fn left_complement(self) -> Trivector {
+    Trivector {
+      e123: -self.e0,
+      e032: -self.e1,
+      e013: -self.e2,
+      e021: -self.e3,
+    }
+  }
+}
+
+impl RightComplement for Bivector {
+  type Output = Bivector;
+
+  #[inline]
+  fn right_complement(self) -> Bivector {
+    Bivector {
+      e23: self.e01,
+      e31: self.e02,
+      e12: self.e03,
+      e01: self.e23,
+      e02: self.e31,
+      e03: self.e12,
+    }
+  }
+}
+
+impl LeftComplement for Bivector {
+  type Output = Bivector;
+
+  #[inline]
+  fn left_complement(self) -> Bivector {
+    Bivector {
+      e23: self.e01,
+      e31: self.e02,
+      e12: self.e03,
+      e01: self.e23,
+      e02: self.e31,
+      e03: self.e12,
+    }
+  }
+}
+
+impl RightComplement for Trivector {
+  type Output = Vector;
+
+  #[inline]
+  fn right_complement(self) -> Vector {
+    Vector {
+      e0: -self.e123,
+      e1: -self.e032,
+      e2: -self.e013,
+      e3: -self.e021,
+    }
+  }
+}
+
+impl LeftComplement for Trivector {
+  type Output = Vector;
+
+  #[inline]
+  fn left_complement(self) -> Vector {
+    Vector {
+      e0: self.e123,
+      e1: self.e032,
+      e2: self.e013,
+      e3: self.e021,
+    }
+  }
+}
+
+impl RightComplement for Pseudoscalar {
+  type Output = Scalar;
+
+  #[inline]
+  fn right_complement(self) -> Scalar {
+    Scalar { s: self.e0123 }
+  }
+}
+
+impl LeftComplement for Pseudoscalar {
+  type Output = Scalar;
+
+  #[inline]
+  fn left_complement(self) -> Scalar {
+    Scalar { s: self.e0123 }
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  mod right_complement {
+    use super::*;
+
+    #[test]
+    fn multivector() {
+      let result = MULTIVECTOR_A.right_complement();
+      let expected = Multivector {
+          e0: -41.,   e1: -43.,   e2: -47.,    e3: -53.,
+           s:  37.,  e23:  23.,  e31:  29.,   e12:  31.,
+         e01:  13.,  e02:  17.,  e03:  19., e0123:  11.,
+        e123:   2., e032:   3., e013:   5.,  e021:   7.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+      assert_eq!(result.left_complement(), MULTIVECTOR_A);
+    }
+
+    #[test]
+    fn scalar() {
+      let result = SCALAR_A.meet(SCALAR_A.right_complement());
+      let expected = Pseudoscalar { e0123: 137. * 137. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn vector() {
+      let result = VECTOR_A.meet(VECTOR_A.right_complement());
+      let expected = Pseudoscalar {
+        e0123: 151.*151. + 157.*157. + 163.*163. + 167.*167.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn bivector() {
+      let result = BIVECTOR_A.meet(BIVECTOR_A.right_complement());
+      let expected = Pseudoscalar {
+        e0123: 223.*223. + 227.*227. + 229.*229.
+             + 233.*233. + 239.*239. + 241.*241.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn trivector() {
+      let result = TRIVECTOR_A.meet(TRIVECTOR_A.right_complement());
+      let expected = Pseudoscalar {
+        e0123: 317.*317. + 331.*331. + 337.*337. + 347.*347.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn pseudoscalar() {
+      let result = PSEUDOSCALAR_A.meet(PSEUDOSCALAR_A.right_complement());
+      let expected = Pseudoscalar { e0123: 397. * 397. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+  }
+
+  mod left_complement {
+    use super::*;
+
+    #[test]
+    fn multivector() {
+      let result = MULTIVECTOR_A.left_complement();
+      let expected = Multivector {
+          e0:  41.,   e1:  43.,   e2:  47.,    e3:  53.,
+           s:  37.,  e23:  23.,  e31:  29.,   e12:  31.,
+         e01:  13.,  e02:  17.,  e03:  19., e0123:  11.,
+        e123:  -2., e032:  -3., e013:  -5.,  e021:  -7.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+      assert_eq!(result.right_complement(), MULTIVECTOR_A);
+    }
+
+    #[test]
+    fn scalar() {
+      let result = SCALAR_A.left_complement().meet(SCALAR_A);
+      let expected = Pseudoscalar { e0123: 137. * 137. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn vector() {
+      let result = VECTOR_A.left_complement().meet(VECTOR_A);
+      let expected = Pseudoscalar {
+        e0123: 151.*151. + 157.*157. + 163.*163. + 167.*167.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn bivector() {
+      let result = BIVECTOR_A.left_complement().meet(BIVECTOR_A);
+      let expected = Pseudoscalar {
+        e0123: 223.*223. + 227.*227. + 229.*229.
+             + 233.*233. + 239.*239. + 241.*241.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn trivector() {
+      let result = TRIVECTOR_A.left_complement().meet(TRIVECTOR_A);
+      let expected = Pseudoscalar {
+        e0123: 317.*317. + 331.*331. + 337.*337. + 347.*347.,
+      };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn pseudoscalar() {
+      let result = PSEUDOSCALAR_A.left_complement().meet(PSEUDOSCALAR_A);
+      let expected = Pseudoscalar { e0123: 397. * 397. };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+  }
+}
@@ -1,6 +1,6 @@
 use crate::*;
 
-pub use ::core::ops::Div;
+pub use ::core::ops::{Div, DivAssign};
 
 macro_rules! impl_div {
   ($lhs:ty, f32 => $output:ty) => {
@@ -13,6 +13,8 @@ macro_rules! impl_div {
         self * Scalar { s: 1.0 / rhs }
       }
     }
+
+    impl_ref_ops! { Div::div: $lhs, f32 }
   };
   ($lhs:ty, $rhs:ty => $output:ty) => {
     #[allow(clippy::suspicious_arithmetic_impl)]
@@ -24,6 +26,8 @@ macro_rules! impl_div {
         self * rhs.inverse()
       }
     }
+
+    impl_ref_ops! { Div::div: $lhs, $rhs }
   };
 }
 
@@ -49,3 +53,43 @@ impl_div! { Trivector, f32 => Trivector }
 impl_div! { Trivector, Scalar => Trivector }
 impl_div! { Trivector, Vector => Multivector }
 impl_div! { Trivector, Trivector => Multivector }
+
+impl_assign_ops! { Div::div, DivAssign::div_assign: Scalar, Vector, Bivector, Trivector }
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  #[test]
+  fn div() {
+    assert_eq!(dbg!(VECTOR_A / 2.), dbg!(VECTOR_A * 0.5));
+    assert_eq!(dbg!(BIVECTOR_A / Scalar { s: 4. }), dbg!(BIVECTOR_A * 0.25));
+    assert_eq!(dbg!(SCALAR_A / SCALAR_B), dbg!(SCALAR_A * SCALAR_B.inverse()));
+    assert_eq!(dbg!(2. / VECTOR_A), dbg!(2. * VECTOR_A.inverse()));
+    assert_eq!(dbg!(TRIVECTOR_A / VECTOR_B), dbg!(TRIVECTOR_A * VECTOR_B.inverse()));
+  }
+
+  #[test]
+  #[allow(clippy::op_ref)]
+  fn references() {
+    let expected = TRIVECTOR_A / TRIVECTOR_B;
+    assert_eq!(dbg!(&TRIVECTOR_A / TRIVECTOR_B), dbg!(expected));
+    assert_eq!(dbg!(TRIVECTOR_A / &TRIVECTOR_B), dbg!(expected));
+    assert_eq!(dbg!(&TRIVECTOR_A / &TRIVECTOR_B), dbg!(expected));
+    assert_eq!(dbg!(&VECTOR_A / &2.), dbg!(VECTOR_A / 2.));
+  }
+
+  #[test]
+  fn div_assign() {
+    let mut result = VECTOR_A;
+    result /= 2.;
+    result /= &SCALAR_A;
+    assert_eq!(dbg!(result), dbg!(VECTOR_A / 2. / SCALAR_A));
+
+    let mut result = SCALAR_A;
+    result /= SCALAR_B;
+    assert_eq!(dbg!(result), dbg!(SCALAR_A / SCALAR_B));
+  }
+}
@@ -1,4 +1,6 @@
 use super::return_empty;
+#[cfg(feature = "simd")]
+use crate::simd::geometric_product as simd_geometric_product;
 use crate::*;
 
 /// The geometric product
@@ -22,7 +24,10 @@ macro_rules! impl_geometric_product {
   };
 }
 
+#[cfg(not(feature = "simd"))]
 impl_geometric_product! { multivector_mul_multivector: Multivector, Multivector => Multivector }
+#[cfg(feature = "simd")]
+impl_geometric_product! { simd_geometric_product: Multivector, Multivector => Multivector }
 impl_geometric_product! { multivector_mul_scalar: Multivector, Scalar => Multivector }
 impl_geometric_product! { multivector_mul_vector: Multivector, Vector => Multivector }
 impl_geometric_product! { multivector_mul_bivector: Multivector, Bivector => Multivector }
@@ -78,11 +83,81 @@ impl_geometric_product! { return_empty: Bivector, Empty => Empty }
 impl_geometric_product! { return_empty: Trivector, Empty => Empty }
 impl_geometric_product! { return_empty: Pseudoscalar, Empty => Empty }
 
+impl_geometric_product! { motor_mul_motor: Motor, Motor => Motor }
+
+impl_const_ops! { "The geometric product, usable in `const` contexts", Multivector {
+  mul_multivector: multivector_mul_multivector(Multivector) => Multivector,
+  mul_scalar: multivector_mul_scalar(Scalar) => Multivector,
+  mul_vector: multivector_mul_vector(Vector) => Multivector,
+  mul_bivector: multivector_mul_bivector(Bivector) => Multivector,
+  mul_trivector: multivector_mul_trivector(Trivector) => Multivector,
+  mul_pseudoscalar: multivector_mul_pseudoscalar(Pseudoscalar) => Multivector,
+} }
+
+impl_const_ops! { "The geometric product, usable in `const` contexts", Scalar {
+  mul_multivector: scalar_mul_multivector(Multivector) => Multivector,
+  mul_scalar: scalar_mul_scalar(Scalar) => Scalar,
+  mul_vector: scalar_mul_vector(Vector) => Vector,
+  mul_bivector: scalar_mul_bivector(Bivector) => Bivector,
+  mul_trivector: scalar_mul_trivector(Trivector) => Trivector,
+  mul_pseudoscalar: scalar_mul_pseudoscalar(Pseudoscalar) => Pseudoscalar,
+} }
+
+impl_const_ops! { "The geometric product, usable in `const` contexts", Vector {
+  mul_multivector: vector_mul_multivector(Multivector) => Multivector,
+  mul_scalar: vector_mul_scalar(Scalar) => Vector,
+  mul_vector: vector_mul_vector(Vector) => Multivector,
+  mul_bivector: vector_mul_bivector(Bivector) => Multivector,
+  mul_trivector: vector_mul_trivector(Trivector) => Multivector,
+  mul_pseudoscalar: vector_mul_pseudoscalar(Pseudoscalar) => Trivector,
+} }
+
+impl_const_ops! { "The geometric product, usable in `const` contexts", Bivector {
+  mul_multivector: bivector_mul_multivector(Multivector) => Multivector,
+  mul_scalar: bivector_mul_scalar(Scalar) => Bivector,
+  mul_vector: bivector_mul_vector(Vector) => Multivector,
+  mul_bivector: bivector_mul_bivector(Bivector) => Multivector,
+  mul_trivector: bivector_mul_trivector(Trivector) => Multivector,
+  mul_pseudoscalar: bivector_mul_pseudoscalar(Pseudoscalar) => Bivector,
+} }
+
+impl_const_ops! { "The geometric product, usable in `const` contexts", Trivector {
+  mul_multivector: trivector_mul_multivector(Multivector) => Multivector,
+  mul_scalar: trivector_mul_scalar(Scalar) => Trivector,
+  mul_vector: trivector_mul_vector(Vector) => Multivector,
+  mul_bivector: trivector_mul_bivector(Bivector) => Multivector,
+  mul_trivector: trivector_mul_trivector(Trivector) => Multivector,
+  mul_pseudoscalar: trivector_mul_pseudoscalar(Pseudoscalar) => Vector,
+} }
+
+impl_const_ops! { "The geometric product, usable in `const` contexts", Pseudoscalar {
+  mul_multivector: pseudoscalar_mul_multivector(Multivector) => Multivector,
+  mul_scalar: pseudoscalar_mul_scalar(Scalar) => Pseudoscalar,
+  mul_vector: pseudoscalar_mul_vector(Vector) => Trivector,
+  mul_bivector: pseudoscalar_mul_bivector(Bivector) => Bivector,
+  mul_trivector: pseudoscalar_mul_trivector(Trivector) => Vector,
+} }
+
+impl_const_ops! { "The geometric product, usable in `const` contexts", Motor {
+  mul_motor: motor_mul_motor(Motor) => Motor,
+} }
+
+// Motor
+
+/// The even subalgebra is closed under the geometric product
+#[inline]
+const fn motor_mul_motor(lhs: Motor, rhs: Motor) -> Motor {
+  Motor::from_even(multivector_mul_multivector(
+    lhs.to_multivector(),
+    rhs.to_multivector(),
+  ))
+}
+
 // Multivector
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_mul_multivector(
+pub(crate) const fn multivector_mul_multivector(
   lhs: Multivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -199,7 +274,7 @@ fn multivector_mul_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_mul_scalar(
+const fn multivector_mul_scalar(
   lhs: Multivector,
   rhs: Scalar,
 ) -> Multivector {
@@ -232,7 +307,7 @@ fn multivector_mul_scalar(
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_mul_vector(
+const fn multivector_mul_vector(
   lhs: Multivector,
   rhs: Vector,
 ) -> Multivector {
@@ -299,7 +374,7 @@ fn multivector_mul_vector(
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_mul_bivector(
+const fn multivector_mul_bivector(
   lhs: Multivector,
   rhs: Bivector,
 ) -> Multivector {
@@ -376,7 +451,7 @@ fn multivector_mul_bivector(
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_mul_trivector(
+const fn multivector_mul_trivector(
   lhs: Multivector,
   rhs: Trivector,
 ) -> Multivector {
@@ -430,7 +505,7 @@ fn multivector_mul_trivector(
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_mul_pseudoscalar(
+const fn multivector_mul_pseudoscalar(
   lhs: Multivector,
   rhs: Pseudoscalar,
 ) -> Multivector {
@@ -450,7 +525,7 @@ fn multivector_mul_pseudoscalar(
     e01, e02, e03,
     e032, e013, e021,
     e0123,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
@@ -458,7 +533,7 @@ fn multivector_mul_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn scalar_mul_multivector(
+const fn scalar_mul_multivector(
   lhs: Scalar,
   mut rhs: Multivector,
 ) -> Multivector {
@@ -484,7 +559,7 @@ fn scalar_mul_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn scalar_mul_scalar(
+const fn scalar_mul_scalar(
   mut lhs: Scalar,
   rhs: Scalar,
 ) -> Scalar {
@@ -495,7 +570,7 @@ fn scalar_mul_scalar(
 
 #[rustfmt::skip]
 #[inline]
-fn scalar_mul_vector(
+const fn scalar_mul_vector(
   lhs: Scalar,
   mut rhs: Vector,
 ) -> Vector {
@@ -509,7 +584,7 @@ fn scalar_mul_vector(
 
 #[rustfmt::skip]
 #[inline]
-fn scalar_mul_bivector(
+const fn scalar_mul_bivector(
   lhs: Scalar,
   mut rhs: Bivector,
 ) -> Bivector {
@@ -525,7 +600,7 @@ fn scalar_mul_bivector(
 
 #[rustfmt::skip]
 #[inline]
-fn scalar_mul_trivector(
+const fn scalar_mul_trivector(
   lhs: Scalar,
   mut rhs: Trivector,
 ) -> Trivector {
@@ -539,7 +614,7 @@ fn scalar_mul_trivector(
 
 #[rustfmt::skip]
 #[inline]
-fn scalar_mul_pseudoscalar(
+const fn scalar_mul_pseudoscalar(
   lhs: Scalar,
   mut rhs: Pseudoscalar,
 ) -> Pseudoscalar {
@@ -552,7 +627,7 @@ fn scalar_mul_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn vector_mul_multivector(
+const fn vector_mul_multivector(
   lhs: Vector,
   rhs: Multivector,
 ) -> Multivector {
@@ -619,7 +694,7 @@ fn vector_mul_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn vector_mul_scalar(
+const fn vector_mul_scalar(
   mut lhs: Vector,
   rhs: Scalar,
 ) -> Vector {
@@ -633,7 +708,7 @@ fn vector_mul_scalar(
 
 #[rustfmt::skip]
 #[inline]
-fn vector_mul_vector(
+const fn vector_mul_vector(
   lhs: Vector,
   rhs: Vector,
 ) -> Multivector {
@@ -652,13 +727,13 @@ fn vector_mul_vector(
   Multivector {
     s,
     e01, e02, e03, e23, e31, e12,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn vector_mul_bivector(
+const fn vector_mul_bivector(
   lhs: Vector,
   rhs: Bivector,
 ) -> Multivector {
@@ -689,13 +764,13 @@ fn vector_mul_bivector(
   Multivector {
       e0,    e1,    e2,    e3,
     e123,  e032,  e013,  e021,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn vector_mul_trivector(
+const fn vector_mul_trivector(
   lhs: Vector,
   rhs: Trivector,
 ) -> Multivector {
@@ -718,13 +793,13 @@ fn vector_mul_trivector(
   Multivector {
     e23, e31, e12, e01, e02, e03,
     e0123,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn vector_mul_pseudoscalar(
+const fn vector_mul_pseudoscalar(
   lhs: Vector,
   rhs: Pseudoscalar,
 ) -> Trivector {
@@ -736,7 +811,7 @@ fn vector_mul_pseudoscalar(
 
   Trivector {
     e032, e013, e021,
-    ..zero()
+    ..Trivector::ZERO
   }
 }
 
@@ -744,7 +819,7 @@ fn vector_mul_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_mul_multivector(
+const fn bivector_mul_multivector(
   lhs: Bivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -821,7 +896,7 @@ fn bivector_mul_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_mul_scalar(
+const fn bivector_mul_scalar(
   mut lhs: Bivector,
   rhs: Scalar,
 ) -> Bivector {
@@ -838,7 +913,7 @@ fn bivector_mul_scalar(
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_mul_vector(
+const fn bivector_mul_vector(
   lhs: Bivector,
   rhs: Vector,
 ) -> Multivector {
@@ -869,13 +944,13 @@ fn bivector_mul_vector(
   Multivector {
     e0, e1, e2, e3,
     e123, e032, e013, e021,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_mul_bivector(
+const fn bivector_mul_bivector(
   lhs: Bivector,
   rhs: Bivector,
 ) -> Multivector {
@@ -902,13 +977,13 @@ fn bivector_mul_bivector(
     e23, e31, e12,
     e01, e02, e03,
     e0123,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_mul_trivector(
+const fn bivector_mul_trivector(
   lhs: Bivector,
   rhs: Trivector,
 ) -> Multivector {
@@ -933,13 +1008,13 @@ fn bivector_mul_trivector(
   Multivector {
     e0, e1, e2, e3,
     e032, e013, e021,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_mul_pseudoscalar(
+const fn bivector_mul_pseudoscalar(
   lhs: Bivector,
   rhs: Pseudoscalar,
 ) -> Bivector {
@@ -951,7 +1026,7 @@ fn bivector_mul_pseudoscalar(
 
   Bivector {
     e01,   e02,   e03,
-    ..zero()
+    ..Bivector::ZERO
   }
 }
 
@@ -959,7 +1034,7 @@ fn bivector_mul_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_mul_multivector(
+const fn trivector_mul_multivector(
   lhs: Trivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -1012,7 +1087,7 @@ fn trivector_mul_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_mul_scalar(
+const fn trivector_mul_scalar(
   mut lhs: Trivector,
   rhs: Scalar,
 ) -> Trivector {
@@ -1026,7 +1101,7 @@ fn trivector_mul_scalar(
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_mul_vector(
+const fn trivector_mul_vector(
   lhs: Trivector,
   rhs: Vector,
 ) -> Multivector {
@@ -1050,13 +1125,13 @@ fn trivector_mul_vector(
     e23, e31, e12,
     e01, e02, e03,
     e0123,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_mul_bivector(
+const fn trivector_mul_bivector(
   lhs: Trivector,
   rhs: Bivector,
 ) -> Multivector {
@@ -1081,13 +1156,13 @@ fn trivector_mul_bivector(
   Multivector {
     e0, e1, e2, e3,
     e032, e013, e021,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_mul_trivector(
+const fn trivector_mul_trivector(
   lhs: Trivector,
   rhs: Trivector,
 ) -> Multivector {
@@ -1101,13 +1176,13 @@ fn trivector_mul_trivector(
   Multivector {
     s,
     e01, e02, e03,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_mul_pseudoscalar(
+const fn trivector_mul_pseudoscalar(
   lhs: Trivector,
   rhs: Pseudoscalar,
 ) -> Vector {
@@ -1115,14 +1190,14 @@ fn trivector_mul_pseudoscalar(
 
   let e0 = a.e123*b.e0123;
 
-  Vector { e0, ..zero() }
+  Vector { e0, ..Vector::ZERO }
 }
 
 // Pseudoscalar
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_mul_multivector(
+const fn pseudoscalar_mul_multivector(
   lhs: Pseudoscalar,
   rhs: Multivector,
 ) -> Multivector {
@@ -1141,13 +1216,13 @@ fn pseudoscalar_mul_multivector(
     e0,
     e01, e02, e03, e0123,
     e032, e013, e021,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_mul_scalar(
+const fn pseudoscalar_mul_scalar(
   mut lhs: Pseudoscalar,
   rhs: Scalar,
 ) -> Pseudoscalar {
@@ -1158,7 +1233,7 @@ fn pseudoscalar_mul_scalar(
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_mul_vector(
+const fn pseudoscalar_mul_vector(
   lhs: Pseudoscalar,
   rhs: Vector,
 ) -> Trivector {
@@ -1170,13 +1245,13 @@ fn pseudoscalar_mul_vector(
 
   Trivector {
     e021, e013, e032,
-    ..zero()
+    ..Trivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_mul_bivector(
+const fn pseudoscalar_mul_bivector(
   lhs: Pseudoscalar,
   rhs: Bivector,
 ) -> Bivector {
@@ -1188,13 +1263,13 @@ fn pseudoscalar_mul_bivector(
 
   Bivector {
     e01, e02, e03,
-    ..zero()
+    ..Bivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_mul_trivector(
+const fn pseudoscalar_mul_trivector(
   lhs: Pseudoscalar,
   rhs: Trivector,
 ) -> Vector {
@@ -1202,7 +1277,7 @@ fn pseudoscalar_mul_trivector(
 
   let e0 = -a.e0123*b.e123;
 
-  Vector { e0, ..zero() }
+  Vector { e0, ..Vector::ZERO }
 }
 
 #[rustfmt::skip]
@@ -1521,7 +1596,6 @@ mod tests {
       let expected = Bivector {
         e01: 31921., e02: 32743.,e03: 33017.,
         e12: 31373., e31: 31099., e23: 30551.,
-        ..zero()
       };
       assert_eq!(dbg!(result), dbg!(expected));
     }
@@ -1688,4 +1762,24 @@ mod tests {
       assert_eq!(dbg!(result), dbg!(expected));
     }
   }
+
+  #[test]
+  fn const_products() {
+    const VECTOR_VECTOR: Multivector = VECTOR_A.mul_vector(VECTOR_B);
+    assert_eq!(dbg!(VECTOR_VECTOR), dbg!(VECTOR_A.geometric_product(VECTOR_B)));
+
+    const MULTIVECTORS: Multivector = MULTIVECTOR_A.mul_multivector(MULTIVECTOR_B);
+    assert_eq!(dbg!(MULTIVECTORS), dbg!(MULTIVECTOR_A.geometric_product(MULTIVECTOR_B)));
+
+    const BIVECTOR_TRIVECTOR: Multivector = BIVECTOR_A.mul_trivector(TRIVECTOR_A);
+    assert_eq!(dbg!(BIVECTOR_TRIVECTOR), dbg!(BIVECTOR_A.geometric_product(TRIVECTOR_A)));
+
+    // a half turn about the z axis, composed of two reflections
+    const HALF_TURN: Motor = Motor::from_even(
+      Vector { e0: 0., e1: 1., e2: 0., e3: 0. }.mul_vector(Vector { e0: 0., e1: 0., e2: 1., e3: 0. })
+    );
+    const FULL_TURN: Motor = HALF_TURN.mul_motor(HALF_TURN);
+    assert_eq!(dbg!(FULL_TURN), dbg!(HALF_TURN.geometric_product(HALF_TURN)));
+    assert_eq!(FULL_TURN, Motor { s: -1., ..zero() });
+  }
 }
@@ -11,11 +11,10 @@ pub trait HodgeDual {
   fn hodge_undual(self) -> Self::Output;
 }
 
-impl HodgeDual for Multivector {
-  type Output = Multivector;
-
+impl Multivector {
+  /// The Hodge dual, usable in `const` contexts
   #[inline]
-  fn hodge_dual(self) -> Self {
+  pub const fn hodge_dual(self) -> Multivector {
     Multivector {
       e0: -self.e123,
       e1: -self.e032,
@@ -36,8 +35,9 @@ impl HodgeDual for Multivector {
     }
   }
 
+  /// The inverse of the Hodge dual, usable in `const` contexts
   #[inline]
-  fn hodge_undual(self) -> Self {
+  pub const fn hodge_undual(self) -> Multivector {
     Multivector {
       e0: self.e123,
       e1: self.e032,
@@ -59,17 +59,59 @@ impl HodgeDual for Multivector {
   }
 }
 
+impl HodgeDual for Multivector {
+  type Output = Multivector;
+
+  #[inline]
+  fn hodge_dual(self) -> Self {
+    Multivector::hodge_dual(self)
+  }
+
+  #[inline]
+  fn hodge_undual(self) -> Self {
+    Multivector::hodge_undual(self)
+  }
+}
+
+impl Empty {
+  /// The Hodge dual, usable in `const` contexts
+  #[inline]
+  pub const fn hodge_dual(self) -> Empty {
+    Empty
+  }
+
+  /// The inverse of the Hodge dual, usable in `const` contexts
+  #[inline]
+  pub const fn hodge_undual(self) -> Empty {
+    Empty
+  }
+}
+
 impl HodgeDual for Empty {
   type Output = Empty;
 
   #[inline]
   fn hodge_dual(self) -> Empty {
-    Empty
+    Empty::hodge_dual(self)
   }
 
   #[inline]
   fn hodge_undual(self) -> Empty {
-    Empty
+    Empty::hodge_undual(self)
+  }
+}
+
+impl Scalar {
+  /// The Hodge dual, usable in `const` contexts
+  #[inline]
+  pub const fn hodge_dual(self) -> Pseudoscalar {
+    Pseudoscalar { e0123: self.s }
+  }
+
+  /// The inverse of the Hodge dual, usable in `const` contexts
+  #[inline]
+  pub const fn hodge_undual(self) -> Pseudoscalar {
+    Pseudoscalar { e0123: self.s }
   }
 }
 
@@ -78,20 +120,19 @@ impl HodgeDual for Scalar {
 
   #[inline]
   fn hodge_dual(self) -> Pseudoscalar {
-    Pseudoscalar { e0123: self.s }
+    Scalar::hodge_dual(self)
   }
 
   #[inline]
   fn hodge_undual(self) -> Pseudoscalar {
-    Pseudoscalar { e0123: self.s }
+    Scalar::hodge_undual(self)
   }
 }
 
-impl HodgeDual for Vector {
-  type Output = Trivector;
-
+impl Vector {
+  /// The Hodge dual, usable in `const` contexts
   #[inline]
-  fn hodge_dual(self) -> Trivector {
+  pub const fn hodge_dual(self) -> Trivector {
     Trivector {
       e123: self.e0,
       e032: self.e1,
@@ -100,8 +141,9 @@ impl HodgeDual for Vector {
     }
   }
 
+  /// The inverse of the Hodge dual, usable in `const` contexts
   #[inline]
-  fn hodge_undual(self) -> Trivector {
+  pub const fn hodge_undual(self) -> Trivector {
     Trivector {
       e123: -self.e0,
       e032: -self.e1,
@@ -111,11 +153,24 @@ impl HodgeDual for Vector {
   }
 }
 
-impl HodgeDual for Bivector {
-  type Output = Bivector;
+impl HodgeDual for Vector {
+  type Output = Trivector;
 
   #[inline]
-  fn hodge_dual(self) -> Bivector {
+  fn hodge_dual(self) -> Trivector {
+    Vector::hodge_dual(self)
+  }
+
+  #[inline]
+  fn hodge_undual(self) -> Trivector {
+    Vector::hodge_undual(self)
+  }
+}
+
+impl Bivector {
+  /// The Hodge dual, usable in `const` contexts
+  #[inline]
+  pub const fn hodge_dual(self) -> Bivector {
     Bivector {
       e23: self.e01,
       e31: self.e02,
@@ -126,8 +181,9 @@ impl HodgeDual for Bivector {
     }
   }
 
+  /// The inverse of the Hodge dual, usable in `const` contexts
   #[inline]
-  fn hodge_undual(self) -> Bivector {
+  pub const fn hodge_undual(self) -> Bivector {
     Bivector {
       e23: self.e01,
       e31: self.e02,
@@ -139,11 +195,24 @@ impl HodgeDual for Bivector {
   }
 }
 
-impl HodgeDual for Trivector {
-  type Output = Vector;
+impl HodgeDual for Bivector {
+  type Output = Bivector;
 
   #[inline]
-  fn hodge_dual(self) -> Vector {
+  fn hodge_dual(self) -> Bivector {
+    Bivector::hodge_dual(self)
+  }
+
+  #[inline]
+  fn hodge_undual(self) -> Bivector {
+    Bivector::hodge_undual(self)
+  }
+}
+
+impl Trivector {
+  /// The Hodge dual, usable in `const` contexts
+  #[inline]
+  pub const fn hodge_dual(self) -> Vector {
     Vector {
       e0: -self.e123,
       e1: -self.e032,
@@ -152,8 +221,9 @@ impl HodgeDual for Trivector {
     }
   }
 
+  /// The inverse of the Hodge dual, usable in `const` contexts
   #[inline]
-  fn hodge_undual(self) -> Vector {
+  pub const fn hodge_undual(self) -> Vector {
     Vector {
       e0: self.e123,
       e1: self.e032,
@@ -163,17 +233,45 @@ impl HodgeDual for Trivector {
   }
 }
 
+impl HodgeDual for Trivector {
+  type Output = Vector;
+
+  #[inline]
+  fn hodge_dual(self) -> Vector {
+    Trivector::hodge_dual(self)
+  }
+
+  #[inline]
+  fn hodge_undual(self) -> Vector {
+    Trivector::hodge_undual(self)
+  }
+}
+
+impl Pseudoscalar {
+  /// The Hodge dual, usable in `const` contexts
+  #[inline]
+  pub const fn hodge_dual(self) -> Scalar {
+    Scalar { s: self.e0123 }
+  }
+
+  /// The inverse of the Hodge dual, usable in `const` contexts
+  #[inline]
+  pub const fn hodge_undual(self) -> Scalar {
+    Scalar { s: self.e0123 }
+  }
+}
+
 impl HodgeDual for Pseudoscalar {
   type Output = Scalar;
 
   #[inline]
   fn hodge_dual(self) -> Scalar {
-    Scalar { s: self.e0123 }
+    Pseudoscalar::hodge_dual(self)
   }
 
   #[inline]
   fn hodge_undual(self) -> Scalar {
-    Scalar { s: self.e0123 }
+    Pseudoscalar::hodge_undual(self)
   }
 }
 
@@ -292,4 +390,13 @@ mod tests {
       assert_eq!(dbg!(result), dbg!(expected));
     }
   }
+
+  #[test]
+  fn const_hodge_dual() {
+    const DUAL: Bivector = BIVECTOR_A.hodge_dual();
+    assert_eq!(dbg!(DUAL), dbg!(HodgeDual::hodge_dual(BIVECTOR_A)));
+
+    const UNDUAL: Multivector = MULTIVECTOR_A.hodge_dual().hodge_undual();
+    assert_eq!(dbg!(UNDUAL), dbg!(MULTIVECTOR_A));
+  }
 }
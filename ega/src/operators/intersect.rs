@@ -0,0 +1,163 @@
+use crate::*;
+
+/// The intersection of two elements, reporting the degenerate cases
+///
+/// The intersection is the [`Meet`], but fails when the elements don't meet
+/// at a single Euclidean element.
+pub trait TryIntersect<Rhs> {
+  type Output;
+
+  /// The intersection, or
+  /// - [`EgaError::ZeroNorm`] if either element is zero,
+  /// - [`EgaError::IdealResult`] if either element lies at infinity,
+  /// - [`EgaError::Parallel`] if the elements are parallel or coincident
+  fn try_intersect(self, rhs: Rhs) -> Result<Self::Output, EgaError>;
+}
+
+macro_rules! impl_try_intersect {
+  ($lhs:ty, $rhs:ty => $output:ty) => {
+    impl TryIntersect<$rhs> for $lhs {
+      type Output = $output;
+
+      #[inline]
+      fn try_intersect(self, rhs: $rhs) -> Result<$output, EgaError> {
+        try_meet(self, rhs)
+      }
+    }
+  };
+}
+
+// plane & plane to line
+impl_try_intersect! { Vector, Vector => Bivector }
+// plane & line to point
+impl_try_intersect! { Vector, Bivector => Trivector }
+impl_try_intersect! { Bivector, Vector => Trivector }
+
+/// The weight of the meet, relative to the weights of the elements, below
+/// which they're treated as parallel: the sine of the angle between two
+/// planes, or the cosine of that between a line & a plane
+const PARALLEL: f32 = 1e-5;
+
+// the `NormSquared` of planes, lines & points is the square of their weight,
+// & the `IdealNormSquared` that of their bulk
+#[inline]
+fn try_meet<Lhs, Rhs, Output>(lhs: Lhs, rhs: Rhs) -> Result<Output, EgaError>
+where
+  Lhs: Copy
+    + Zero
+    + PartialEq
+    + NormSquared
+    + IdealNormSquared
+    + Meet<Rhs, Output = Output>,
+  Rhs: Copy + Zero + PartialEq + NormSquared + IdealNormSquared,
+  Output: Copy + NormSquared,
+{
+  if lhs == zero() || rhs == zero() {
+    return Err(EgaError::ZeroNorm);
+  }
+  // an element is ideal when its weight is lost in the rounding of its bulk
+  let weight_squared = |weight: Scalar, bulk: Pseudoscalar| {
+    let epsilon_squared = f32::EPSILON * f32::EPSILON;
+    (weight.s > epsilon_squared * bulk.e0123).then_some(weight.s)
+  };
+  let (Some(lhs_weight), Some(rhs_weight)) = (
+    weight_squared(lhs.norm_squared(), lhs.ideal_norm_squared()),
+    weight_squared(rhs.norm_squared(), rhs.ideal_norm_squared()),
+  ) else {
+    return Err(EgaError::IdealResult);
+  };
+  let meet = lhs.meet(rhs);
+  let tolerance = PARALLEL * PARALLEL * lhs_weight * rhs_weight;
+  if meet.norm_squared().s <= tolerance {
+    return Err(EgaError::Parallel);
+  }
+  Ok(meet)
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+
+  const PLANE_X: Vector = Vector { e0: -1., e1: 1., e2: 0., e3: 0. };
+  const PLANE_X_2: Vector = Vector { e0: -2., e1: 1., e2: 0., e3: 0. };
+  const PLANE_Y: Vector = Vector { e0: 0., e1: 0., e2: 1., e3: 0. };
+  const PLANE_Z: Vector = Vector { e0: 0., e1: 0., e2: 0., e3: 1. };
+  const PLANE_AT_INFINITY: Vector = Vector { e0: 1., e1: 0., e2: 0., e3: 0. };
+
+  #[test]
+  fn planes() {
+    let result = PLANE_X.try_intersect(PLANE_Y);
+    assert_eq!(dbg!(result), Ok(PLANE_X.meet(PLANE_Y)));
+  }
+
+  #[test]
+  fn parallel_planes() {
+    assert_eq!(dbg!(PLANE_X.try_intersect(PLANE_X_2)), Err(EgaError::Parallel));
+    assert_eq!(dbg!(PLANE_X.try_intersect(PLANE_X)), Err(EgaError::Parallel));
+  }
+
+  #[test]
+  fn nearly_parallel_planes() {
+    // scaling rounds the normal, so the planes are very slightly skew
+    let mut state = 0x9e37_79b9_u32;
+    let mut next = || {
+      state ^= state << 13;
+      state ^= state >> 17;
+      state ^= state << 5;
+      (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+    };
+    for _ in 0..200 {
+      let a = Vector { e0: next(), e1: next(), e2: next(), e3: next() };
+      let b = Vector { e0: a.e0 * 0.1 + 0.3, ..a * 0.1 };
+      assert_eq!(dbg!(a.try_intersect(b)), Err(EgaError::Parallel), "{a:?}");
+      let line = a.meet(PLANE_Y + PLANE_Z);
+      if line.norm_squared().s > 1e-2 {
+        let c = b + (PLANE_Y + PLANE_Z) * 0.01;
+        assert_eq!(dbg!(line.try_intersect(a)), Err(EgaError::Parallel));
+        assert!(dbg!(a.try_intersect(c)).is_ok());
+      }
+    }
+    // at a small angle, but not within the tolerance
+    let tilted = Vector { e1: 1e-3, ..PLANE_Y };
+    assert!(dbg!(PLANE_Y.try_intersect(tilted)).is_ok());
+  }
+
+  #[test]
+  fn ideal_plane() {
+    let result = PLANE_X.try_intersect(PLANE_AT_INFINITY);
+    assert_eq!(dbg!(result), Err(EgaError::IdealResult));
+    // a weight lost in the rounding of the distance
+    let nearly = Vector { e1: 1e-9, ..PLANE_AT_INFINITY };
+    assert_eq!(dbg!(PLANE_X.try_intersect(nearly)), Err(EgaError::IdealResult));
+  }
+
+  #[test]
+  fn zero_plane() {
+    assert_eq!(dbg!(PLANE_X.try_intersect(Vector::ZERO)), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn line_and_plane() {
+    let line = PLANE_X.meet(PLANE_Y);
+    let expected = Trivector { e123: 1., e032: 1., e013: 0., e021: 0. };
+    assert_eq!(dbg!(line.try_intersect(PLANE_Z)), Ok(expected));
+    assert_eq!(dbg!(PLANE_Z.try_intersect(line)), Ok(expected));
+  }
+
+  #[test]
+  fn line_parallel_to_plane() {
+    let line = PLANE_X.meet(PLANE_Y);
+    // parallel to the plane
+    assert_eq!(dbg!(line.try_intersect(PLANE_X_2)), Err(EgaError::Parallel));
+    // contained in the plane
+    assert_eq!(dbg!(line.try_intersect(PLANE_X)), Err(EgaError::Parallel));
+  }
+
+  #[test]
+  fn ideal_line() {
+    let line = PLANE_X.meet(PLANE_AT_INFINITY);
+    assert_eq!(dbg!(line.try_intersect(PLANE_Z)), Err(EgaError::IdealResult));
+    assert_eq!(dbg!(Bivector::ZERO.try_intersect(PLANE_Z)), Err(EgaError::ZeroNorm));
+  }
+}
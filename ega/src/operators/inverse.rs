@@ -4,6 +4,12 @@ pub trait Inverse {
   fn inverse(self) -> Self;
 }
 
+/// The inverse, for values which might not have one
+pub trait TryInverse: Sized {
+  /// The inverse, or [`EgaError::NotInvertible`] if the value has none
+  fn try_inverse(self) -> Result<Self, EgaError>;
+}
+
 impl Inverse for Scalar {
   #[inline]
   fn inverse(self) -> Self {
@@ -11,6 +17,17 @@ impl Inverse for Scalar {
   }
 }
 
+impl TryInverse for Scalar {
+  #[inline]
+  fn try_inverse(self) -> Result<Self, EgaError> {
+    // zero, & values small enough for the inverse to overflow, have none
+    match self.inverse() {
+      inverse if inverse.s.is_finite() => Ok(inverse),
+      _ => Err(EgaError::NotInvertible),
+    }
+  }
+}
+
 impl Inverse for Vector {
   #[inline]
   fn inverse(self) -> Self {
@@ -18,6 +35,13 @@ impl Inverse for Vector {
   }
 }
 
+impl TryInverse for Vector {
+  #[inline]
+  fn try_inverse(self) -> Result<Self, EgaError> {
+    simple_try_inverse(self)
+  }
+}
+
 impl Inverse for Trivector {
   #[inline]
   fn inverse(self) -> Self {
@@ -25,6 +49,13 @@ impl Inverse for Trivector {
   }
 }
 
+impl TryInverse for Trivector {
+  #[inline]
+  fn try_inverse(self) -> Result<Self, EgaError> {
+    simple_try_inverse(self)
+  }
+}
+
 // this is only valid for some kinds of elements
 #[inline]
 fn simple_inverse<T: Copy + Reverse + NormSquared + Mul<f32, Output = T>>(
@@ -33,6 +64,21 @@ fn simple_inverse<T: Copy + Reverse + NormSquared + Mul<f32, Output = T>>(
   value.reverse() * (1.0 / value.norm_squared().s)
 }
 
+// elements with no weight, i.e. ideal planes & points, square to zero, & a
+// tiny enough weight overflows the reciprocal of its square
+#[inline]
+fn simple_try_inverse<
+  T: Copy + Reverse + NormSquared + Mul<f32, Output = T>,
+>(
+  value: T,
+) -> Result<T, EgaError> {
+  let reciprocal = 1.0 / value.norm_squared().s;
+  if !reciprocal.is_finite() {
+    return Err(EgaError::NotInvertible);
+  }
+  Ok(value.reverse() * reciprocal)
+}
+
 #[cfg(any(test, doctest))]
 mod tests {
   use super::*;
@@ -119,4 +165,64 @@ mod tests {
     let expected = Multivector::from(Scalar::UNIT);
     assert_relative_eq!(dbg!(expected), dbg!(product));
   }
+
+  #[test]
+  fn try_inverse() {
+    assert_eq!(dbg!(SCALAR_A.try_inverse()), Ok(SCALAR_A.inverse()));
+    assert_eq!(dbg!(VECTOR_A.try_inverse()), Ok(VECTOR_A.inverse()));
+    assert_eq!(dbg!(TRIVECTOR_A.try_inverse()), Ok(TRIVECTOR_A.inverse()));
+  }
+
+  #[test]
+  fn try_inverse_zero() {
+    assert_eq!(
+      dbg!(Scalar { s: 0. }.try_inverse()),
+      Err(EgaError::NotInvertible)
+    );
+    assert_eq!(
+      dbg!(Vector::ZERO.try_inverse()),
+      Err(EgaError::NotInvertible)
+    );
+    assert_eq!(
+      dbg!(Trivector::ZERO.try_inverse()),
+      Err(EgaError::NotInvertible)
+    );
+  }
+
+  #[test]
+  fn try_inverse_tiny() {
+    // nonzero, but the inverses overflow
+    assert_eq!(
+      dbg!(Scalar { s: 1e-39 }.try_inverse()),
+      Err(EgaError::NotInvertible)
+    );
+    let plane = Vector {
+      e0: 1.,
+      e1: 1e-20,
+      e2: 0.,
+      e3: 0.,
+    };
+    assert_eq!(dbg!(plane.try_inverse()), Err(EgaError::NotInvertible));
+  }
+
+  #[test]
+  fn try_inverse_ideal() {
+    // the plane at infinity
+    let plane = Vector {
+      e0: 1.,
+      e1: 0.,
+      e2: 0.,
+      e3: 0.,
+    };
+    assert_eq!(dbg!(plane.try_inverse()), Err(EgaError::NotInvertible));
+
+    // a point at infinity
+    let point = Trivector {
+      e123: 0.,
+      e032: 1.,
+      e013: 2.,
+      e021: 3.,
+    };
+    assert_eq!(dbg!(point.try_inverse()), Err(EgaError::NotInvertible));
+  }
 }
@@ -1,4 +1,6 @@
 use super::return_empty;
+#[cfg(feature = "simd")]
+use crate::simd::join as simd_join;
 use crate::*;
 
 /// The regressive product
@@ -22,7 +24,10 @@ macro_rules! impl_join {
   };
 }
 
+#[cfg(not(feature = "simd"))]
 impl_join! { multivector_join_multivector: Multivector, Multivector => Multivector }
+#[cfg(feature = "simd")]
+impl_join! { simd_join: Multivector, Multivector => Multivector }
 impl_join! { multivector_join_scalar: Multivector, Scalar => Scalar }
 impl_join! { multivector_join_vector: Multivector, Vector => Multivector }
 impl_join! { multivector_join_bivector: Multivector, Bivector => Multivector }
@@ -78,11 +83,55 @@ impl_join! { return_empty: Empty, Bivector => Empty }
 impl_join! { return_empty: Empty, Trivector => Empty }
 impl_join! { return_empty: Empty, Pseudoscalar => Empty }
 
+impl_const_ops! { "The join (regressive product), usable in `const` contexts", Multivector {
+  join_multivector: multivector_join_multivector(Multivector) => Multivector,
+  join_scalar: multivector_join_scalar(Scalar) => Scalar,
+  join_vector: multivector_join_vector(Vector) => Multivector,
+  join_bivector: multivector_join_bivector(Bivector) => Multivector,
+  join_trivector: multivector_join_trivector(Trivector) => Multivector,
+  join_pseudoscalar: multivector_join_pseudoscalar(Pseudoscalar) => Multivector,
+} }
+
+impl_const_ops! { "The join (regressive product), usable in `const` contexts", Scalar {
+  join_multivector: scalar_join_multivector(Multivector) => Scalar,
+  join_pseudoscalar: scalar_join_pseudoscalar(Pseudoscalar) => Scalar,
+} }
+
+impl_const_ops! { "The join (regressive product), usable in `const` contexts", Vector {
+  join_multivector: vector_join_multivector(Multivector) => Multivector,
+  join_trivector: vector_join_trivector(Trivector) => Scalar,
+  join_pseudoscalar: vector_join_pseudoscalar(Pseudoscalar) => Vector,
+} }
+
+impl_const_ops! { "The join (regressive product), usable in `const` contexts", Bivector {
+  join_multivector: bivector_join_multivector(Multivector) => Multivector,
+  join_bivector: bivector_join_bivector(Bivector) => Scalar,
+  join_trivector: bivector_join_trivector(Trivector) => Vector,
+  join_pseudoscalar: bivector_join_pseudoscalar(Pseudoscalar) => Bivector,
+} }
+
+impl_const_ops! { "The join (regressive product), usable in `const` contexts", Trivector {
+  join_multivector: trivector_join_multivector(Multivector) => Multivector,
+  join_vector: trivector_join_vector(Vector) => Scalar,
+  join_bivector: trivector_join_bivector(Bivector) => Vector,
+  join_trivector: trivector_join_trivector(Trivector) => Bivector,
+  join_pseudoscalar: trivector_join_pseudoscalar(Pseudoscalar) => Trivector,
+} }
+
+impl_const_ops! { "The join (regressive product), usable in `const` contexts", Pseudoscalar {
+  join_multivector: pseudoscalar_join_multivector(Multivector) => Multivector,
+  join_scalar: pseudoscalar_join_scalar(Scalar) => Scalar,
+  join_vector: pseudoscalar_join_vector(Vector) => Vector,
+  join_bivector: pseudoscalar_join_bivector(Bivector) => Bivector,
+  join_trivector: pseudoscalar_join_trivector(Trivector) => Trivector,
+  join_pseudoscalar: pseudoscalar_join_pseudoscalar(Pseudoscalar) => Pseudoscalar,
+} }
+
 // Multivector
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_join_multivector(
+pub(crate) const fn multivector_join_multivector(
   lhs: Multivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -140,7 +189,7 @@ fn multivector_join_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_join_scalar(
+const fn multivector_join_scalar(
   lhs: Multivector,
   rhs: Scalar,
 ) -> Scalar {
@@ -153,7 +202,7 @@ fn multivector_join_scalar(
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_join_vector(
+const fn multivector_join_vector(
   lhs: Multivector,
   rhs: Vector,
 ) -> Multivector {
@@ -171,13 +220,13 @@ fn multivector_join_vector(
   Multivector {
     s,
     e0,    e1,    e2,    e3,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_join_bivector(
+const fn multivector_join_bivector(
   lhs: Multivector,
   rhs: Bivector,
 ) -> Multivector {
@@ -209,13 +258,13 @@ fn multivector_join_bivector(
      e0,    e1,    e2,    e3,
       s,   e23,   e31,   e12,
     e01,   e02,   e03,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_join_trivector(
+const fn multivector_join_trivector(
   lhs: Multivector,
   rhs: Trivector,
 ) -> Multivector {
@@ -253,13 +302,13 @@ fn multivector_join_trivector(
        s,   e23,   e31,   e12,
      e01,   e02,   e03,
     e123,  e032,  e013,  e021,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_join_pseudoscalar(
+const fn multivector_join_pseudoscalar(
   lhs: Multivector,
   rhs: Pseudoscalar,
 ) -> Multivector {
@@ -294,7 +343,7 @@ fn multivector_join_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn scalar_join_multivector(
+const fn scalar_join_multivector(
   lhs: Scalar,
   rhs: Multivector,
 ) -> Scalar {
@@ -307,7 +356,7 @@ fn scalar_join_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn scalar_join_pseudoscalar(
+const fn scalar_join_pseudoscalar(
   lhs: Scalar,
   rhs: Pseudoscalar,
 ) -> Scalar {
@@ -322,7 +371,7 @@ fn scalar_join_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn vector_join_multivector(
+const fn vector_join_multivector(
   lhs: Vector,
   rhs: Multivector,
 ) -> Multivector {
@@ -340,13 +389,13 @@ fn vector_join_multivector(
   Multivector {
     e0,    e1,    e2,    e3,
     s,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn vector_join_trivector(
+const fn vector_join_trivector(
   lhs: Vector,
   rhs: Trivector,
 ) -> Scalar {
@@ -362,7 +411,7 @@ fn vector_join_trivector(
 
 #[rustfmt::skip]
 #[inline]
-fn vector_join_pseudoscalar(
+const fn vector_join_pseudoscalar(
   lhs: Vector,
   rhs: Pseudoscalar,
 ) -> Vector {
@@ -380,7 +429,7 @@ fn vector_join_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_join_multivector(
+const fn bivector_join_multivector(
   lhs: Bivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -412,13 +461,13 @@ fn bivector_join_multivector(
      e0,    e1,    e2,    e3,
       s,   e23,   e31,   e12,
     e01,   e02,   e03,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_join_bivector(
+const fn bivector_join_bivector(
   lhs: Bivector,
   rhs: Bivector,
 ) -> Scalar {
@@ -433,7 +482,7 @@ fn bivector_join_bivector(
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_join_trivector(
+const fn bivector_join_trivector(
   lhs: Bivector,
   rhs: Trivector,
 ) -> Vector {
@@ -457,7 +506,7 @@ fn bivector_join_trivector(
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_join_pseudoscalar(
+const fn bivector_join_pseudoscalar(
   lhs: Bivector,
   rhs: Pseudoscalar,
 ) -> Bivector {
@@ -480,7 +529,7 @@ fn bivector_join_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_join_multivector(
+const fn trivector_join_multivector(
   lhs: Trivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -518,13 +567,13 @@ fn trivector_join_multivector(
        s,   e23,   e31,   e12,
      e01,   e02,   e03,
     e123,  e032,  e013,  e021,
-    ..zero()
+    ..Multivector::ZERO
   }
 }
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_join_vector(
+const fn trivector_join_vector(
   lhs: Trivector,
   rhs: Vector,
 ) -> Scalar {
@@ -540,7 +589,7 @@ fn trivector_join_vector(
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_join_bivector(
+const fn trivector_join_bivector(
   lhs: Trivector,
   rhs: Bivector,
 ) -> Vector {
@@ -564,7 +613,7 @@ fn trivector_join_bivector(
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_join_trivector(
+const fn trivector_join_trivector(
   lhs: Trivector,
   rhs: Trivector,
 ) -> Bivector {
@@ -585,7 +634,7 @@ fn trivector_join_trivector(
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_join_pseudoscalar(
+const fn trivector_join_pseudoscalar(
   lhs: Trivector,
   rhs: Pseudoscalar,
 ) -> Trivector {
@@ -603,7 +652,7 @@ fn trivector_join_pseudoscalar(
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_join_multivector(
+const fn pseudoscalar_join_multivector(
   lhs: Pseudoscalar,
   rhs: Multivector,
 ) -> Multivector {
@@ -636,7 +685,7 @@ fn pseudoscalar_join_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_join_scalar(
+const fn pseudoscalar_join_scalar(
   lhs: Pseudoscalar,
   rhs: Scalar,
 ) -> Scalar {
@@ -649,7 +698,7 @@ fn pseudoscalar_join_scalar(
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_join_vector(
+const fn pseudoscalar_join_vector(
   lhs: Pseudoscalar,
   rhs: Vector,
 ) -> Vector {
@@ -665,7 +714,7 @@ fn pseudoscalar_join_vector(
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_join_bivector(
+const fn pseudoscalar_join_bivector(
   lhs: Pseudoscalar,
   rhs: Bivector,
 ) -> Bivector {
@@ -686,7 +735,7 @@ fn pseudoscalar_join_bivector(
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_join_trivector(
+const fn pseudoscalar_join_trivector(
   lhs: Pseudoscalar,
   rhs: Trivector,
 ) -> Trivector {
@@ -702,7 +751,7 @@ fn pseudoscalar_join_trivector(
 
 #[rustfmt::skip]
 #[inline]
-fn pseudoscalar_join_pseudoscalar(
+const fn pseudoscalar_join_pseudoscalar(
   lhs: Pseudoscalar,
   rhs: Pseudoscalar,
 ) -> Pseudoscalar {
@@ -1066,7 +1115,6 @@ mod tests {
         e12: 7543., e31: 6749., e23: 5161.,
         e021: 21041., e013: 18659., e032: 17071., e123: 16277.,
         e0123: 14689.,
-        ..zero()
       };
       assert_eq!(dbg!(result), dbg!(expected));
     }
@@ -1108,4 +1156,13 @@ mod tests {
       assert_eq!(dbg!(result), dbg!(expected));
     }
   }
+
+  #[test]
+  fn const_joins() {
+    const TRIVECTOR_TRIVECTOR: Bivector = TRIVECTOR_A.join_trivector(TRIVECTOR_B);
+    assert_eq!(dbg!(TRIVECTOR_TRIVECTOR), dbg!(TRIVECTOR_A.join(TRIVECTOR_B)));
+
+    const MULTIVECTORS: Multivector = MULTIVECTOR_A.join_multivector(MULTIVECTOR_B);
+    assert_eq!(dbg!(MULTIVECTORS), dbg!(MULTIVECTOR_A.join(MULTIVECTOR_B)));
+  }
 }
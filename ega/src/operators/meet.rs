@@ -1,4 +1,6 @@
-use super::return_empty;
+use super::{return_empty, scale};
+#[cfg(feature = "simd")]
+use crate::simd::meet as simd_meet;
 use crate::*;
 
 /// The outer product
@@ -22,7 +24,10 @@ macro_rules! impl_meet {
   };
 }
 
+#[cfg(not(feature = "simd"))]
 impl_meet! { multivector_meet_multivector: Multivector, Multivector => Multivector }
+#[cfg(feature = "simd")]
+impl_meet! { simd_meet: Multivector, Multivector => Multivector }
 impl_meet! { multivector_meet_scalar: Multivector, Scalar => Multivector }
 impl_meet! { multivector_meet_vector: Multivector, Vector => Multivector }
 impl_meet! { multivector_meet_bivector: Multivector, Bivector => Multivector }
@@ -78,11 +83,55 @@ impl_meet! { return_empty: Empty, Bivector => Empty }
 impl_meet! { return_empty: Empty, Trivector => Empty }
 impl_meet! { return_empty: Empty, Pseudoscalar => Empty }
 
+impl_const_ops! { "The meet (outer product), usable in `const` contexts", Multivector {
+  meet_multivector: multivector_meet_multivector(Multivector) => Multivector,
+  meet_scalar: multivector_meet_scalar(Scalar) => Multivector,
+  meet_vector: multivector_meet_vector(Vector) => Multivector,
+  meet_bivector: multivector_meet_bivector(Bivector) => Multivector,
+  meet_trivector: multivector_meet_trivector(Trivector) => Multivector,
+  meet_pseudoscalar: multivector_meet_pseudoscalar(Pseudoscalar) => Pseudoscalar,
+} }
+
+impl_const_ops! { "The meet (outer product), usable in `const` contexts", Scalar {
+  meet_multivector: scalar_meet_multivector(Multivector) => Multivector,
+  meet_scalar: scalar_meet_scalar(Scalar) => Scalar,
+  meet_vector: scalar_meet_vector(Vector) => Vector,
+  meet_bivector: scalar_meet_bivector(Bivector) => Bivector,
+  meet_trivector: scalar_meet_trivector(Trivector) => Trivector,
+  meet_pseudoscalar: scalar_meet_pseudoscalar(Pseudoscalar) => Pseudoscalar,
+} }
+
+impl_const_ops! { "The meet (outer product), usable in `const` contexts", Vector {
+  meet_multivector: vector_meet_multivector(Multivector) => Multivector,
+  meet_scalar: vector_meet_scalar(Scalar) => Vector,
+  meet_vector: vector_meet_vector(Vector) => Bivector,
+  meet_bivector: vector_meet_bivector(Bivector) => Trivector,
+  meet_trivector: vector_meet_trivector(Trivector) => Pseudoscalar,
+} }
+
+impl_const_ops! { "The meet (outer product), usable in `const` contexts", Bivector {
+  meet_multivector: bivector_meet_multivector(Multivector) => Multivector,
+  meet_scalar: bivector_meet_scalar(Scalar) => Bivector,
+  meet_vector: bivector_meet_vector(Vector) => Trivector,
+  meet_bivector: bivector_meet_bivector(Bivector) => Pseudoscalar,
+} }
+
+impl_const_ops! { "The meet (outer product), usable in `const` contexts", Trivector {
+  meet_multivector: trivector_meet_multivector(Multivector) => Multivector,
+  meet_scalar: trivector_meet_scalar(Scalar) => Trivector,
+  meet_vector: trivector_meet_vector(Vector) => Pseudoscalar,
+} }
+
+impl_const_ops! { "The meet (outer product), usable in `const` contexts", Pseudoscalar {
+  meet_multivector: pseudoscalar_meet_multivector(Multivector) => Pseudoscalar,
+  meet_scalar: pseudoscalar_meet_scalar(Scalar) => Pseudoscalar,
+} }
+
 // Multivector
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_meet_multivector(
+pub(crate) const fn multivector_meet_multivector(
   lhs: Multivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -139,14 +188,17 @@ fn multivector_meet_multivector(
 }
 
 #[inline]
-fn multivector_meet_scalar(lhs: Multivector, rhs: Scalar) -> Multivector {
-  let elements = lhs.to_multivector_array().elements.map(|e| e * rhs.s);
-  MultivectorArray::from(elements).to_multivector()
+const fn multivector_meet_scalar(
+  lhs: Multivector,
+  rhs: Scalar,
+) -> Multivector {
+  let elements = scale(lhs.to_multivector_array().elements, rhs.s);
+  MultivectorArray { elements }.to_multivector()
 }
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_meet_vector(lhs: Multivector, rhs: Vector) -> Multivector {
+const fn multivector_meet_vector(lhs: Multivector, rhs: Vector) -> Multivector {
   let (a, b) = (lhs, rhs);
   let s = 0f32;
 
@@ -178,7 +230,7 @@ fn multivector_meet_vector(lhs: Multivector, rhs: Vector) -> Multivector {
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_meet_bivector(
+const fn multivector_meet_bivector(
   lhs: Multivector,
   rhs: Bivector,
 ) -> Multivector {
@@ -209,7 +261,7 @@ fn multivector_meet_bivector(
 
 #[rustfmt::skip]
 #[inline]
-fn multivector_meet_trivector(
+const fn multivector_meet_trivector(
   lhs: Multivector,
   rhs: Trivector,
 ) -> Multivector {
@@ -231,7 +283,7 @@ fn multivector_meet_trivector(
 }
 
 #[inline]
-fn multivector_meet_pseudoscalar(
+const fn multivector_meet_pseudoscalar(
   lhs: Multivector,
   rhs: Pseudoscalar,
 ) -> Pseudoscalar {
@@ -243,50 +295,58 @@ fn multivector_meet_pseudoscalar(
 // Scalar
 
 #[inline]
-fn scalar_meet_scalar(lhs: Scalar, rhs: Scalar) -> Scalar {
+const fn scalar_meet_scalar(lhs: Scalar, rhs: Scalar) -> Scalar {
   Scalar { s: lhs.s * rhs.s }
 }
 
 #[inline]
-fn scalar_meet_vector(lhs: Scalar, rhs: Vector) -> Vector {
-  let elements = rhs.to_vector_array().elements.map(|e| lhs.s * e);
-  VectorArray::from(elements).to_vector()
+const fn scalar_meet_vector(lhs: Scalar, rhs: Vector) -> Vector {
+  let elements = scale(rhs.to_vector_array().elements, lhs.s);
+  VectorArray { elements }.to_vector()
 }
 
 #[inline]
-fn scalar_meet_bivector(lhs: Scalar, rhs: Bivector) -> Bivector {
-  let elements = rhs.to_bivector_array().elements.map(|e| lhs.s * e);
-  BivectorArray::from(elements).to_bivector()
+const fn scalar_meet_bivector(lhs: Scalar, rhs: Bivector) -> Bivector {
+  let elements = scale(rhs.to_bivector_array().elements, lhs.s);
+  BivectorArray { elements }.to_bivector()
 }
 
 #[inline]
-fn scalar_meet_trivector(lhs: Scalar, rhs: Trivector) -> Trivector {
-  let elements = rhs.to_trivector_array().elements.map(|e| lhs.s * e);
-  TrivectorArray::from(elements).to_trivector()
+const fn scalar_meet_trivector(lhs: Scalar, rhs: Trivector) -> Trivector {
+  let elements = scale(rhs.to_trivector_array().elements, lhs.s);
+  TrivectorArray { elements }.to_trivector()
 }
 
 #[inline]
-fn scalar_meet_pseudoscalar(lhs: Scalar, rhs: Pseudoscalar) -> Pseudoscalar {
-  Pseudoscalar::from(lhs.s * rhs.e0123)
+const fn scalar_meet_pseudoscalar(
+  lhs: Scalar,
+  rhs: Pseudoscalar,
+) -> Pseudoscalar {
+  Pseudoscalar {
+    e0123: lhs.s * rhs.e0123,
+  }
 }
 
 #[inline]
-fn scalar_meet_multivector(lhs: Scalar, rhs: Multivector) -> Multivector {
-  let elements = rhs.to_multivector_array().elements.map(|e| lhs.s * e);
-  MultivectorArray::from(elements).to_multivector()
+const fn scalar_meet_multivector(
+  lhs: Scalar,
+  rhs: Multivector,
+) -> Multivector {
+  let elements = scale(rhs.to_multivector_array().elements, lhs.s);
+  MultivectorArray { elements }.to_multivector()
 }
 
 // Vector
 
 #[inline]
-fn vector_meet_scalar(lhs: Vector, rhs: Scalar) -> Vector {
-  let elements = lhs.to_vector_array().elements.map(|e| rhs.s * e);
-  VectorArray::from(elements).to_vector()
+const fn vector_meet_scalar(lhs: Vector, rhs: Scalar) -> Vector {
+  let elements = scale(lhs.to_vector_array().elements, rhs.s);
+  VectorArray { elements }.to_vector()
 }
 
 #[rustfmt::skip]
 #[inline]
-fn vector_meet_vector(lhs: Vector, rhs: Vector) -> Bivector {
+const fn vector_meet_vector(lhs: Vector, rhs: Vector) -> Bivector {
   let (p, q) = (lhs, rhs);
 
   let e23 = p.e2*q.e3 - p.e3*q.e2;
@@ -301,7 +361,7 @@ fn vector_meet_vector(lhs: Vector, rhs: Vector) -> Bivector {
 
 #[rustfmt::skip]
 #[inline]
-fn vector_meet_bivector(lhs: Vector, rhs: Bivector) -> Trivector {
+const fn vector_meet_bivector(lhs: Vector, rhs: Bivector) -> Trivector {
   let (p, l) = (lhs, rhs);
 
   let e123 = p.e1*l.e23 + p.e2*l.e31 + p.e3*l.e12;
@@ -314,7 +374,7 @@ fn vector_meet_bivector(lhs: Vector, rhs: Bivector) -> Trivector {
 
 #[rustfmt::skip]
 #[inline]
-fn vector_meet_trivector(lhs: Vector, rhs: Trivector) -> Pseudoscalar {
+const fn vector_meet_trivector(lhs: Vector, rhs: Trivector) -> Pseudoscalar {
   let (p, x) = (lhs, rhs);
   let e0123 = p.e0*x.e123 + p.e1*x.e032 + p.e2*x.e013 + p.e3*x.e021;
 
@@ -323,7 +383,7 @@ fn vector_meet_trivector(lhs: Vector, rhs: Trivector) -> Pseudoscalar {
 
 #[rustfmt::skip]
 #[inline]
-fn vector_meet_multivector(lhs: Vector, rhs: Multivector) -> Multivector {
+const fn vector_meet_multivector(lhs: Vector, rhs: Multivector) -> Multivector {
   let (a, b) = (lhs, rhs);
   let s = 0f32;
 
@@ -354,14 +414,14 @@ fn vector_meet_multivector(lhs: Vector, rhs: Multivector) -> Multivector {
 // Bivector
 
 #[inline]
-fn bivector_meet_scalar(lhs: Bivector, rhs: Scalar) -> Bivector {
-  let elements = lhs.to_bivector_array().elements.map(|e| rhs.s * e);
-  BivectorArray::from(elements).to_bivector()
+const fn bivector_meet_scalar(lhs: Bivector, rhs: Scalar) -> Bivector {
+  let elements = scale(lhs.to_bivector_array().elements, rhs.s);
+  BivectorArray { elements }.to_bivector()
 }
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_meet_vector(lhs: Bivector, rhs: Vector) -> Trivector {
+const fn bivector_meet_vector(lhs: Bivector, rhs: Vector) -> Trivector {
   let (a, b) = (lhs, rhs);
 
   let e123 = a.e23*b.e1 + a.e31*b.e2 + a.e12*b.e3;
@@ -374,7 +434,7 @@ fn bivector_meet_vector(lhs: Bivector, rhs: Vector) -> Trivector {
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_meet_bivector(lhs: Bivector, rhs: Bivector) -> Pseudoscalar {
+const fn bivector_meet_bivector(lhs: Bivector, rhs: Bivector) -> Pseudoscalar {
   let (l, m) = (lhs, rhs);
   let e0123 = l.e01*m.e23 + l.e23*m.e01
             + l.e02*m.e31 + l.e31*m.e02
@@ -385,7 +445,7 @@ fn bivector_meet_bivector(lhs: Bivector, rhs: Bivector) -> Pseudoscalar {
 
 #[rustfmt::skip]
 #[inline]
-fn bivector_meet_multivector(
+const fn bivector_meet_multivector(
   lhs: Bivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -417,14 +477,14 @@ fn bivector_meet_multivector(
 // Trivector
 
 #[inline]
-fn trivector_meet_scalar(lhs: Trivector, rhs: Scalar) -> Trivector {
-  let elements = lhs.to_trivector_array().elements.map(|e| rhs.s * e);
-  TrivectorArray::from(elements).to_trivector()
+const fn trivector_meet_scalar(lhs: Trivector, rhs: Scalar) -> Trivector {
+  let elements = scale(lhs.to_trivector_array().elements, rhs.s);
+  TrivectorArray { elements }.to_trivector()
 }
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_meet_multivector(
+const fn trivector_meet_multivector(
   lhs: Trivector,
   rhs: Multivector,
 ) -> Multivector {
@@ -447,7 +507,7 @@ fn trivector_meet_multivector(
 
 #[rustfmt::skip]
 #[inline]
-fn trivector_meet_vector(lhs: Trivector, rhs: Vector) -> Pseudoscalar {
+const fn trivector_meet_vector(lhs: Trivector, rhs: Vector) -> Pseudoscalar {
   let (a, b) = (lhs, rhs);
   let e0123 = -a.e123*b.e0 - a.e032*b.e1 - a.e013*b.e2 - a.e021*b.e3;
 
@@ -457,7 +517,7 @@ fn trivector_meet_vector(lhs: Trivector, rhs: Vector) -> Pseudoscalar {
 // Pseudoscalar
 
 #[inline]
-fn pseudoscalar_meet_multivector(
+const fn pseudoscalar_meet_multivector(
   lhs: Pseudoscalar,
   rhs: Multivector,
 ) -> Pseudoscalar {
@@ -467,7 +527,10 @@ fn pseudoscalar_meet_multivector(
 }
 
 #[inline]
-fn pseudoscalar_meet_scalar(lhs: Pseudoscalar, rhs: Scalar) -> Pseudoscalar {
+const fn pseudoscalar_meet_scalar(
+  lhs: Pseudoscalar,
+  rhs: Scalar,
+) -> Pseudoscalar {
   Pseudoscalar {
     e0123: lhs.e0123 * rhs.s,
   }
@@ -635,7 +698,6 @@ mod tests {
            s: -10001.,  e23: -10823.,  e31: -11371.,   e12: -12193.,
          e01: -13289.,  e02: -13837.,  e03: -14111., e0123: -14659.,
         e123: -14933., e032: -15481., e013: -17399.,  e021: -17947.,
-        ..Multivector::zero()
       };
       assert_eq!(dbg!(result), dbg!(expected));
     }
@@ -736,7 +798,6 @@ mod tests {
            s:      0.,  e23:   -384.,  e31:    960.,   e12:   -576.,
          e01:     52.,  e02:   -500.,  e03:   -868., e0123: -76778.,
         e123: -40795., e032:  12007., e013:  12505.,  e021:  13393.,
-        ..Multivector::zero()
       };
       assert_eq!(dbg!(result), dbg!(expected));
     }
@@ -1025,4 +1086,16 @@ mod tests {
       assert_eq!(dbg!(result), dbg!(expected));
     }
   }
+
+  #[test]
+  fn const_meets() {
+    const VECTOR_VECTOR: Bivector = VECTOR_A.meet_vector(VECTOR_B);
+    assert_eq!(dbg!(VECTOR_VECTOR), dbg!(VECTOR_A.meet(VECTOR_B)));
+
+    const MULTIVECTORS: Multivector = MULTIVECTOR_A.meet_multivector(MULTIVECTOR_B);
+    assert_eq!(dbg!(MULTIVECTORS), dbg!(MULTIVECTOR_A.meet(MULTIVECTOR_B)));
+
+    const SCALAR_TRIVECTOR: Trivector = SCALAR_A.meet_trivector(TRIVECTOR_A);
+    assert_eq!(dbg!(SCALAR_TRIVECTOR), dbg!(SCALAR_A.meet(TRIVECTOR_A)));
+  }
 }
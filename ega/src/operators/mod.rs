@@ -1,4 +1,83 @@
+/// Implement a binary operator on references, by copying the operands
+///
+/// Every value type is `Copy`, so `&lhs op rhs`, `lhs op &rhs` &
+/// `&lhs op &rhs` are the same as `lhs op rhs`.
+macro_rules! impl_ref_ops {
+  ($op:ident::$op_fn:ident: $lhs:ty, $rhs:ty) => {
+    impl $op<&$rhs> for $lhs {
+      type Output = <$lhs as $op<$rhs>>::Output;
+
+      #[inline]
+      fn $op_fn(self, rhs: &$rhs) -> Self::Output {
+        $op::$op_fn(self, *rhs)
+      }
+    }
+
+    impl $op<$rhs> for &$lhs {
+      type Output = <$lhs as $op<$rhs>>::Output;
+
+      #[inline]
+      fn $op_fn(self, rhs: $rhs) -> Self::Output {
+        $op::$op_fn(*self, rhs)
+      }
+    }
+
+    impl $op<&$rhs> for &$lhs {
+      type Output = <$lhs as $op<$rhs>>::Output;
+
+      #[inline]
+      fn $op_fn(self, rhs: &$rhs) -> Self::Output {
+        $op::$op_fn(*self, *rhs)
+      }
+    }
+  };
+}
+
+/// Implement a compound assignment operator for each of the types, wherever
+/// the binary operator returns the type of the left-hand-side
+macro_rules! impl_assign_ops {
+  ($op:ident::$op_fn:ident, $assign:ident::$assign_fn:ident: $($type:ty),*) => {
+    $(
+      impl<Rhs> $assign<Rhs> for $type
+      where
+        $type: $op<Rhs, Output = $type>,
+      {
+        #[inline]
+        fn $assign_fn(&mut self, rhs: Rhs) {
+          *self = $op::$op_fn(*self, rhs);
+        }
+      }
+    )*
+  };
+}
+
+/// Implement inherent `const fn` versions of a product, calling the same
+/// functions as the operator traits
+///
+/// usage: `impl_const_ops! { "<doc>", <lhs> { <method>: <fn>(<rhs>) => <out> } }`
+macro_rules! impl_const_ops {
+  (
+    $doc:literal, $lhs:ty {
+      $($method:ident: $op_fn:ident($rhs:ty) => $output:ty),+ $(,)?
+    }
+  ) => {
+    impl $lhs {
+      $(
+        #[doc = $doc]
+        #[inline]
+        pub const fn $method(self, rhs: $rhs) -> $output {
+          $op_fn(self, rhs)
+        }
+      )+
+    }
+  };
+}
+
 mod add;
+mod bulk_weight;
+mod bulk_weight_dual;
+mod classify;
+mod complement;
 mod conjugate;
 mod div;
 mod dot;
@@ -6,37 +85,61 @@ mod geometric_product;
 mod grade_select;
 mod hodge_dual;
 mod ideal_norm;
+mod intersect;
 mod inverse;
 mod join;
 mod meet;
 mod mul;
 mod neg;
 mod norm;
+// needs sqrt function, so relies on std or libm
+#[cfg(any(feature = "std", feature = "libm"))]
 mod normalise;
 mod partial_eq;
+mod polarity;
 mod reverse;
 mod scalar_product;
 mod sub;
+// needs sqrt function, so relies on std or libm
+#[cfg(any(feature = "std", feature = "libm"))]
+mod unitize;
 
-pub use add::Add;
+pub use add::{Add, AddAssign};
+pub use bulk_weight::{Bulk, BulkNorm, GeometricNorm, Weight, WeightNorm};
+pub use bulk_weight_dual::{BulkDual, WeightDual};
+pub use classify::{Classify, GeometricObject};
+pub use complement::{LeftComplement, RightComplement};
 pub use conjugate::Conjugate;
-pub use div::Div;
+pub use div::{Div, DivAssign};
 pub use dot::Dot;
 pub use geometric_product::GeometricProduct;
 pub use grade_select::GradeSelect;
 pub use hodge_dual::HodgeDual;
 pub use ideal_norm::{IdealNorm, IdealNormSquared};
-pub use inverse::Inverse;
+pub use intersect::TryIntersect;
+pub use inverse::{Inverse, TryInverse};
 pub use join::Join;
 pub use meet::Meet;
-pub use mul::Mul;
+pub use mul::{Mul, MulAssign};
 pub use neg::Neg;
 pub use norm::{Norm, NormSquared};
-pub use normalise::Normalise;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use normalise::{Normalise, TryNormalise};
 pub use partial_eq::PartialEq;
+pub use polarity::Polarity;
 pub use reverse::Reverse;
 pub use scalar_product::ScalarProduct;
-pub use sub::Sub;
+pub use sub::{Sub, SubAssign};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use unitize::Unitize;
+
+// the scalar paths, for the `simd` module's fallback & tests
+#[cfg(any(test, doctest, not(target_arch = "x86_64")))]
+pub(crate) use geometric_product::multivector_mul_multivector;
+#[cfg(any(test, doctest, not(target_arch = "x86_64")))]
+pub(crate) use join::multivector_join_multivector;
+#[cfg(any(test, doctest, not(target_arch = "x86_64")))]
+pub(crate) use meet::multivector_meet_multivector;
 
 use crate::{values::Empty, Zero};
 
@@ -80,6 +183,17 @@ fn return_neg_rhs<Lhs, Rhs: Neg<Output = Rhs>>(_: Lhs, rhs: Rhs) -> Rhs {
   -rhs
 }
 
+/// multiply each of the elements by `s`
+#[inline]
+const fn scale<const N: usize>(mut elements: [f32; N], s: f32) -> [f32; N] {
+  let mut i = 0;
+  while i < N {
+    elements[i] *= s;
+    i += 1;
+  }
+  elements
+}
+
 /// return zero
 #[inline]
 fn return_zero<Lhs, Rhs, Output: Zero>(_: Lhs, _: Rhs) -> Output {
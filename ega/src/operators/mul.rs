@@ -1,6 +1,7 @@
 use crate::*;
+use ::core::iter::Product;
 
-pub use ::core::ops::Mul;
+pub use ::core::ops::{Mul, MulAssign};
 
 macro_rules! impl_mul {
   // we wrap `f32`s with `Scalar`
@@ -14,6 +15,8 @@ macro_rules! impl_mul {
         self.geometric_product(Scalar { s: rhs })
       }
     }
+
+    impl_ref_ops! { Mul::mul: $lhs, f32 }
   };
   (f32, $rhs:ty => $output:ty) => {
     impl Mul<$rhs> for f32 {
@@ -25,6 +28,8 @@ macro_rules! impl_mul {
         Scalar { s: self }.geometric_product(rhs)
       }
     }
+
+    impl_ref_ops! { Mul::mul: f32, $rhs }
   };
   ($lhs:ty, $rhs:ty => $output:ty) => {
     impl Mul<$rhs> for $lhs {
@@ -36,6 +41,8 @@ macro_rules! impl_mul {
         self.geometric_product(rhs)
       }
     }
+
+    impl_ref_ops! { Mul::mul: $lhs, $rhs }
   };
 }
 
@@ -110,3 +117,74 @@ impl_mul! { Empty, Bivector => Empty }
 impl_mul! { Empty, Trivector => Empty }
 impl_mul! { Empty, Pseudoscalar => Empty }
 impl_mul! { Empty, Empty => Empty }
+
+impl_mul! { Motor, Motor => Motor }
+
+impl_assign_ops! { Mul::mul, MulAssign::mul_assign: Multivector, Scalar, Vector, Bivector, Trivector, Pseudoscalar, Empty, Motor }
+
+macro_rules! impl_product {
+  ($($type:ty: $one:expr),*) => {
+    $(
+      impl Product for $type {
+        #[inline]
+        fn product<I: Iterator<Item = $type>>(iter: I) -> $type {
+          iter.fold($one, |acc, value| acc * value)
+        }
+      }
+
+      impl<'a> Product<&'a $type> for $type {
+        #[inline]
+        fn product<I: Iterator<Item = &'a $type>>(iter: I) -> $type {
+          iter.fold($one, |acc, value| acc * value)
+        }
+      }
+    )*
+  };
+}
+
+// only the types closed under the geometric product, with a value for `1`
+impl_product! {
+  Multivector: Multivector { s: 1., ..zero() },
+  Scalar: Scalar { s: 1. },
+  Motor: Motor::identity()
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  #[test]
+  #[allow(clippy::op_ref)]
+  fn references() {
+    let expected = MULTIVECTOR_A * MULTIVECTOR_B;
+    assert_eq!(dbg!(&MULTIVECTOR_A * MULTIVECTOR_B), dbg!(expected));
+    assert_eq!(dbg!(MULTIVECTOR_A * &MULTIVECTOR_B), dbg!(expected));
+    assert_eq!(dbg!(&MULTIVECTOR_A * &MULTIVECTOR_B), dbg!(expected));
+    assert_eq!(dbg!(2. * &VECTOR_A), dbg!(&VECTOR_A * &2.));
+  }
+
+  #[test]
+  fn mul_assign() {
+    let mut result = MULTIVECTOR_A;
+    result *= VECTOR_A;
+    result *= &MULTIVECTOR_B;
+    assert_eq!(dbg!(result), dbg!(MULTIVECTOR_A * VECTOR_A * MULTIVECTOR_B));
+
+    let mut result = BIVECTOR_A;
+    result *= 2.;
+    assert_eq!(dbg!(result), dbg!(BIVECTOR_A * 2.));
+  }
+
+  #[test]
+  fn product() {
+    let values = [MULTIVECTOR_A, MULTIVECTOR_B, MULTIVECTOR_C];
+    let expected = MULTIVECTOR_A * MULTIVECTOR_B * MULTIVECTOR_C;
+    assert_eq!(dbg!(values.iter().product::<Multivector>()), dbg!(expected));
+    assert_eq!(dbg!(values.into_iter().product::<Multivector>()), dbg!(expected));
+
+    let motors: [Motor; 0] = [];
+    assert_eq!(dbg!(motors.iter().product::<Motor>()), Motor::identity());
+  }
+}
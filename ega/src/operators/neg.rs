@@ -2,69 +2,60 @@ use crate::*;
 
 pub use ::core::ops::Neg;
 
-impl Neg for Multivector {
-  type Output = Multivector;
-
-  #[inline]
-  fn neg(self) -> Self::Output {
-    MultivectorArray::from(self.to_multivector_array().elements.map(|e| -e))
-      .to_multivector()
+/// negate each of the elements
+#[inline]
+const fn negate<const N: usize>(mut elements: [f32; N]) -> [f32; N] {
+  let mut i = 0;
+  while i < N {
+    elements[i] = -elements[i];
+    i += 1;
   }
+  elements
 }
 
-impl Neg for Empty {
-  type Output = Empty;
-
-  #[inline]
-  fn neg(self) -> Self::Output {
-    Empty
-  }
+macro_rules! impl_neg {
+  ($type:ident: $array:ident, $to_array:ident, $from_array:ident) => {
+    impl $type {
+      /// The negation, usable in `const` contexts
+      #[inline]
+      pub const fn neg(self) -> $type {
+        let elements = negate(self.$to_array().elements);
+        $array { elements }.$from_array()
+      }
+    }
+
+    impl Neg for $type {
+      type Output = $type;
+
+      #[inline]
+      fn neg(self) -> Self::Output {
+        $type::neg(self)
+      }
+    }
+  };
 }
 
-impl Neg for Scalar {
-  type Output = Scalar;
+impl_neg! { Multivector: MultivectorArray, to_multivector_array, to_multivector }
+impl_neg! { Scalar: ScalarArray, to_scalar_array, to_scalar }
+impl_neg! { Vector: VectorArray, to_vector_array, to_vector }
+impl_neg! { Bivector: BivectorArray, to_bivector_array, to_bivector }
+impl_neg! { Trivector: TrivectorArray, to_trivector_array, to_trivector }
+impl_neg! { Pseudoscalar: PseudoscalarArray, to_pseudoscalar_array, to_pseudoscalar }
 
+impl Empty {
+  /// The negation, usable in `const` contexts
   #[inline]
-  fn neg(self) -> Self::Output {
-    Scalar::from(-self.s)
-  }
-}
-
-impl Neg for Vector {
-  type Output = Vector;
-
-  #[inline]
-  fn neg(self) -> Self::Output {
-    VectorArray::from(self.to_vector_array().elements.map(|e| -e)).to_vector()
-  }
-}
-
-impl Neg for Bivector {
-  type Output = Bivector;
-
-  #[inline]
-  fn neg(self) -> Self::Output {
-    BivectorArray::from(self.to_bivector_array().elements.map(|e| -e))
-      .to_bivector()
-  }
-}
-
-impl Neg for Trivector {
-  type Output = Trivector;
-
-  #[inline]
-  fn neg(self) -> Self::Output {
-    TrivectorArray::from(self.to_trivector_array().elements.map(|e| -e))
-      .to_trivector()
+  pub const fn neg(self) -> Empty {
+    Empty
   }
 }
 
-impl Neg for Pseudoscalar {
-  type Output = Pseudoscalar;
+impl Neg for Empty {
+  type Output = Empty;
 
   #[inline]
   fn neg(self) -> Self::Output {
-    Pseudoscalar::from(-self.e0123)
+    Empty
   }
 }
 
@@ -123,4 +114,13 @@ mod tests {
     let expected = Pseudoscalar { e0123: -397. };
     assert_eq!(dbg!(result), dbg!(expected));
   }
+
+  #[test]
+  fn const_neg() {
+    const MULTIVECTOR: Multivector = MULTIVECTOR_A.neg();
+    assert_eq!(dbg!(MULTIVECTOR), dbg!(-MULTIVECTOR_A));
+
+    const TRIVECTOR: Trivector = TRIVECTOR_A.neg();
+    assert_eq!(dbg!(TRIVECTOR), dbg!(-TRIVECTOR_A));
+  }
 }
@@ -6,6 +6,13 @@ pub trait Normalise {
   fn normalise(self) -> Self;
 }
 
+/// Normalise, for values whose norm might be zero
+pub trait TryNormalise: Sized {
+  /// The normalised value, or [`EgaError::ZeroNorm`] if the norm is zero, or
+  /// too small to normalise by
+  fn try_normalise(self) -> Result<Self, EgaError>;
+}
+
 impl Normalise for Scalar {
   #[inline]
   fn normalise(self) -> Self {
@@ -13,6 +20,13 @@ impl Normalise for Scalar {
   }
 }
 
+impl TryNormalise for Scalar {
+  #[inline]
+  fn try_normalise(self) -> Result<Self, EgaError> {
+    simple_try_normalise(self)
+  }
+}
+
 impl Normalise for Vector {
   #[inline]
   fn normalise(self) -> Self {
@@ -20,6 +34,13 @@ impl Normalise for Vector {
   }
 }
 
+impl TryNormalise for Vector {
+  #[inline]
+  fn try_normalise(self) -> Result<Self, EgaError> {
+    simple_try_normalise(self)
+  }
+}
+
 impl Normalise for Trivector {
   #[inline]
   fn normalise(self) -> Self {
@@ -27,6 +48,61 @@ impl Normalise for Trivector {
   }
 }
 
+impl TryNormalise for Trivector {
+  #[inline]
+  fn try_normalise(self) -> Result<Self, EgaError> {
+    simple_try_normalise(self)
+  }
+}
+
+impl Normalise for Motor {
+  /// Scale the motor so that `M M̃ = 1`
+  #[inline]
+  fn normalise(self) -> Self {
+    let normaliser = motor_normaliser(self);
+    Motor::from_even(self.to_multivector().mul_multivector(normaliser))
+  }
+}
+
+impl TryNormalise for Motor {
+  #[inline]
+  fn try_normalise(self) -> Result<Self, EgaError> {
+    // the weight of a motor is its scalar & Euclidean bivector parts, & the
+    // normaliser isn't finite when it's zero or tiny
+    let normaliser = motor_normaliser(self);
+    if !(normaliser.s.is_finite() && normaliser.e0123.is_finite()) {
+      return Err(EgaError::ZeroNorm);
+    }
+    Ok(Motor::from_even(
+      self.to_multivector().mul_multivector(normaliser),
+    ))
+  }
+}
+
+/// The inverse square root of `M M̃`
+#[inline]
+fn motor_normaliser(motor: Motor) -> Multivector {
+  // `M M̃ = a + bI`, which has the inverse square root `1/√a - b/(2a√a) I`,
+  // where `a` is the squared norm of the weight
+  let b = motor
+    .to_multivector()
+    .mul_multivector(motor.reverse().to_multivector())
+    .e0123;
+  let weight = Multivector {
+    s: motor.s,
+    e23: motor.e23,
+    e31: motor.e31,
+    e12: motor.e12,
+    ..Multivector::ZERO
+  };
+  let (norm, a) = (weight.norm().s, weight.norm_squared().s);
+  Multivector {
+    s: 1. / norm,
+    e0123: -b / (2. * a * norm),
+    ..Multivector::ZERO
+  }
+}
+
 // not valid in the general case
 #[inline]
 fn simple_normalise<
@@ -37,11 +113,27 @@ fn simple_normalise<
   value * (1.0 / value.norm().s)
 }
 
+// a zero norm, or one tiny enough to overflow its reciprocal, can't be
+// normalised by
+#[inline]
+fn simple_try_normalise<
+  T: Copy + Norm + Mul<f32, Output = T> + Add<T, Output = T>,
+>(
+  value: T,
+) -> Result<T, EgaError> {
+  let reciprocal = 1.0 / value.norm().s;
+  if !reciprocal.is_finite() {
+    return Err(EgaError::ZeroNorm);
+  }
+  Ok(value * reciprocal)
+}
+
 #[rustfmt::skip]
 #[cfg(any(test, doctest))]
 mod tests {
   use super::*;
   use crate::test_values::*;
+  use ::approx::assert_relative_eq;
 
   #[test]
   fn normalise_scalar() {
@@ -54,10 +146,10 @@ mod tests {
   fn normalise_vector() {
     let result = VECTOR_A.normalise();
     let expected = Vector {
-      e0: 0.53687042,
-      e1: 0.5582030416,
-      e2: 0.5795356631,
-      e3: 0.593757391,
+      e0: 0.5368704,
+      e1: 0.55820304,
+      e2: 0.57953566,
+      e3: 0.5937574,
     };
     assert_eq!(dbg!(result), dbg!(expected));
   }
@@ -66,11 +158,64 @@ mod tests {
   fn normalise_trivector() {
     let result = TRIVECTOR_A.normalise();
     let expected = Trivector {
-      e021: 1.0946372747,
-      e013: 1.0630915165,
-      e032: 1.0441640615,
+      e021: 1.0946373,
+      e013: 1.0630915,
+      e032: 1.0441641,
       e123: 1.,
     };
     assert_eq!(dbg!(result), dbg!(expected));
   }
+
+  #[test]
+  fn try_normalise() {
+    assert_eq!(dbg!(SCALAR_A.try_normalise()), Ok(SCALAR_A.normalise()));
+    assert_eq!(dbg!(VECTOR_A.try_normalise()), Ok(VECTOR_A.normalise()));
+    assert_eq!(dbg!(TRIVECTOR_A.try_normalise()), Ok(TRIVECTOR_A.normalise()));
+  }
+
+  #[test]
+  fn try_normalise_zero() {
+    assert_eq!(dbg!(Scalar::ZERO.try_normalise()), Err(EgaError::ZeroNorm));
+    assert_eq!(dbg!(Vector::ZERO.try_normalise()), Err(EgaError::ZeroNorm));
+    assert_eq!(dbg!(Trivector::ZERO.try_normalise()), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn try_normalise_tiny() {
+    // nonzero, but the reciprocals of the norms overflow
+    assert_eq!(dbg!(Scalar { s: 1e-39 }.try_normalise()), Err(EgaError::ZeroNorm));
+    let plane = Vector { e0: 1., e1: 1e-39, e2: 0., e3: 0. };
+    assert_eq!(dbg!(plane.try_normalise()), Err(EgaError::ZeroNorm));
+    let motor = Motor { s: 1e-20, e01: 1., ..Motor::ZERO };
+    assert_eq!(dbg!(motor.try_normalise()), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn normalise_motor() {
+    let motor = Motor::from_even(
+      (VECTOR_A * VECTOR_B) * (VECTOR_C * Vector { e0: 1., e1: 2., e2: 0., e3: 0. }),
+    );
+    let result = motor.normalise();
+    let product = Motor::from_even(result.to_multivector() * result.reverse().to_multivector());
+    assert_relative_eq!(product, Motor::identity(), epsilon = 1e-5);
+    assert_relative_eq!(result.normalise(), result, max_relative = 1e-5);
+  }
+
+  #[test]
+  fn try_normalise_ideal_motor() {
+    // a motor with no weight, i.e. only a translation part
+    let motor = Motor { e01: 1., e0123: 2., ..Motor::ZERO };
+    assert_eq!(dbg!(motor.try_normalise()), Err(EgaError::ZeroNorm));
+    assert_eq!(dbg!(Motor::ZERO.try_normalise()), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn try_normalise_ideal() {
+    // the plane at infinity has no Euclidean norm
+    let plane = Vector { e0: 1., e1: 0., e2: 0., e3: 0. };
+    assert_eq!(dbg!(plane.try_normalise()), Err(EgaError::ZeroNorm));
+
+    let point = Trivector { e123: 0., e032: 1., e013: 2., e021: 3. };
+    assert_eq!(dbg!(point.try_normalise()), Err(EgaError::ZeroNorm));
+  }
 }
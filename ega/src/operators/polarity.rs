@@ -0,0 +1,169 @@
+use crate::*;
+
+/// The polarity
+///
+/// The geometric product with the Euclidean pseudoscalar `e123`, from the
+/// right
+pub trait Polarity {
+  type Output;
+
+  /// The polarity
+  fn polarity(self) -> Self::Output;
+}
+
+impl Polarity for Multivector {
+  type Output = Multivector;
+
+  #[inline]
+  fn polarity(self) -> Multivector {
+    Multivector {
+      s: -self.e123,
+      e0: -self.e0123,
+      e1: -self.e23,
+      e2: -self.e31,
+      e3: -self.e12,
+      e23: self.e1,
+      e31: self.e2,
+      e12: self.e3,
+      e01: self.e032,
+      e02: self.e013,
+      e03: self.e021,
+      e0123: self.e0,
+      e123: self.s,
+      e032: -self.e01,
+      e013: -self.e02,
+      e021: -self.e03,
+    }
+  }
+}
+
+impl Polarity for Empty {
+  type Output = Empty;
+
+  #[inline]
+  fn polarity(self) -> Empty {
+    Empty
+  }
+}
+
+impl Polarity for Scalar {
+  type Output = Trivector;
+
+  #[inline]
+  fn polarity(self) -> Trivector {
+    Trivector {
+      e123: self.s,
+      ..zero()
+    }
+  }
+}
+
+impl Polarity for Vector {
+  type Output = Multivector;
+
+  #[inline]
+  fn polarity(self) -> Multivector {
+    Multivector {
+      e23: self.e1,
+      e31: self.e2,
+      e12: self.e3,
+      e0123: self.e0,
+      ..zero()
+    }
+  }
+}
+
+impl Polarity for Bivector {
+  type Output = Multivector;
+
+  #[inline]
+  fn polarity(self) -> Multivector {
+    Multivector {
+      e1: -self.e23,
+      e2: -self.e31,
+      e3: -self.e12,
+      e032: -self.e01,
+      e013: -self.e02,
+      e021: -self.e03,
+      ..zero()
+    }
+  }
+}
+
+impl Polarity for Trivector {
+  type Output = Multivector;
+
+  #[inline]
+  fn polarity(self) -> Multivector {
+    Multivector {
+      s: -self.e123,
+      e01: self.e032,
+      e02: self.e013,
+      e03: self.e021,
+      ..zero()
+    }
+  }
+}
+
+impl Polarity for Pseudoscalar {
+  type Output = Vector;
+
+  #[inline]
+  fn polarity(self) -> Vector {
+    Vector {
+      e0: -self.e0123,
+      ..zero()
+    }
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  const E123: Trivector = Trivector { e123: 1., e032: 0., e013: 0., e021: 0. };
+
+  #[test]
+  fn polarity_multivector() {
+    let result = MULTIVECTOR_A.polarity();
+    let expected = MULTIVECTOR_A.geometric_product(E123);
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn polarity_scalar() {
+    let result = SCALAR_A.polarity();
+    let expected = SCALAR_A.geometric_product(E123);
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn polarity_vector() {
+    let result = VECTOR_A.polarity();
+    let expected = VECTOR_A.geometric_product(E123);
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn polarity_bivector() {
+    let result = BIVECTOR_A.polarity();
+    let expected = BIVECTOR_A.geometric_product(E123);
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn polarity_trivector() {
+    let result = TRIVECTOR_A.polarity();
+    let expected = TRIVECTOR_A.geometric_product(E123);
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn polarity_pseudoscalar() {
+    let result = PSEUDOSCALAR_A.polarity();
+    let expected = PSEUDOSCALAR_A.geometric_product(E123);
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+}
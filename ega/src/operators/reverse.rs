@@ -6,9 +6,10 @@ pub trait Reverse {
   fn reverse(self) -> Self;
 }
 
-impl Reverse for Multivector {
+impl Multivector {
+  /// The reverse, usable in `const` contexts
   #[inline]
-  fn reverse(self) -> Multivector {
+  pub const fn reverse(self) -> Multivector {
     Multivector {
       e01: -self.e01,
       e02: -self.e02,
@@ -25,16 +26,47 @@ impl Reverse for Multivector {
   }
 }
 
-impl Reverse for Empty {
+impl Reverse for Multivector {
   #[inline]
   fn reverse(self) -> Self {
+    Multivector::reverse(self)
+  }
+}
+
+impl Empty {
+  /// The reverse, usable in `const` contexts
+  #[inline]
+  pub const fn reverse(self) -> Empty {
     Empty
   }
 }
 
+impl Reverse for Empty {
+  #[inline]
+  fn reverse(self) -> Self {
+    Empty::reverse(self)
+  }
+}
+
+impl Scalar {
+  /// The reverse, usable in `const` contexts
+  #[inline]
+  pub const fn reverse(self) -> Scalar {
+    self
+  }
+}
+
 impl Reverse for Scalar {
   #[inline]
   fn reverse(self) -> Self {
+    Scalar::reverse(self)
+  }
+}
+
+impl Vector {
+  /// The reverse, usable in `const` contexts
+  #[inline]
+  pub const fn reverse(self) -> Vector {
     self
   }
 }
@@ -42,28 +74,59 @@ impl Reverse for Scalar {
 impl Reverse for Vector {
   #[inline]
   fn reverse(self) -> Self {
-    self
+    Vector::reverse(self)
+  }
+}
+
+impl Bivector {
+  /// The reverse, usable in `const` contexts
+  #[inline]
+  pub const fn reverse(self) -> Bivector {
+    self.neg()
   }
 }
 
 impl Reverse for Bivector {
   #[inline]
   fn reverse(self) -> Self {
-    -self
+    Bivector::reverse(self)
+  }
+}
+
+impl Trivector {
+  /// The reverse, usable in `const` contexts
+  #[inline]
+  pub const fn reverse(self) -> Trivector {
+    self.neg()
   }
 }
 
 impl Reverse for Trivector {
   #[inline]
   fn reverse(self) -> Self {
-    -self
+    Trivector::reverse(self)
+  }
+}
+
+impl Pseudoscalar {
+  /// The reverse, usable in `const` contexts
+  #[inline]
+  pub const fn reverse(self) -> Pseudoscalar {
+    self
   }
 }
 
 impl Reverse for Pseudoscalar {
   #[inline]
   fn reverse(self) -> Self {
-    self
+    Pseudoscalar::reverse(self)
+  }
+}
+
+impl Reverse for Motor {
+  #[inline]
+  fn reverse(self) -> Self {
+    Motor::reverse(self)
   }
 }
 
@@ -122,4 +185,13 @@ mod tests {
     let expected = Pseudoscalar { e0123: 397. };
     assert_eq!(dbg!(result), dbg!(expected));
   }
+
+  #[test]
+  fn const_reverse() {
+    const MULTIVECTOR: Multivector = MULTIVECTOR_A.reverse();
+    assert_eq!(dbg!(MULTIVECTOR), dbg!(Reverse::reverse(MULTIVECTOR_A)));
+
+    const BIVECTOR: Bivector = BIVECTOR_A.reverse();
+    assert_eq!(dbg!(BIVECTOR), dbg!(Reverse::reverse(BIVECTOR_A)));
+  }
 }
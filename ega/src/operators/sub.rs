@@ -1,7 +1,7 @@
 use super::{return_lhs, return_neg_rhs};
 use crate::*;
 
-pub use core::ops::Sub;
+pub use core::ops::{Sub, SubAssign};
 
 macro_rules! impl_sub {
   ($sub_fn:ident: $lhs:ty, $rhs:ty => $output:ty) => {
@@ -13,6 +13,8 @@ macro_rules! impl_sub {
         $sub_fn(self, rhs)
       }
     }
+
+    impl_ref_ops! { Sub::sub: $lhs, $rhs }
   };
 }
 
@@ -72,6 +74,8 @@ impl_sub! { return_neg_rhs: Empty, Bivector => Bivector }
 impl_sub! { return_neg_rhs: Empty, Trivector => Trivector }
 impl_sub! { return_neg_rhs: Empty, Pseudoscalar => Pseudoscalar }
 
+impl_assign_ops! { Sub::sub, SubAssign::sub_assign: Multivector, Scalar, Vector, Bivector, Trivector, Pseudoscalar, Empty }
+
 // Multivector
 
 #[inline]
@@ -551,3 +555,40 @@ fn pseudoscalar_sub_pseudoscalar(
 
   lhs
 }
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  #[test]
+  fn sub() {
+    assert_eq!(dbg!(MULTIVECTOR_A - VECTOR_A), dbg!(MULTIVECTOR_A + -VECTOR_A));
+    assert_eq!(dbg!(SCALAR_A - TRIVECTOR_A), dbg!(SCALAR_A + -TRIVECTOR_A));
+    assert_eq!(dbg!(VECTOR_A - VECTOR_B), dbg!(VECTOR_A + -VECTOR_B));
+    assert_eq!(dbg!(VECTOR_A - VECTOR_A), Vector::zero());
+    assert_eq!(dbg!(MULTIVECTOR_A - Empty), dbg!(MULTIVECTOR_A));
+  }
+
+  #[test]
+  #[allow(clippy::op_ref)]
+  fn references() {
+    let expected = MULTIVECTOR_A - VECTOR_A;
+    assert_eq!(dbg!(&MULTIVECTOR_A - VECTOR_A), dbg!(expected));
+    assert_eq!(dbg!(MULTIVECTOR_A - &VECTOR_A), dbg!(expected));
+    assert_eq!(dbg!(&MULTIVECTOR_A - &VECTOR_A), dbg!(expected));
+  }
+
+  #[test]
+  fn sub_assign() {
+    let mut result = MULTIVECTOR_A;
+    result -= VECTOR_A;
+    result -= &BIVECTOR_A;
+    assert_eq!(dbg!(result), dbg!(MULTIVECTOR_A - VECTOR_A - BIVECTOR_A));
+
+    let mut result = TRIVECTOR_A;
+    result -= TRIVECTOR_B;
+    assert_eq!(dbg!(result), dbg!(TRIVECTOR_A - TRIVECTOR_B));
+  }
+}
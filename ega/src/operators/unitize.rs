@@ -0,0 +1,95 @@
+use crate::*;
+
+/// Unitize
+///
+/// Scale the value so that its [`WeightNorm`] is one
+pub trait Unitize {
+  /// The unitized value
+  fn unitize(self) -> Self;
+}
+
+impl Unitize for Multivector {
+  #[inline]
+  fn unitize(self) -> Self {
+    weight_unitize(self)
+  }
+}
+
+impl Unitize for Scalar {
+  #[inline]
+  fn unitize(self) -> Self {
+    weight_unitize(self)
+  }
+}
+
+impl Unitize for Vector {
+  #[inline]
+  fn unitize(self) -> Self {
+    weight_unitize(self)
+  }
+}
+
+impl Unitize for Bivector {
+  #[inline]
+  fn unitize(self) -> Self {
+    weight_unitize(self)
+  }
+}
+
+impl Unitize for Trivector {
+  #[inline]
+  fn unitize(self) -> Self {
+    weight_unitize(self)
+  }
+}
+
+// `Pseudoscalar` & `Empty` have no weight, so can't be unitized
+#[inline]
+fn weight_unitize<T: Copy + WeightNorm + Mul<f32, Output = T>>(value: T) -> T {
+  value * (1.0 / value.weight_norm().s)
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+  use ::approx::assert_relative_eq;
+
+  #[test]
+  fn unitize_multivector() {
+    let result = MULTIVECTOR_A.unitize().weight_norm();
+    let expected = Scalar { s: 1. };
+    assert_relative_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn unitize_vector() {
+    let result = VECTOR_A.unitize();
+    assert_relative_eq!(dbg!(result.weight_norm()), dbg!(Scalar::UNIT));
+    assert_relative_eq!(
+      dbg!(result.geometric_norm()),
+      dbg!(VECTOR_A.geometric_norm()),
+    );
+  }
+
+  #[test]
+  fn unitize_bivector() {
+    let result = BIVECTOR_A.unitize();
+    assert_relative_eq!(dbg!(result.weight_norm()), dbg!(Scalar::UNIT));
+    assert_relative_eq!(
+      dbg!(result.geometric_norm()),
+      dbg!(BIVECTOR_A.geometric_norm()),
+    );
+  }
+
+  #[test]
+  fn unitize_trivector() {
+    let result = Trivector { e123: 2., e032: 4., e013: 6., e021: 12. }.unitize();
+    let expected = Trivector { e123: 1., e032: 2., e013: 3., e021: 6. };
+    assert_eq!(dbg!(result), dbg!(expected));
+
+    let result = TRIVECTOR_C.unitize();
+    assert_relative_eq!(dbg!(result.e123), -1.);
+  }
+}
@@ -69,3 +69,6 @@ impl_approx_eq_self! {
 impl_approx_eq_self! {
   Pseudoscalar: e0123
 }
+impl_approx_eq_self! {
+  Motor: s, e23, e31, e12, e01, e02, e03, e0123
+}
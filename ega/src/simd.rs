@@ -0,0 +1,424 @@
+//! An explicit SIMD implementation of the products of [`Multivector`]s
+//!
+//! The components of a [`Multivector`] are laid out as four groups of four
+//! (see `MultivectorArray`), each of which is loaded into a 128-bit register.
+//! Each group of a product is then a sum of lane-wise products of whole
+//! registers, shuffled such that every lane meets the right pair of
+//! components, with the signs applied by flipping the sign bits. Each lane
+//! adds its terms in the same order as the scalar implementation, taking
+//! them from the products of several pairs of registers where the lanes'
+//! terms come from different groups, & adding `-0.` once a lane has run out
+//! of terms, which leaves every value unchanged. So the results are
+//! identical to the scalar implementation's, bit for bit.
+//!
+//! On `x86_64` the products use SSE2, which is part of the baseline target;
+//! on other targets they fall back to the scalar implementation. Enabling the
+//! `simd` feature makes the [`GeometricProduct`], [`Meet`] & [`Join`] of two
+//! [`Multivector`]s use these functions. The `products` benchmark compares
+//! the two paths.
+
+use crate::*;
+
+/// The geometric product of two multivectors
+#[inline]
+pub fn geometric_product(lhs: Multivector, rhs: Multivector) -> Multivector {
+  #[cfg(target_arch = "x86_64")]
+  return sse::geometric_product(lhs, rhs);
+  #[cfg(not(target_arch = "x86_64"))]
+  return crate::operators::multivector_mul_multivector(lhs, rhs);
+}
+
+/// The outer product, [`Meet`], of two multivectors
+#[inline]
+pub fn meet(lhs: Multivector, rhs: Multivector) -> Multivector {
+  #[cfg(target_arch = "x86_64")]
+  return sse::meet(lhs, rhs);
+  #[cfg(not(target_arch = "x86_64"))]
+  return crate::operators::multivector_meet_multivector(lhs, rhs);
+}
+
+/// The regressive product, [`Join`], of two multivectors
+#[inline]
+pub fn join(lhs: Multivector, rhs: Multivector) -> Multivector {
+  #[cfg(target_arch = "x86_64")]
+  return sse::join(lhs, rhs);
+  #[cfg(not(target_arch = "x86_64"))]
+  return crate::operators::multivector_join_multivector(lhs, rhs);
+}
+
+/// The sandwich product `M X M̃`
+#[inline]
+pub fn sandwich(motor: Multivector, value: Multivector) -> Multivector {
+  geometric_product(geometric_product(motor, value), motor.reverse())
+}
+
+#[cfg(target_arch = "x86_64")]
+mod sse {
+  use crate::*;
+  use core::arch::x86_64::*;
+
+  /// The `_mm_shuffle_ps` immediate which takes lane `i` of the result from
+  /// lane `lanes[i]` of the input
+  const fn shuffle(lanes: [i32; 4]) -> i32 {
+    lanes[0] | lanes[1] << 2 | lanes[2] << 4 | lanes[3] << 6
+  }
+
+  /// The lane-wise product of the shuffled `a` & `b`, multiplied by `signs`,
+  /// where a sign of `0.` marks a lane without a term, which is zeroed
+  #[inline(always)]
+  unsafe fn product<const A: i32, const B: i32>(
+    a: __m128,
+    b: __m128,
+    signs: [f32; 4],
+  ) -> __m128 {
+    let product =
+      _mm_mul_ps(_mm_shuffle_ps::<A>(a, a), _mm_shuffle_ps::<B>(b, b));
+    // `signs` is constant, so these fold away when they're not needed
+    let signs = _mm_loadu_ps(signs.as_ptr());
+    let negative = _mm_and_ps(signs, _mm_set1_ps(-0.));
+    let present = _mm_cmpneq_ps(signs, _mm_setzero_ps());
+    _mm_and_ps(_mm_xor_ps(product, negative), present)
+  }
+
+  /// The next term of each lane, from products whose lanes with terms don't
+  /// overlap, & `-0.` in the lanes which have none
+  #[inline(always)]
+  unsafe fn terms<const N: usize>(
+    products: [__m128; N],
+    signs: [[f32; 4]; N],
+  ) -> __m128 {
+    let mut absent = [-0.; 4];
+    for signs in signs {
+      for (absent, sign) in absent.iter_mut().zip(signs) {
+        if sign != 0. {
+          *absent = 0.;
+        }
+      }
+    }
+    let absent = _mm_loadu_ps(absent.as_ptr());
+    products
+      .into_iter()
+      .fold(absent, |sum, p| _mm_or_ps(sum, p))
+  }
+
+  /// Sum the terms of each lane in order, where each step takes the next term
+  /// of every lane from the products `[(lhs group [lanes], rhs group [lanes],
+  /// [signs]), …]`
+  macro_rules! sum {
+    ($a:ident, $b:ident: $first:tt, $($rest:tt,)*) => {{
+      let mut sum = sum!(@terms $a, $b, $first);
+      $(sum = _mm_add_ps(sum, sum!(@terms $a, $b, $rest));)*
+      sum
+    }};
+    (@terms $a:ident, $b:ident, [$($product:tt),+]) => {
+      terms(
+        [$(sum!(@product $a, $b, $product)),+],
+        [$(sum!(@signs $product)),+],
+      )
+    };
+    (
+      @product $a:ident, $b:ident,
+      ($lhs:literal $lhs_lanes:tt, $rhs:literal $rhs_lanes:tt, $signs:tt)
+    ) => {
+      product::<{ shuffle($lhs_lanes) }, { shuffle($rhs_lanes) }>(
+        $a[$lhs], $b[$rhs], $signs,
+      )
+    };
+    (
+      @signs
+      ($lhs:literal $lhs_lanes:tt, $rhs:literal $rhs_lanes:tt, $signs:tt)
+    ) => {
+      $signs
+    };
+  }
+
+  #[inline(always)]
+  unsafe fn load(multivector: Multivector) -> [__m128; 4] {
+    let elements = multivector.to_multivector_array().elements;
+    [0, 4, 8, 12].map(|i| _mm_loadu_ps(elements[i..].as_ptr()))
+  }
+
+  #[inline(always)]
+  unsafe fn store(groups: [__m128; 4]) -> Multivector {
+    let mut elements = [0.; 16];
+    for (i, group) in groups.into_iter().enumerate() {
+      _mm_storeu_ps(elements[4 * i..].as_mut_ptr(), group);
+    }
+    MultivectorArray::from(elements).to_multivector()
+  }
+
+  #[rustfmt::skip]
+  #[inline]
+  pub(super) fn geometric_product(
+    lhs: Multivector,
+    rhs: Multivector,
+  ) -> Multivector {
+    // SAFETY: SSE2 is part of the x86_64 baseline, & every group has 4
+    // elements
+    unsafe {
+      let (a, b) = (load(lhs), load(rhs));
+      store([
+        // e0, e1, e2, e3
+        sum!(a, b:
+          [(0 [0, 1, 2, 3], 1 [0, 0, 0, 0], [ 1.,  1.,  1.,  1.])],
+          [(1 [0, 0, 0, 0], 0 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(2 [0, 1, 2, 3], 0 [1, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (1 [0, 3, 3, 2], 0 [0, 2, 1, 1], [ 0.,  1., -1.,  1.])],
+          [(0 [1, 1, 2, 3], 2 [0, 1, 2, 3], [-1.,  0.,  0.,  0.]), (0 [0, 2, 1, 1], 1 [0, 3, 3, 2], [ 0., -1.,  1., -1.])],
+          [(2 [1, 1, 2, 3], 0 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (1 [0, 2, 1, 1], 0 [0, 3, 3, 2], [ 0., -1.,  1., -1.])],
+          [(0 [2, 1, 2, 3], 2 [1, 1, 2, 3], [-1.,  0.,  0.,  0.]), (0 [0, 3, 3, 2], 1 [0, 2, 1, 1], [ 0.,  1., -1.,  1.])],
+          [(2 [2, 1, 2, 3], 0 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (3 [0, 0, 0, 0], 1 [0, 1, 2, 3], [ 0., -1., -1., -1.])],
+          [(0 [3, 1, 2, 3], 2 [2, 1, 2, 3], [-1.,  0.,  0.,  0.]), (1 [0, 1, 2, 3], 3 [0, 0, 0, 0], [ 0., -1., -1., -1.])],
+          [(3 [3, 1, 2, 3], 1 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(1 [3, 1, 2, 3], 3 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(3 [2, 1, 2, 3], 1 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(1 [2, 1, 2, 3], 3 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(3 [1, 1, 2, 3], 1 [1, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(1 [1, 1, 2, 3], 3 [1, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(2 [3, 1, 2, 3], 3 [0, 1, 2, 3], [-1.,  0.,  0.,  0.])],
+          [(3 [0, 1, 2, 3], 2 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+        ),
+        // s, e23, e31, e12
+        sum!(a, b:
+          [(1 [0, 1, 2, 3], 1 [0, 0, 0, 0], [ 1.,  1.,  1.,  1.])],
+          [(0 [1, 1, 2, 3], 0 [1, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (1 [0, 0, 0, 0], 1 [0, 1, 2, 3], [ 0.,  1.,  1.,  1.])],
+          [(0 [2, 3, 3, 2], 0 [2, 2, 1, 1], [ 1., -1.,  1., -1.])],
+          [(0 [3, 2, 1, 1], 0 [3, 3, 3, 2], [ 1.,  1., -1.,  1.])],
+          [(1 [1, 2, 1, 1], 1 [1, 3, 3, 2], [-1., -1.,  1., -1.])],
+          [(1 [2, 3, 3, 2], 1 [2, 2, 1, 1], [-1.,  1., -1.,  1.])],
+          [(1 [3, 1, 2, 3], 1 [3, 1, 2, 3], [-1.,  0.,  0.,  0.]), (3 [0, 0, 0, 0], 0 [0, 1, 2, 3], [ 0.,  1.,  1.,  1.])],
+          [(3 [0, 1, 2, 3], 3 [0, 1, 2, 3], [-1.,  0.,  0.,  0.]), (0 [0, 1, 2, 3], 3 [0, 0, 0, 0], [ 0.,  1.,  1.,  1.])],
+        ),
+        // e01, e02, e03, e0123
+        sum!(a, b:
+          [(2 [0, 1, 2, 3], 1 [0, 0, 0, 0], [ 1.,  1.,  1.,  1.])],
+          [(1 [0, 0, 0, 0], 2 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(0 [1, 2, 3, 3], 0 [0, 0, 0, 3], [-1., -1., -1.,  0.]), (1 [0, 1, 2, 3], 2 [0, 1, 2, 2], [ 0.,  0.,  0.,  1.])],
+          [(0 [0, 0, 0, 3], 0 [1, 2, 3, 3], [ 1.,  1.,  1.,  0.]), (2 [0, 1, 2, 2], 1 [0, 1, 2, 3], [ 0.,  0.,  0.,  1.])],
+          [(1 [3, 3, 2, 2], 2 [1, 0, 0, 1], [ 1., -1.,  1.,  1.])],
+          [(2 [1, 0, 0, 1], 1 [3, 3, 2, 2], [-1.,  1., -1.,  1.])],
+          [(1 [2, 1, 1, 1], 2 [2, 2, 1, 0], [-1.,  1., -1.,  1.])],
+          [(2 [2, 2, 1, 0], 1 [2, 1, 1, 1], [ 1., -1.,  1.,  1.])],
+          [(3 [3, 3, 2, 3], 0 [2, 1, 1, 3], [-1.,  1., -1., -1.])],
+          [(0 [2, 1, 1, 3], 3 [3, 3, 2, 3], [-1.,  1., -1.,  1.])],
+          [(3 [2, 1, 1, 2], 0 [3, 3, 2, 2], [ 1., -1.,  1., -1.])],
+          [(0 [3, 3, 2, 2], 3 [2, 1, 1, 2], [ 1., -1.,  1.,  1.])],
+          [(3 [0, 0, 0, 3], 3 [1, 2, 3, 3], [-1., -1., -1.,  0.]), (3 [0, 1, 2, 1], 0 [0, 1, 2, 1], [ 0.,  0.,  0., -1.])],
+          [(3 [1, 2, 3, 3], 3 [0, 0, 0, 3], [ 1.,  1.,  1.,  0.]), (0 [0, 1, 2, 1], 3 [0, 1, 2, 1], [ 0.,  0.,  0.,  1.])],
+          [(2 [3, 3, 3, 3], 1 [1, 2, 3, 3], [-1., -1., -1.,  0.]), (3 [0, 1, 2, 0], 0 [0, 1, 2, 0], [ 0.,  0.,  0., -1.])],
+          [(1 [1, 2, 3, 3], 2 [3, 3, 3, 3], [-1., -1., -1.,  0.]), (0 [0, 1, 2, 0], 3 [0, 1, 2, 0], [ 0.,  0.,  0.,  1.])],
+        ),
+        // e123, e032, e013, e021
+        sum!(a, b:
+          [(3 [0, 1, 2, 3], 1 [0, 0, 0, 0], [ 1.,  1.,  1.,  1.])],
+          [(1 [0, 0, 0, 0], 3 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(1 [3, 1, 2, 3], 0 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (2 [0, 1, 0, 0], 0 [0, 3, 3, 2], [ 0., -1.,  1., -1.])],
+          [(0 [3, 1, 2, 3], 1 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (0 [0, 3, 3, 2], 2 [0, 1, 0, 0], [ 0., -1.,  1., -1.])],
+          [(1 [2, 1, 2, 3], 0 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (2 [0, 2, 2, 1], 0 [0, 2, 1, 1], [ 0.,  1., -1.,  1.])],
+          [(0 [2, 1, 2, 3], 1 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (0 [0, 2, 1, 1], 2 [0, 2, 2, 1], [ 0.,  1., -1.,  1.])],
+          [(1 [1, 1, 2, 3], 0 [1, 0, 0, 0], [ 1., -1., -1., -1.])],
+          [(0 [1, 0, 0, 0], 1 [1, 1, 2, 3], [ 1., -1., -1., -1.])],
+          [(3 [0, 3, 3, 2], 1 [0, 2, 1, 1], [ 0.,  1., -1.,  1.])],
+          [(1 [0, 2, 1, 1], 3 [0, 3, 3, 2], [ 0., -1.,  1., -1.])],
+          [(3 [0, 2, 1, 1], 1 [0, 3, 3, 2], [ 0., -1.,  1., -1.])],
+          [(1 [0, 3, 3, 2], 3 [0, 2, 1, 1], [ 0.,  1., -1.,  1.])],
+          [(3 [0, 0, 0, 0], 2 [0, 0, 1, 2], [ 0.,  1.,  1.,  1.])],
+          [(2 [0, 0, 1, 2], 3 [0, 0, 0, 0], [ 0., -1., -1., -1.])],
+          [(2 [0, 3, 3, 3], 0 [0, 1, 2, 3], [ 0., -1., -1., -1.])],
+          [(0 [0, 1, 2, 3], 2 [0, 3, 3, 3], [ 0.,  1.,  1.,  1.])],
+        ),
+      ])
+    }
+  }
+
+  #[rustfmt::skip]
+  #[inline]
+  pub(super) fn meet(lhs: Multivector, rhs: Multivector) -> Multivector {
+    // SAFETY: SSE2 is part of the x86_64 baseline, & every group has 4
+    // elements
+    unsafe {
+      let (a, b) = (load(lhs), load(rhs));
+      store([
+        // e0, e1, e2, e3
+        sum!(a, b:
+          [(1 [0, 0, 0, 0], 0 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(0 [0, 1, 2, 3], 1 [0, 0, 0, 0], [ 1.,  1.,  1.,  1.])],
+        ),
+        // s, e23, e31, e12
+        sum!(a, b:
+          [(1 [0, 0, 0, 0], 1 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(1 [0, 1, 2, 3], 1 [0, 0, 0, 0], [ 0.,  1.,  1.,  1.])],
+          [(0 [0, 2, 1, 1], 0 [0, 3, 3, 2], [ 0.,  1., -1.,  1.])],
+          [(0 [0, 3, 3, 2], 0 [0, 2, 1, 1], [ 0., -1.,  1., -1.])],
+        ),
+        // e01, e02, e03, e0123
+        sum!(a, b:
+          [(1 [0, 0, 0, 3], 2 [0, 1, 2, 3], [ 1.,  1.,  1.,  0.]), (2 [0, 1, 2, 3], 1 [0, 1, 2, 0], [ 0.,  0.,  0.,  1.])],
+          [(0 [0, 0, 0, 3], 0 [1, 2, 3, 3], [ 1.,  1.,  1.,  0.]), (1 [0, 1, 2, 0], 2 [0, 1, 2, 3], [ 0.,  0.,  0.,  1.])],
+          [(2 [0, 1, 2, 3], 1 [0, 0, 0, 3], [ 1.,  1.,  1.,  0.]), (0 [0, 1, 2, 0], 3 [0, 1, 2, 0], [ 0.,  0.,  0.,  1.])],
+          [(0 [1, 2, 3, 3], 0 [0, 0, 0, 3], [-1., -1., -1.,  0.]), (3 [0, 1, 2, 0], 0 [0, 1, 2, 0], [ 0.,  0.,  0., -1.])],
+          [(0 [0, 1, 2, 1], 3 [0, 1, 2, 1], [ 0.,  0.,  0.,  1.])],
+          [(3 [0, 1, 2, 1], 0 [0, 1, 2, 1], [ 0.,  0.,  0., -1.])],
+          [(0 [0, 1, 2, 2], 3 [0, 1, 2, 2], [ 0.,  0.,  0.,  1.])],
+          [(3 [0, 1, 2, 2], 0 [0, 1, 2, 2], [ 0.,  0.,  0., -1.])],
+          [(0 [0, 1, 2, 3], 3 [0, 1, 2, 3], [ 0.,  0.,  0.,  1.])],
+          [(3 [0, 1, 2, 3], 0 [0, 1, 2, 3], [ 0.,  0.,  0., -1.])],
+          [(1 [0, 1, 2, 1], 2 [0, 1, 2, 0], [ 0.,  0.,  0.,  1.])],
+          [(2 [0, 1, 2, 0], 1 [0, 1, 2, 1], [ 0.,  0.,  0.,  1.])],
+          [(1 [0, 1, 2, 2], 2 [0, 1, 2, 1], [ 0.,  0.,  0.,  1.])],
+          [(2 [0, 1, 2, 1], 1 [0, 1, 2, 2], [ 0.,  0.,  0.,  1.])],
+          [(1 [0, 1, 2, 3], 2 [0, 1, 2, 2], [ 0.,  0.,  0.,  1.])],
+          [(2 [0, 1, 2, 2], 1 [0, 1, 2, 3], [ 0.,  0.,  0.,  1.])],
+        ),
+        // e123, e032, e013, e021
+        sum!(a, b:
+          [(1 [0, 0, 0, 0], 3 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(3 [0, 1, 2, 3], 1 [0, 0, 0, 0], [ 1.,  1.,  1.,  1.])],
+          [(0 [1, 0, 0, 0], 1 [1, 1, 2, 3], [ 1., -1., -1., -1.])],
+          [(1 [1, 1, 2, 3], 0 [1, 0, 0, 0], [ 1., -1., -1., -1.])],
+          [(0 [2, 1, 2, 3], 1 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (0 [0, 2, 1, 1], 2 [0, 2, 2, 1], [ 0.,  1., -1.,  1.])],
+          [(1 [2, 1, 2, 3], 0 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (2 [0, 2, 2, 1], 0 [0, 2, 1, 1], [ 0.,  1., -1.,  1.])],
+          [(0 [3, 1, 2, 3], 1 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (0 [0, 3, 3, 2], 2 [0, 1, 0, 0], [ 0., -1.,  1., -1.])],
+          [(1 [3, 1, 2, 3], 0 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (2 [0, 1, 0, 0], 0 [0, 3, 3, 2], [ 0., -1.,  1., -1.])],
+        ),
+      ])
+    }
+  }
+
+  #[rustfmt::skip]
+  #[inline]
+  pub(super) fn join(lhs: Multivector, rhs: Multivector) -> Multivector {
+    // SAFETY: SSE2 is part of the x86_64 baseline, & every group has 4
+    // elements
+    unsafe {
+      let (a, b) = (load(lhs), load(rhs));
+      store([
+        // e0, e1, e2, e3
+        sum!(a, b:
+          [(0 [0, 1, 2, 3], 2 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (2 [0, 3, 3, 3], 0 [0, 1, 2, 3], [ 0.,  1.,  1.,  1.])],
+          [(2 [3, 1, 2, 3], 0 [0, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (0 [0, 1, 2, 3], 2 [0, 3, 3, 3], [ 0.,  1.,  1.,  1.])],
+          [(3 [1, 1, 0, 0], 2 [0, 1, 1, 2], [-1.,  0.,  1.,  1.]), (3 [0, 3, 2, 3], 1 [0, 2, 2, 3], [ 0.,  1.,  0.,  0.])],
+          [(2 [0, 1, 1, 2], 3 [1, 1, 0, 0], [-1.,  0.,  1.,  1.]), (1 [0, 2, 2, 3], 3 [0, 3, 2, 3], [ 0.,  1.,  0.,  0.])],
+          [(3 [2, 0, 2, 3], 2 [1, 0, 2, 3], [-1.,  1.,  0.,  0.]), (3 [0, 1, 1, 1], 1 [0, 1, 3, 2], [ 0.,  0.,  1., -1.])],
+          [(2 [1, 0, 2, 3], 3 [2, 0, 2, 3], [-1.,  1.,  0.,  0.]), (1 [0, 1, 3, 2], 3 [0, 1, 1, 1], [ 0.,  0.,  1., -1.])],
+          [(3 [3, 1, 2, 3], 2 [2, 1, 2, 3], [-1.,  0.,  0.,  0.]), (3 [0, 2, 3, 2], 1 [0, 3, 1, 1], [ 0., -1., -1.,  1.])],
+          [(2 [2, 1, 2, 3], 3 [3, 1, 2, 3], [-1.,  0.,  0.,  0.]), (1 [0, 3, 1, 1], 3 [0, 2, 3, 2], [ 0., -1., -1.,  1.])],
+        ),
+        // s, e23, e31, e12
+        sum!(a, b:
+          [(2 [3, 3, 3, 3], 1 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(1 [0, 1, 2, 3], 2 [3, 3, 3, 3], [ 1.,  1.,  1.,  1.])],
+          [(3 [0, 1, 2, 3], 0 [0, 1, 2, 3], [ 1.,  0.,  0.,  0.]), (3 [0, 0, 0, 0], 3 [0, 1, 2, 3], [ 0., -1., -1., -1.])],
+          [(0 [0, 1, 2, 3], 3 [0, 1, 2, 3], [-1.,  0.,  0.,  0.]), (3 [0, 1, 2, 3], 3 [0, 0, 0, 0], [ 0.,  1.,  1.,  1.])],
+          [(3 [1, 1, 2, 3], 0 [1, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(0 [1, 1, 2, 3], 3 [1, 1, 2, 3], [-1.,  0.,  0.,  0.])],
+          [(3 [2, 1, 2, 3], 0 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(0 [2, 1, 2, 3], 3 [2, 1, 2, 3], [-1.,  0.,  0.,  0.])],
+          [(3 [3, 1, 2, 3], 0 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(0 [3, 1, 2, 3], 3 [3, 1, 2, 3], [-1.,  0.,  0.,  0.])],
+          [(2 [0, 1, 2, 3], 1 [1, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(1 [1, 1, 2, 3], 2 [0, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(2 [1, 1, 2, 3], 1 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(1 [2, 1, 2, 3], 2 [1, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(2 [2, 1, 2, 3], 1 [3, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+          [(1 [3, 1, 2, 3], 2 [2, 1, 2, 3], [ 1.,  0.,  0.,  0.])],
+        ),
+        // e01, e02, e03, e0123
+        sum!(a, b:
+          [(2 [3, 3, 3, 3], 2 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(2 [0, 1, 2, 3], 2 [3, 3, 3, 3], [ 1.,  1.,  1.,  0.])],
+          [(3 [2, 1, 1, 3], 3 [3, 3, 2, 3], [-1.,  1., -1.,  0.])],
+          [(3 [3, 3, 2, 3], 3 [2, 1, 1, 3], [ 1., -1.,  1.,  0.])],
+        ),
+        // e123, e032, e013, e021
+        sum!(a, b:
+          [(2 [3, 3, 3, 3], 3 [0, 1, 2, 3], [ 1.,  1.,  1.,  1.])],
+          [(3 [0, 1, 2, 3], 2 [3, 3, 3, 3], [ 1.,  1.,  1.,  1.])],
+        ),
+      ])
+    }
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::operators::{
+    multivector_join_multivector, multivector_meet_multivector,
+    multivector_mul_multivector,
+  };
+  use crate::test_values::*;
+
+  /// A deterministic spread of values, including negative zeros, tiny &
+  /// large magnitudes
+  fn values() -> Vec<Multivector> {
+    let mut state = 0x2545_f491_u32;
+    let mut next = || {
+      state ^= state << 13;
+      state ^= state >> 17;
+      state ^= state << 5;
+      let mantissa = (state >> 8) as f32 / (1 << 24) as f32 - 0.5;
+      let exponent = (state % 16) as i32 - 8;
+      match state % 11 {
+        0 => 0.,
+        1 => -0.,
+        _ => mantissa * 2f32.powi(exponent),
+      }
+    };
+    let mut values = vec![
+      MULTIVECTOR_A, MULTIVECTOR_B, MULTIVECTOR_C, MULTIVECTOR_D, zero(),
+    ];
+    for _ in 0..64 {
+      let elements = core::array::from_fn(|_| next());
+      values.push(MultivectorArray::from(elements).to_multivector());
+    }
+    values
+  }
+
+  /// Check that the components are identical, including the signs of zeros
+  fn assert_bits(result: Multivector, expected: Multivector) {
+    let bits = |x: Multivector| x.to_multivector_array().elements.map(f32::to_bits);
+    assert_eq!(bits(result), bits(expected), "{result:?} != {expected:?}");
+  }
+
+  #[test]
+  fn geometric_product_matches_scalar() {
+    for a in values() {
+      for b in values() {
+        assert_bits(geometric_product(a, b), multivector_mul_multivector(a, b));
+        assert_bits(geometric_product(a, b), a * b);
+      }
+    }
+  }
+
+  #[test]
+  fn meet_matches_scalar() {
+    for a in values() {
+      for b in values() {
+        assert_bits(meet(a, b), multivector_meet_multivector(a, b));
+      }
+    }
+  }
+
+  #[test]
+  fn join_matches_scalar() {
+    for a in values() {
+      for b in values() {
+        assert_bits(join(a, b), multivector_join_multivector(a, b));
+      }
+    }
+  }
+
+  #[test]
+  fn sandwich_matches_scalar() {
+    for m in values() {
+      for x in values() {
+        let expected = multivector_mul_multivector(
+          multivector_mul_multivector(m, x),
+          m.reverse(),
+        );
+        assert_bits(sandwich(m, x), expected);
+      }
+    }
+  }
+}
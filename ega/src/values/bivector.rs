@@ -1,4 +1,5 @@
 use super::*;
+use crate::*;
 use ::core::fmt::{Debug, Formatter};
 
 #[derive(Copy, Clone, Default, PartialEq)]
@@ -12,6 +13,57 @@ pub struct Bivector {
   pub e03: f32,
 }
 
+impl Bivector {
+  /// Whether the bivector is simple, i.e. represents a single line
+  ///
+  /// Tests the Plücker condition `B ∧ B = 0`, treating the [`Pseudoscalar`]
+  /// as zero when its magnitude is no greater than `epsilon` times `|B|²`,
+  /// the sum of the squares of the components, so that the result doesn't
+  /// depend on the bivector's scale.
+  #[inline]
+  pub fn is_simple(self, epsilon: f32) -> bool {
+    let e0123 = self.meet(self).e0123;
+    let magnitude = self.norm_squared().s + self.ideal_norm_squared().e0123;
+    e0123 * e0123 <= epsilon * epsilon * magnitude * magnitude
+  }
+
+  /// Decompose the bivector into a Euclidean line & a commuting ideal line
+  ///
+  /// Returns `(line, ideal_line)` such that `line + ideal_line == self`, both
+  /// parts are simple, & they commute. The ideal line is parallel to the
+  /// Euclidean line's direction. When the bivector has no Euclidean part the
+  /// ideal line is zero & `line` is the (ideal) bivector itself.
+  #[inline]
+  pub fn decompose(self) -> (Bivector, Bivector) {
+    let weight = self.norm_squared().s;
+    if weight == 0. {
+      return (self, zero());
+    }
+
+    // half of `B ∧ B`, i.e. the dot product of the Euclidean & ideal parts
+    let e0123 = 0.5 * self.meet(self).e0123;
+    let lambda = e0123 / weight;
+    let ideal_line = Bivector {
+      e01: lambda * self.e23,
+      e02: lambda * self.e31,
+      e03: lambda * self.e12,
+      ..zero()
+    };
+
+    (self - ideal_line, ideal_line)
+  }
+
+  /// The nearest simple bivector
+  ///
+  /// Keeps the Euclidean part, & removes the component of the ideal part
+  /// which is parallel to it. A bivector which is already simple is returned
+  /// (approximately) unchanged.
+  #[inline]
+  pub fn project_simple(self) -> Bivector {
+    self.decompose().0
+  }
+}
+
 #[derive(Copy, Clone, Default, PartialEq)]
 #[repr(C)]
 pub(crate) struct BivectorArray {
@@ -120,3 +172,68 @@ impl Debug for BivectorArray {
     }
   }
 }
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+  use ::approx::assert_relative_eq;
+
+  #[test]
+  fn is_simple() {
+    let line = TRIVECTOR_A.join(TRIVECTOR_B);
+    assert!(line.is_simple(0.));
+    assert!(VECTOR_A.meet(VECTOR_B).is_simple(0.));
+    assert!(!BIVECTOR_A.is_simple(0.5));
+    // the tolerance is relative to the scale
+    let nearly = line + Bivector { e01: 1e-3, ..zero() } * line.norm().s;
+    for scale in [1e-3, 1., 1e3] {
+      assert!(dbg!(nearly * scale).is_simple(1e-2));
+      assert!(!(nearly * scale).is_simple(1e-5));
+      assert!(!(BIVECTOR_A * scale).is_simple(0.5));
+    }
+  }
+
+  #[test]
+  fn decompose() {
+    let (line, ideal_line) = BIVECTOR_A.decompose();
+
+    assert_relative_eq!(dbg!(line + ideal_line), dbg!(BIVECTOR_A));
+    assert!(dbg!(line).is_simple(0.1));
+    assert_eq!(dbg!(ideal_line).weight(), zero::<Bivector>());
+    assert_relative_eq!(
+      dbg!(line * ideal_line),
+      dbg!(ideal_line * line),
+      epsilon = 1e-2,
+    );
+  }
+
+  #[test]
+  fn decompose_simple() {
+    let line = TRIVECTOR_A.join(TRIVECTOR_B);
+    let (result, ideal_line) = line.decompose();
+
+    assert_eq!(dbg!(result), dbg!(line));
+    assert_eq!(dbg!(ideal_line), dbg!(Bivector::zero()));
+  }
+
+  #[test]
+  fn decompose_ideal() {
+    let ideal = BIVECTOR_A.bulk();
+    assert!(ideal.is_simple(0.));
+
+    let (line, ideal_line) = ideal.decompose();
+    assert_eq!(dbg!(line), dbg!(ideal));
+    assert_eq!(dbg!(ideal_line), dbg!(Bivector::zero()));
+    assert_eq!(dbg!(ideal.project_simple()), dbg!(ideal));
+  }
+
+  #[test]
+  fn project_simple() {
+    let result = BIVECTOR_A.project_simple();
+
+    assert!(dbg!(result).is_simple(0.1));
+    assert_eq!(dbg!(result.weight()), dbg!(BIVECTOR_A.weight()));
+  }
+}
@@ -1,6 +1,8 @@
 mod bivector;
 mod empty;
+mod motor;
 mod multivector;
+mod notation;
 mod pseudoscalar;
 mod scalar;
 mod trivector;
@@ -9,7 +11,9 @@ mod zero;
 
 pub use bivector::*;
 pub use empty::*;
+pub use motor::*;
 pub use multivector::*;
+pub use notation::{ParseValueError, ParsedValue};
 pub use pseudoscalar::*;
 pub use scalar::*;
 pub use trivector::*;
@@ -34,10 +38,20 @@ mod ega_value {
     + Reverse
     + Conjugate
     + HodgeDual
+    + LeftComplement
+    + RightComplement
+    + BulkDual
+    + WeightDual
+    + Polarity
     + NormSquared
     + Norm
     + IdealNormSquared
     + IdealNorm
+    + Bulk
+    + Weight
+    + BulkNorm
+    + WeightNorm
+    + GeometricNorm
     + Add<Multivector>
     + Add<Empty>
     + Add<Scalar>
@@ -99,6 +113,7 @@ mod ega_value {
   impl EgaValue for Pseudoscalar {}
 
   mod private {
+    #[allow(unused)]
     pub trait Sealed {}
   }
 }
@@ -0,0 +1,417 @@
+use crate::*;
+use ::core::fmt::{Debug, Formatter};
+
+/// An element of the even subalgebra, a rigid motion
+///
+/// Motors are the product of an even number of planes, i.e. a scalar, a
+/// bivector & a pseudoscalar; they transform values by the sandwich product
+/// `M X M̃`.
+#[derive(Copy, Clone, Default, PartialEq)]
+#[repr(C)]
+pub struct Motor {
+  pub s: f32,
+  pub e23: f32,
+  pub e31: f32,
+  pub e12: f32,
+  pub e01: f32,
+  pub e02: f32,
+  pub e03: f32,
+  pub e0123: f32,
+}
+
+impl Motor {
+  /// The identity motor
+  #[inline]
+  pub const fn identity() -> Motor {
+    Motor {
+      s: 1.,
+      e23: 0.,
+      e31: 0.,
+      e12: 0.,
+      e01: 0.,
+      e02: 0.,
+      e03: 0.,
+      e0123: 0.,
+    }
+  }
+
+  /// The even grade part of a multivector
+  #[inline]
+  pub const fn from_even(multivector: Multivector) -> Motor {
+    Motor {
+      s: multivector.s,
+      e23: multivector.e23,
+      e31: multivector.e31,
+      e12: multivector.e12,
+      e01: multivector.e01,
+      e02: multivector.e02,
+      e03: multivector.e03,
+      e0123: multivector.e0123,
+    }
+  }
+
+  /// The motor as a multivector
+  #[inline]
+  pub const fn to_multivector(self) -> Multivector {
+    Multivector {
+      s: self.s,
+      e23: self.e23,
+      e31: self.e31,
+      e12: self.e12,
+      e01: self.e01,
+      e02: self.e02,
+      e03: self.e03,
+      e0123: self.e0123,
+      ..Multivector::ZERO
+    }
+  }
+
+  /// The reverse, M̃
+  #[inline]
+  pub const fn reverse(self) -> Motor {
+    Motor {
+      s: self.s,
+      e23: -self.e23,
+      e31: -self.e31,
+      e12: -self.e12,
+      e01: -self.e01,
+      e02: -self.e02,
+      e03: -self.e03,
+      e0123: self.e0123,
+    }
+  }
+
+  /// The logarithm, the line `B` such that `M = exp(B)`
+  ///
+  /// The motor should be normalised. `M` & `-M` give the same motion; the
+  /// logarithm of a pure translation is taken from whichever has a positive
+  /// scalar part.
+  #[cfg(any(feature = "std", feature = "libm"))]
+  pub fn log(self) -> Bivector {
+    let bivector = Bivector {
+      e23: self.e23,
+      e31: self.e31,
+      e12: self.e12,
+      e01: self.e01,
+      e02: self.e02,
+      e03: self.e03,
+    };
+    let euclidean = Bivector {
+      e01: 0.,
+      e02: 0.,
+      e03: 0.,
+      ..bivector
+    };
+    let sin_squared = euclidean.norm_squared().s;
+    if sin_squared == 0. {
+      // a pure translation, `1 + B`
+      return bivector * (1. / self.s);
+    }
+    // `M = cos φ + sin φ L + t cos φ LI - t sin φ I`, for the unit line `L`
+    let sin = euclidean.norm().s;
+    #[cfg(not(feature = "libm"))]
+    let angle = sin.atan2(self.s);
+    #[cfg(feature = "libm")]
+    let angle = Libm::<f32>::atan2(sin, self.s);
+    let b = angle / sin;
+    let c = self.e0123 * (1. - self.s * b) / sin_squared;
+    let ideal = euclidean.mul_pseudoscalar(Pseudoscalar { e0123: c });
+    bivector * b - ideal
+  }
+
+  /// The logarithm of the normalised motor, or [`EgaError::ZeroNorm`] if
+  /// the motor has no weight
+  ///
+  /// See [`log`](Self::log).
+  #[cfg(any(feature = "std", feature = "libm"))]
+  pub fn try_log(self) -> Result<Bivector, EgaError> {
+    self.try_normalise().map(Motor::log)
+  }
+
+  /// The sandwich product `M X M̃`
+  #[inline]
+  pub(crate) fn sandwich(self, value: Multivector) -> Multivector {
+    let motor = Multivector::from(self);
+    motor * value * Multivector::from(self.reverse())
+  }
+
+  /// The matrix applying the motor to the components of a [`Trivector`],
+  /// `[e123, e032, e013, e021]`
+  pub fn point_matrix(self) -> [[f32; 4]; 4] {
+    let columns: [_; 4] = core::array::from_fn(|i| {
+      let mut basis = [0.; 4];
+      basis[i] = 1.;
+      let point = TrivectorArray::from(basis).to_trivector();
+      let image = self.sandwich(point.into()).grade_3();
+      image.to_trivector_array().elements
+    });
+    transpose(columns)
+  }
+
+  /// The matrix applying the motor to the components of a [`Vector`],
+  /// `[e0, e1, e2, e3]`
+  pub fn plane_matrix(self) -> [[f32; 4]; 4] {
+    let columns: [_; 4] = core::array::from_fn(|i| {
+      let mut basis = [0.; 4];
+      basis[i] = 1.;
+      let plane = VectorArray::from(basis).to_vector();
+      let image = self.sandwich(plane.into()).grade_1();
+      image.to_vector_array().elements
+    });
+    transpose(columns)
+  }
+
+  /// The matrix applying the motor to the components of a [`Bivector`],
+  /// `[e23, e31, e12, e01, e02, e03]`
+  pub fn line_matrix(self) -> [[f32; 6]; 6] {
+    let columns: [_; 6] = core::array::from_fn(|i| {
+      let mut basis = [0.; 6];
+      basis[i] = 1.;
+      let line = BivectorArray::from(basis).to_bivector();
+      let image = self.sandwich(line.into()).grade_2();
+      image.to_bivector_array().elements
+    });
+    transpose(columns)
+  }
+
+  /// Transform each of the `points` by the motor, writing the results to
+  /// `out`
+  ///
+  /// The motor is converted to a matrix once, so this is cheaper than taking
+  /// the sandwich product of each point.
+  ///
+  /// # Panics
+  ///
+  /// If `points` & `out` have different lengths.
+  pub fn transform_points(&self, points: &[Trivector], out: &mut [Trivector]) {
+    assert_eq!(points.len(), out.len(), "mismatched slice lengths");
+    transform_points_by(&self.point_matrix(), points, out);
+  }
+
+  /// Transform each of the `planes` by the motor, writing the results to
+  /// `out`
+  ///
+  /// See [`transform_points`](Self::transform_points).
+  ///
+  /// # Panics
+  ///
+  /// If `planes` & `out` have different lengths.
+  pub fn transform_planes(&self, planes: &[Vector], out: &mut [Vector]) {
+    assert_eq!(planes.len(), out.len(), "mismatched slice lengths");
+    transform_planes_by(&self.plane_matrix(), planes, out);
+  }
+
+  /// Transform each of the `lines` by the motor, writing the results to
+  /// `out`
+  ///
+  /// See [`transform_points`](Self::transform_points).
+  ///
+  /// # Panics
+  ///
+  /// If `lines` & `out` have different lengths.
+  pub fn transform_lines(&self, lines: &[Bivector], out: &mut [Bivector]) {
+    assert_eq!(lines.len(), out.len(), "mismatched slice lengths");
+    transform_lines_by(&self.line_matrix(), lines, out);
+  }
+}
+
+/// Apply a matrix from [`Motor::point_matrix`] to each of the points
+#[inline]
+pub(crate) fn transform_points_by(
+  matrix: &[[f32; 4]; 4],
+  points: &[Trivector],
+  out: &mut [Trivector],
+) {
+  for (point, out) in points.iter().zip(out.iter_mut()) {
+    let elements = apply(matrix, point.to_trivector_array().elements);
+    *out = TrivectorArray::from(elements).to_trivector();
+  }
+}
+
+/// Apply a matrix from [`Motor::plane_matrix`] to each of the planes
+#[inline]
+pub(crate) fn transform_planes_by(
+  matrix: &[[f32; 4]; 4],
+  planes: &[Vector],
+  out: &mut [Vector],
+) {
+  for (plane, out) in planes.iter().zip(out.iter_mut()) {
+    let elements = apply(matrix, plane.to_vector_array().elements);
+    *out = VectorArray::from(elements).to_vector();
+  }
+}
+
+/// Apply a matrix from [`Motor::line_matrix`] to each of the lines
+#[inline]
+pub(crate) fn transform_lines_by(
+  matrix: &[[f32; 6]; 6],
+  lines: &[Bivector],
+  out: &mut [Bivector],
+) {
+  for (line, out) in lines.iter().zip(out.iter_mut()) {
+    let elements = apply(matrix, line.to_bivector_array().elements);
+    *out = BivectorArray::from(elements).to_bivector();
+  }
+}
+
+#[inline]
+fn transpose<const N: usize>(columns: [[f32; N]; N]) -> [[f32; N]; N] {
+  core::array::from_fn(|row| core::array::from_fn(|col| columns[col][row]))
+}
+
+#[inline(always)]
+fn apply<const N: usize>(matrix: &[[f32; N]; N], x: [f32; N]) -> [f32; N] {
+  core::array::from_fn(|row| {
+    let mut sum = 0.;
+    for (a, b) in matrix[row].iter().zip(x.iter()) {
+      sum += a * b;
+    }
+    sum
+  })
+}
+
+impl From<Motor> for Multivector {
+  #[inline]
+  fn from(motor: Motor) -> Multivector {
+    motor.to_multivector()
+  }
+}
+
+impl Debug for Motor {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    fmt
+      .debug_struct("Motor")
+      .field("s", &self.s)
+      .field("e23", &self.e23)
+      .field("e31", &self.e31)
+      .field("e12", &self.e12)
+      .field("e01", &self.e01)
+      .field("e02", &self.e02)
+      .field("e03", &self.e03)
+      .field("e0123", &self.e0123)
+      .finish()
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+  use ::approx::assert_relative_eq;
+
+  const PLANE_X: Vector = Vector { e0: -1., e1: 1., e2: 0., e3: 0. };
+  const PLANE_Y: Vector = Vector { e0: 0., e1: 0., e2: 1., e3: 0. };
+  const PLANE_XYZ: Vector = Vector { e0: 3., e1: 1., e2: 2., e3: 2. };
+  const PLANE_X_2: Vector = Vector { e0: -2., e1: 1., e2: 0., e3: 0. };
+
+  fn motor() -> Motor {
+    Motor::from_even((PLANE_X * PLANE_Y) * (PLANE_XYZ * PLANE_X_2))
+  }
+
+  #[test]
+  fn identity() {
+    let mut out = [zero(); 2];
+    Motor::identity().transform_points(&[TRIVECTOR_A, TRIVECTOR_B], &mut out);
+    assert_eq!(dbg!(out), [TRIVECTOR_A, TRIVECTOR_B]);
+  }
+
+  #[test]
+  fn transform_points() {
+    let motor = motor();
+    let points = [TRIVECTOR_A, TRIVECTOR_B, TRIVECTOR_C];
+    let mut out = [zero(); 3];
+    motor.transform_points(&points, &mut out);
+    for (point, result) in points.iter().zip(out) {
+      let expected = motor.sandwich((*point).into()).grade_3();
+      assert_relative_eq!(result, expected, max_relative = 1e-5);
+    }
+  }
+
+  #[test]
+  fn transform_planes() {
+    let motor = motor();
+    let planes = [VECTOR_A, VECTOR_B, VECTOR_C];
+    let mut out = [zero(); 3];
+    motor.transform_planes(&planes, &mut out);
+    for (plane, result) in planes.iter().zip(out) {
+      let expected = motor.sandwich((*plane).into()).grade_1();
+      assert_relative_eq!(result, expected, max_relative = 1e-5);
+    }
+  }
+
+  #[test]
+  fn transform_lines() {
+    let motor = motor();
+    let lines = [BIVECTOR_A, BIVECTOR_B, BIVECTOR_C];
+    let mut out = [zero(); 3];
+    motor.transform_lines(&lines, &mut out);
+    for (line, result) in lines.iter().zip(out) {
+      let expected = motor.sandwich((*line).into()).grade_2();
+      assert_relative_eq!(result, expected, max_relative = 1e-5);
+    }
+  }
+
+  #[test]
+  #[should_panic]
+  fn mismatched_lengths() {
+    let mut out = [zero(); 1];
+    motor().transform_points(&[TRIVECTOR_A, TRIVECTOR_B], &mut out);
+  }
+
+  /// `exp(B)`, by summing its power series
+  fn exp(bivector: Bivector) -> Motor {
+    let b = Multivector::from(bivector);
+    let (mut term, mut sum) = (Multivector::from(Scalar { s: 1. }), Multivector::ZERO);
+    for k in 1..24 {
+      sum += term;
+      term = term * b * (1. / k as f32);
+    }
+    Motor::from_even(sum)
+  }
+
+  #[test]
+  fn log() {
+    let bivectors = [
+      // a rotation about the z axis
+      Bivector { e12: 0.5, ..Bivector::ZERO },
+      // a translation
+      Bivector { e01: 1., e02: -2., e03: 0.5, ..Bivector::ZERO },
+      // a screw motion about a line through the origin
+      Bivector { e12: 0.5, e03: 2., ..Bivector::ZERO },
+      // a screw motion about a line offset from the origin
+      Bivector { e23: 0.3, e31: -0.6, e12: 0.2, e01: 0.7, e02: 1.1, e03: -0.4 },
+    ];
+    for bivector in bivectors {
+      let result = exp(bivector).log();
+      assert_relative_eq!(dbg!(result), dbg!(bivector), epsilon = 1e-5);
+    }
+  }
+
+  #[test]
+  fn try_log() {
+    let bivector = Bivector { e23: 0.3, e31: -0.6, e12: 0.2, e01: 0.7, e02: 1.1, e03: -0.4 };
+    let motor = exp(bivector);
+    // scaling the motor doesn't change the motion
+    let scaled = Motor::from_even(motor.to_multivector() * 3.);
+    assert_relative_eq!(scaled.try_log().unwrap(), bivector, epsilon = 1e-5);
+    assert_eq!(dbg!(Motor::ZERO.try_log()), Err(EgaError::ZeroNorm));
+    let ideal = Motor { e01: 1., ..Motor::ZERO };
+    assert_eq!(dbg!(ideal.try_log()), Err(EgaError::ZeroNorm));
+  }
+
+  #[test]
+  fn from_even() {
+    let result = Multivector::from(Motor::from_even(MULTIVECTOR_A));
+    let expected = MULTIVECTOR_A.grade_0() + MULTIVECTOR_A.grade_2() + MULTIVECTOR_A.grade_4();
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn reverse() {
+    let motor = motor();
+    let result = Multivector::from(motor.reverse());
+    let expected = Multivector::from(motor).reverse();
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+}
@@ -0,0 +1,680 @@
+use crate::*;
+use ::core::{
+  fmt::{Display, Formatter},
+  str::FromStr,
+};
+
+// Display
+
+/// Write the non-zero terms as a sum, e.g. `3 + 2e01 - e0123`
+///
+/// The formatter's width & precision are applied to each coefficient. Unit
+/// coefficients are omitted from basis elements unless a precision is given.
+fn write_terms(
+  fmt: &mut Formatter<'_>,
+  terms: &[(f32, &str)],
+) -> core::fmt::Result {
+  let width = fmt.width().unwrap_or(0);
+  let precision = fmt.precision();
+
+  let mut first = true;
+  for &(coefficient, basis) in terms.iter().filter(|(c, _)| *c != 0.) {
+    let negative = coefficient.is_sign_negative();
+    let magnitude = if negative { -coefficient } else { coefficient };
+    match (first, negative) {
+      (true, true) => fmt.write_str("-")?,
+      (true, false) => {},
+      (false, true) => fmt.write_str(" - ")?,
+      (false, false) => fmt.write_str(" + ")?,
+    }
+    first = false;
+
+    if !basis.is_empty() && magnitude == 1. && precision.is_none() {
+      fmt.write_str(basis)?;
+      continue;
+    }
+    match precision {
+      Some(precision) => {
+        fmt.write_fmt(format_args!("{magnitude:width$.precision$}"))?
+      },
+      None => fmt.write_fmt(format_args!("{magnitude:width$}"))?,
+    }
+    fmt.write_str(basis)?;
+  }
+
+  if first {
+    match precision {
+      Some(precision) => {
+        fmt.write_fmt(format_args!("{:width$.precision$}", 0.))?
+      },
+      None => fmt.write_fmt(format_args!("{:width$}", 0.))?,
+    }
+  }
+
+  Ok(())
+}
+
+impl Display for Multivector {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    write_terms(
+      fmt,
+      &[
+        (self.s, ""),
+        (self.e0, "e0"),
+        (self.e1, "e1"),
+        (self.e2, "e2"),
+        (self.e3, "e3"),
+        (self.e23, "e23"),
+        (self.e31, "e31"),
+        (self.e12, "e12"),
+        (self.e01, "e01"),
+        (self.e02, "e02"),
+        (self.e03, "e03"),
+        (self.e123, "e123"),
+        (self.e032, "e032"),
+        (self.e013, "e013"),
+        (self.e021, "e021"),
+        (self.e0123, "e0123"),
+      ],
+    )
+  }
+}
+
+impl Display for Empty {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    write_terms(fmt, &[])
+  }
+}
+
+impl Display for Scalar {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    write_terms(fmt, &[(self.s, "")])
+  }
+}
+
+impl Display for Vector {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    write_terms(
+      fmt,
+      &[
+        (self.e0, "e0"),
+        (self.e1, "e1"),
+        (self.e2, "e2"),
+        (self.e3, "e3"),
+      ],
+    )
+  }
+}
+
+impl Display for Bivector {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    write_terms(
+      fmt,
+      &[
+        (self.e23, "e23"),
+        (self.e31, "e31"),
+        (self.e12, "e12"),
+        (self.e01, "e01"),
+        (self.e02, "e02"),
+        (self.e03, "e03"),
+      ],
+    )
+  }
+}
+
+impl Display for Trivector {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    write_terms(
+      fmt,
+      &[
+        (self.e123, "e123"),
+        (self.e032, "e032"),
+        (self.e013, "e013"),
+        (self.e021, "e021"),
+      ],
+    )
+  }
+}
+
+impl Display for Pseudoscalar {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    write_terms(fmt, &[(self.e0123, "e0123")])
+  }
+}
+
+// FromStr
+
+/// An error from parsing a value written in algebraic notation
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseValueError {
+  /// The string contained no terms
+  Empty,
+  /// A coefficient couldn't be parsed as an `f32`
+  InvalidCoefficient,
+  /// A basis element contained an index other than `0`, `1`, `2` or `3`
+  InvalidBasisElement,
+  /// An unexpected character was found where a term or operator should be
+  UnexpectedCharacter(char),
+  /// The value has components outside of the grade(s) of the requested type
+  WrongGrade,
+}
+
+impl Display for ParseValueError {
+  fn fmt(&self, fmt: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      ParseValueError::Empty => fmt.write_str("no terms to parse"),
+      ParseValueError::InvalidCoefficient => {
+        fmt.write_str("invalid coefficient")
+      },
+      ParseValueError::InvalidBasisElement => {
+        fmt.write_str("invalid basis element")
+      },
+      ParseValueError::UnexpectedCharacter(c) => {
+        fmt.write_fmt(format_args!("unexpected character {c:?}"))
+      },
+      ParseValueError::WrongGrade => {
+        fmt.write_str("value has components of the wrong grade")
+      },
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseValueError {}
+
+/// Reduce a product of basis vectors, given by their indices, to one of the
+/// native basis elements
+///
+/// Returns the sign & the index of the element in the `MultivectorArray`, or
+/// `None` if the product is zero (i.e. contains `e0` more than once)
+fn reduce_basis(
+  indices: &mut [u8],
+) -> Result<Option<(f32, usize)>, ParseValueError> {
+  if indices.iter().any(|&i| i > 3) {
+    return Err(ParseValueError::InvalidBasisElement);
+  }
+
+  // bubble sort, flipping the sign for each swap
+  let mut sign = 1f32;
+  for i in 0..indices.len() {
+    for j in 0..indices.len() - 1 - i {
+      if indices[j] > indices[j + 1] {
+        indices.swap(j, j + 1);
+        sign = -sign;
+      }
+    }
+  }
+
+  // contract repeated basis vectors, e1e1 = e2e2 = e3e3 = 1 & e0e0 = 0
+  let mut mask = 0u8;
+  for &i in indices.iter() {
+    if mask & (1 << i) != 0 && i == 0 {
+      return Ok(None);
+    }
+    mask ^= 1 << i;
+  }
+
+  // the sign of the native element relative to the sorted basis vectors
+  #[rustfmt::skip]
+  let (native_sign, index) = match mask {
+    0b0000 => ( 1.,  4),
+    0b0001 => ( 1.,  0),
+    0b0010 => ( 1.,  1),
+    0b0100 => ( 1.,  2),
+    0b1000 => ( 1.,  3),
+    0b1100 => ( 1.,  5),
+    0b1010 => (-1.,  6),
+    0b0110 => ( 1.,  7),
+    0b0011 => ( 1.,  8),
+    0b0101 => ( 1.,  9),
+    0b1001 => ( 1., 10),
+    0b1111 => ( 1., 11),
+    0b1110 => ( 1., 12),
+    0b1101 => (-1., 13),
+    0b1011 => ( 1., 14),
+    0b0111 => (-1., 15),
+    _ => unreachable!(),
+  };
+
+  Ok(Some((sign * native_sign, index)))
+}
+
+/// The end of the coefficient starting at `start`, in `f32` syntax
+///
+/// An `e` directly after the digits might begin an exponent or a basis
+/// element, as in `2e01`. It begins an exponent when followed by a sign, or
+/// by digits & then a basis element, as in `1e-5 e1` or `2e3e1`; `E` always
+/// begins an exponent.
+fn coefficient_end(s: &str, start: usize) -> usize {
+  let bytes = s.as_bytes();
+  // compared as bytes, as the input may continue with any character
+  let rest = &bytes[start..];
+  for special in ["infinity", "inf", "nan"] {
+    let prefix = rest.get(..special.len());
+    if prefix
+      .is_some_and(|prefix| prefix.eq_ignore_ascii_case(special.as_bytes()))
+    {
+      return start + special.len();
+    }
+  }
+  let digits = |mut i: usize| {
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+      i += 1;
+    }
+    i
+  };
+
+  let mut i = start;
+  while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+    i += 1;
+  }
+  if i == start || i == bytes.len() {
+    return i;
+  }
+  let exponent = match bytes[i] {
+    b'E' => true,
+    b'e' => match bytes.get(i + 1) {
+      Some(b'+' | b'-') => true,
+      Some(c) if c.is_ascii_digit() => {
+        // a basis element follows the digits
+        let mut j = digits(i + 1);
+        while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+          j += 1;
+        }
+        if j < bytes.len() && bytes[j] == b'*' {
+          j += 1;
+          while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+            j += 1;
+          }
+        }
+        bytes.get(j) == Some(&b'e')
+      },
+      _ => false,
+    },
+    _ => false,
+  };
+  if !exponent {
+    return i;
+  }
+  i += 1;
+  if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+    i += 1;
+  }
+  digits(i)
+}
+
+/// Parse a sum of terms like `3 + 2e01 - e0123` into a `Multivector`
+fn parse_terms(s: &str) -> Result<Multivector, ParseValueError> {
+  let mut elements = [0f32; 16];
+  let bytes = s.as_bytes();
+  let mut i = 0;
+  let mut terms = 0;
+
+  let skip_whitespace = |i: &mut usize| {
+    while *i < bytes.len() && bytes[*i].is_ascii_whitespace() {
+      *i += 1;
+    }
+  };
+
+  loop {
+    skip_whitespace(&mut i);
+    if i == bytes.len() {
+      break;
+    }
+
+    // sign
+    let mut sign = 1f32;
+    if terms > 0 {
+      match bytes[i] {
+        b'+' => {},
+        b'-' => sign = -1.,
+        _ => {
+          return Err(ParseValueError::UnexpectedCharacter(
+            s[i..].chars().next().unwrap(),
+          ))
+        },
+      }
+      i += 1;
+      skip_whitespace(&mut i);
+    } else if bytes[i] == b'-' || bytes[i] == b'+' {
+      if bytes[i] == b'-' {
+        sign = -1.;
+      }
+      i += 1;
+      skip_whitespace(&mut i);
+    }
+
+    // coefficient
+    let start = i;
+    i = coefficient_end(s, i);
+    let coefficient = match &s[start..i] {
+      "" => None,
+      number => Some(
+        f32::from_str(number)
+          .map_err(|_| ParseValueError::InvalidCoefficient)?,
+      ),
+    };
+
+    // basis element
+    skip_whitespace(&mut i);
+    if coefficient.is_some() && i < bytes.len() && bytes[i] == b'*' {
+      i += 1;
+      skip_whitespace(&mut i);
+    }
+    let basis = if i < bytes.len() && bytes[i] == b'e' {
+      i += 1;
+      let mut indices = [0u8; 16];
+      let mut count = 0;
+      while i < bytes.len() && bytes[i].is_ascii_digit() {
+        if count == indices.len() {
+          return Err(ParseValueError::InvalidBasisElement);
+        }
+        indices[count] = bytes[i] - b'0';
+        count += 1;
+        i += 1;
+      }
+      if count == 0 {
+        return Err(ParseValueError::InvalidBasisElement);
+      }
+      Some(reduce_basis(&mut indices[..count])?)
+    } else {
+      None
+    };
+
+    let (basis_sign, index) = match (coefficient, basis) {
+      (None, None) => {
+        return Err(match s[i..].chars().next() {
+          Some(c) => ParseValueError::UnexpectedCharacter(c),
+          None => ParseValueError::Empty,
+        })
+      },
+      (_, Some(None)) => (0., 4),
+      (_, Some(Some(basis))) => basis,
+      (Some(_), None) => (1., 4),
+    };
+    elements[index] += sign * basis_sign * coefficient.unwrap_or(1.);
+    terms += 1;
+  }
+
+  if terms == 0 {
+    return Err(ParseValueError::Empty);
+  }
+
+  Ok(MultivectorArray::from(elements).to_multivector())
+}
+
+impl FromStr for Multivector {
+  type Err = ParseValueError;
+
+  /// Parse a value written in algebraic notation, e.g. `3 + 2e01 - e0123`
+  fn from_str(s: &str) -> Result<Multivector, ParseValueError> {
+    parse_terms(s)
+  }
+}
+
+macro_rules! impl_from_str {
+  ($type:ty: $grade_fn:ident) => {
+    impl FromStr for $type {
+      type Err = ParseValueError;
+
+      /// Parse a value written in algebraic notation, e.g. `3 + 2e01 - e0123`
+      fn from_str(s: &str) -> Result<$type, ParseValueError> {
+        let multivector = parse_terms(s)?;
+        let value = multivector.$grade_fn();
+        if Multivector::from(value) != multivector {
+          return Err(ParseValueError::WrongGrade);
+        }
+        Ok(value)
+      }
+    }
+  };
+}
+
+impl_from_str! { Scalar: grade_0 }
+impl_from_str! { Vector: grade_1 }
+impl_from_str! { Bivector: grade_2 }
+impl_from_str! { Trivector: grade_3 }
+impl_from_str! { Pseudoscalar: grade_4 }
+
+/// A value parsed into the narrowest type that fits it
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParsedValue {
+  Scalar(Scalar),
+  Vector(Vector),
+  Bivector(Bivector),
+  Trivector(Trivector),
+  Pseudoscalar(Pseudoscalar),
+  Multivector(Multivector),
+}
+
+impl FromStr for ParsedValue {
+  type Err = ParseValueError;
+
+  /// Parse a value written in algebraic notation, e.g. `3 + 2e01 - e0123`,
+  /// into the narrowest type that fits it
+  ///
+  /// A value with no non-zero components is parsed as a `Scalar`
+  fn from_str(s: &str) -> Result<ParsedValue, ParseValueError> {
+    let m = parse_terms(s)?;
+    let value = if m == Multivector::from(m.grade_0()) {
+      ParsedValue::Scalar(m.grade_0())
+    } else if m == Multivector::from(m.grade_1()) {
+      ParsedValue::Vector(m.grade_1())
+    } else if m == Multivector::from(m.grade_2()) {
+      ParsedValue::Bivector(m.grade_2())
+    } else if m == Multivector::from(m.grade_3()) {
+      ParsedValue::Trivector(m.grade_3())
+    } else if m == Multivector::from(m.grade_4()) {
+      ParsedValue::Pseudoscalar(m.grade_4())
+    } else {
+      ParsedValue::Multivector(m)
+    };
+
+    Ok(value)
+  }
+}
+
+impl From<ParsedValue> for Multivector {
+  #[inline]
+  fn from(value: ParsedValue) -> Multivector {
+    match value {
+      ParsedValue::Scalar(value) => value.into(),
+      ParsedValue::Vector(value) => value.into(),
+      ParsedValue::Bivector(value) => value.into(),
+      ParsedValue::Trivector(value) => value.into(),
+      ParsedValue::Pseudoscalar(value) => value.into(),
+      ParsedValue::Multivector(value) => value,
+    }
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+
+  mod display {
+    use super::*;
+
+    #[test]
+    fn multivector() {
+      let value = Multivector { s: 3., e01: 2., e0123: -1., ..zero() };
+      assert_eq!(format!("{value}"), "3 + 2e01 - e0123");
+
+      let result = format!("{MULTIVECTOR_A}");
+      let expected = "11 + 2e0 + 3e1 + 5e2 + 7e3 + 13e23 + 17e31 + 19e12 \
+        + 23e01 + 29e02 + 31e03 + 41e123 + 43e032 + 47e013 + 53e021 + 37e0123";
+      assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn all_zero() {
+      assert_eq!(format!("{}", Multivector::zero()), "0");
+      assert_eq!(format!("{}", Empty), "0");
+      assert_eq!(format!("{:.2}", Vector::zero()), "0.00");
+    }
+
+    #[test]
+    fn leading_negative() {
+      let value = Vector { e0: 0., e1: -1., e2: 0.5, e3: -2. };
+      assert_eq!(format!("{value}"), "-e1 + 0.5e2 - 2e3");
+    }
+
+    #[test]
+    fn precision_and_width() {
+      let value = Trivector { e123: 1., e032: -2.5, e013: 0., e021: 0. };
+      assert_eq!(format!("{value:.2}"), "1.00e123 - 2.50e032");
+      assert_eq!(format!("{value:6.1}"), "   1.0e123 -    2.5e032");
+    }
+
+    #[test]
+    fn grades() {
+      assert_eq!(format!("{SCALAR_C}"), "-149");
+      assert_eq!(format!("{VECTOR_A}"), "151e0 + 157e1 + 163e2 + 167e3");
+      assert_eq!(
+        format!("{BIVECTOR_C}"),
+        "-281e23 - 283e31 - 293e12 - 307e01 - 311e02 - 313e03",
+      );
+      assert_eq!(
+        format!("{TRIVECTOR_A}"),
+        "317e123 + 331e032 + 337e013 + 347e021",
+      );
+      assert_eq!(format!("{PSEUDOSCALAR_A}"), "397e0123");
+    }
+  }
+
+  mod from_str {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+      for value in [MULTIVECTOR_A, MULTIVECTOR_B, MULTIVECTOR_C, MULTIVECTOR_D] {
+        let result: Multivector = format!("{value}").parse().unwrap();
+        assert_eq!(dbg!(result), dbg!(value));
+      }
+      let result: Bivector = format!("{BIVECTOR_C}").parse().unwrap();
+      assert_eq!(dbg!(result), dbg!(BIVECTOR_C));
+      let result: Trivector = format!("{TRIVECTOR_B:.3}").parse().unwrap();
+      assert_eq!(dbg!(result), dbg!(TRIVECTOR_B));
+    }
+
+    #[test]
+    fn notation() {
+      let result: Multivector = "3 + 2e01 - e0123".parse().unwrap();
+      let expected = Multivector { s: 3., e01: 2., e0123: -1., ..zero() };
+      assert_eq!(dbg!(result), dbg!(expected));
+
+      let result: Multivector = " -0.5*e1+e2 -e2 + 4 ".parse().unwrap();
+      let expected = Multivector { s: 4., e1: -0.5, ..zero() };
+      assert_eq!(dbg!(result), dbg!(expected));
+
+      let result: Multivector = "1 e2".parse().unwrap();
+      let expected = Multivector { e2: 1., ..zero() };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn exponents() {
+      let result: Multivector = "1e-5 e1 + 2.5E3 - 3e+2*e0123 + 4e2e3".parse().unwrap();
+      let expected = Multivector { s: 2500., e1: 1e-5, e0123: -300., e3: 400., ..zero() };
+      assert_eq!(dbg!(result), dbg!(expected));
+      // without a sign or a following basis element, `e` starts a basis element
+      let result: Multivector = "2e01 + 1e3".parse().unwrap();
+      let expected = Multivector { e01: 2., e3: 1., ..zero() };
+      assert_eq!(dbg!(result), dbg!(expected));
+
+      let result: Multivector = "-Infinity e1 + inf e2 + NaN".parse().unwrap();
+      assert_eq!(result.e1, f32::NEG_INFINITY);
+      assert_eq!(result.e2, f32::INFINITY);
+      assert!(result.s.is_nan());
+      // Display writes small & large values without exponents
+      let value = Vector { e0: 1e-30, e1: 3e30, ..zero() };
+      assert_eq!(format!("{value}").parse::<Vector>(), Ok(value));
+      assert_eq!("1e e1".parse::<Multivector>(), Err(ParseValueError::InvalidBasisElement));
+      assert_eq!("1e- e1".parse::<Multivector>(), Err(ParseValueError::InvalidCoefficient));
+    }
+
+    #[test]
+    fn basis_order() {
+      let result: Bivector = "e13 + 2e10".parse().unwrap();
+      let expected = Bivector { e31: -1., e01: -2., ..zero() };
+      assert_eq!(dbg!(result), dbg!(expected));
+
+      let result: Trivector = "e321 + e023 + e012".parse().unwrap();
+      let expected = Trivector { e123: -1., e032: -1., e013: 0., e021: -1. };
+      assert_eq!(dbg!(result), dbg!(expected));
+
+      let result: Multivector = "e11 + 2e00 + e1230".parse().unwrap();
+      let expected = Multivector { s: 1., e0123: -1., ..zero() };
+      assert_eq!(dbg!(result), dbg!(expected));
+    }
+
+    #[test]
+    fn narrowest() {
+      let result: ParsedValue = "2".parse().unwrap();
+      assert_eq!(result, ParsedValue::Scalar(Scalar { s: 2. }));
+
+      let result: ParsedValue = "e1 - 2e0".parse().unwrap();
+      let expected = Vector { e0: -2., e1: 1., ..zero() };
+      assert_eq!(result, ParsedValue::Vector(expected));
+
+      let result: ParsedValue = format!("{BIVECTOR_A}").parse().unwrap();
+      assert_eq!(result, ParsedValue::Bivector(BIVECTOR_A));
+
+      let result: ParsedValue = format!("{TRIVECTOR_A}").parse().unwrap();
+      assert_eq!(result, ParsedValue::Trivector(TRIVECTOR_A));
+
+      let result: ParsedValue = "-e0123".parse().unwrap();
+      let expected = Pseudoscalar { e0123: -1. };
+      assert_eq!(result, ParsedValue::Pseudoscalar(expected));
+
+      let result: ParsedValue = "1 + e12".parse().unwrap();
+      let expected = Multivector { s: 1., e12: 1., ..zero() };
+      assert_eq!(result, ParsedValue::Multivector(expected));
+    }
+
+    #[test]
+    fn errors() {
+      assert_eq!("".parse::<Multivector>(), Err(ParseValueError::Empty));
+      assert_eq!("  ".parse::<Multivector>(), Err(ParseValueError::Empty));
+      assert_eq!(
+        "1 + e4".parse::<Multivector>(),
+        Err(ParseValueError::InvalidBasisElement),
+      );
+      assert_eq!(
+        "1 + e".parse::<Multivector>(),
+        Err(ParseValueError::InvalidBasisElement),
+      );
+      assert_eq!(
+        "1.2.3".parse::<Multivector>(),
+        Err(ParseValueError::InvalidCoefficient),
+      );
+      assert_eq!(
+        "1 x".parse::<Multivector>(),
+        Err(ParseValueError::UnexpectedCharacter('x')),
+      );
+      assert_eq!(
+        "1 + ".parse::<Multivector>(),
+        Err(ParseValueError::Empty),
+      );
+      assert_eq!(
+        "1 + e1".parse::<Vector>(),
+        Err(ParseValueError::WrongGrade),
+      );
+      // characters longer than a byte, where the special values are checked
+      assert_eq!(
+        "1 + 日本語語".parse::<Multivector>(),
+        Err(ParseValueError::UnexpectedCharacter('日')),
+      );
+      assert_eq!(
+        "1 + in日".parse::<Multivector>(),
+        Err(ParseValueError::UnexpectedCharacter('i')),
+      );
+      assert_eq!(
+        "é1".parse::<Multivector>(),
+        Err(ParseValueError::UnexpectedCharacter('é')),
+      );
+    }
+  }
+}
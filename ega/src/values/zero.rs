@@ -13,10 +13,18 @@ pub trait Zero {
 
 macro_rules! impl_zero {
   ($type:ty, $count:literal) => {
+    impl $type {
+      /// The zero value, usable in `const` contexts
+      // SAFETY: every value type is a `repr(C)` struct of `$count` `f32`s, or
+      // zero sized
+      pub const ZERO: $type =
+        unsafe { core::mem::transmute::<[f32; $count], $type>([0.; $count]) };
+    }
+
     impl Zero for $type {
       #[inline]
       fn zero() -> Self {
-        Self::default()
+        Self::ZERO
       }
     }
   };
@@ -29,3 +37,4 @@ impl_zero! { Vector, 4 }
 impl_zero! { Bivector, 6 }
 impl_zero! { Trivector, 4 }
 impl_zero! { Pseudoscalar, 1 }
+impl_zero! { Motor, 8 }
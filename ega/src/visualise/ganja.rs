@@ -0,0 +1,484 @@
+use crate::*;
+use core::fmt::Write;
+
+/// Where the page produced by [`GanjaScene::to_html`] gets ganja.js from
+#[derive(Clone, Debug, PartialEq)]
+pub enum GanjaScript {
+  /// Load ganja.js from a URL, e.g. a CDN, or the path of a local copy
+  Src(String),
+  /// Embed the source of ganja.js in the page, so that it is self-contained
+  /// & can be viewed offline
+  ///
+  /// ```no_run
+  /// # use ega::visualise::{GanjaOptions, GanjaScript};
+  /// let source = std::fs::read_to_string("ganja.js").unwrap();
+  /// let options = GanjaOptions {
+  ///   script: GanjaScript::Inline(source),
+  ///   ..GanjaOptions::default()
+  /// };
+  /// ```
+  Inline(String),
+}
+
+/// Options controlling how a [`GanjaScene`] is rendered
+#[derive(Clone, Debug, PartialEq)]
+pub struct GanjaOptions {
+  /// How the page loads ganja.js
+  ///
+  /// The crate doesn't bundle ganja.js, so this defaults to loading it from
+  /// the unpkg CDN, & **viewing the page then needs a network connection**.
+  /// Use [`GanjaScript::Inline`] with the source of ganja.js for a page which
+  /// can be viewed offline.
+  pub script: GanjaScript,
+  /// Draw the grid
+  pub grid: bool,
+  /// Draw the labels
+  pub labels: bool,
+  /// The width of lines, in pixels
+  pub line_width: f32,
+  /// The radius of points, in pixels
+  pub point_radius: f32,
+  /// The camera's heading, in radians
+  pub heading: f32,
+  /// The camera's pitch, in radians
+  pub pitch: f32,
+}
+
+impl Default for GanjaOptions {
+  fn default() -> Self {
+    GanjaOptions {
+      script: GanjaScript::Src(String::from("https://unpkg.com/ganja.js")),
+      grid: true,
+      labels: true,
+      line_width: 3.,
+      point_radius: 1.,
+      heading: 0.6,
+      pitch: -0.2,
+    }
+  }
+}
+
+/// A collection of points, lines, planes & motors to be viewed with ganja.js
+///
+/// ```
+/// # use ega::*;
+/// # use ega::visualise::GanjaScene;
+/// let origin = Trivector { e123: 1., e032: 0., e013: 0., e021: 0. };
+/// let x = Trivector { e123: 1., e032: 1., e013: 0., e021: 0. };
+///
+/// let mut scene = GanjaScene::new();
+/// scene
+///   .point(origin, 0xff0000, "origin")
+///   .point(x, 0x00ff00, "x")
+///   .line(origin.join(x), 0x0000ff, "x axis");
+/// let html = scene.to_html();
+/// // std::fs::write("scene.html", html).unwrap();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GanjaScene {
+  pub options: GanjaOptions,
+  items: Vec<Item>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Item {
+  color: u32,
+  label: String,
+  expression: String,
+}
+
+impl GanjaScene {
+  /// An empty scene with the default options
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// An empty scene with the given options
+  pub fn with_options(options: GanjaOptions) -> Self {
+    GanjaScene {
+      options,
+      items: Vec::new(),
+    }
+  }
+
+  /// Add a point
+  ///
+  /// `color` is in ganja.js' `0xAARRGGBB` format, where the alpha byte is the
+  /// transparency, i.e. `0x00` is opaque. An empty `label` is not drawn.
+  pub fn point(
+    &mut self,
+    point: Trivector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    self.push(Multivector::from(point), color, label)
+  }
+
+  /// Add a line
+  ///
+  /// See [`point`](Self::point) for the meaning of `color` & `label`.
+  pub fn line(
+    &mut self,
+    line: Bivector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    self.push(Multivector::from(line), color, label)
+  }
+
+  /// Add a plane
+  ///
+  /// See [`point`](Self::point) for the meaning of `color` & `label`.
+  pub fn plane(
+    &mut self,
+    plane: Vector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    self.push(Multivector::from(plane), color, label)
+  }
+
+  /// Add a motor, drawn as its screw axis
+  ///
+  /// The axis is the Euclidean line of the motor's bivector part; a pure
+  /// translation has no such axis & is drawn as the ideal line instead. See
+  /// [`point`](Self::point) for the meaning of `color` & `label`.
+  pub fn motor(
+    &mut self,
+    motor: Multivector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    let bivector = motor.grade_2();
+    let axis = if bivector.norm_squared().s > 0. {
+      bivector.decompose().0
+    } else {
+      bivector
+    };
+    self.line(axis, color, label)
+  }
+
+  /// Add each of the points, with the same colour
+  pub fn points(
+    &mut self,
+    points: impl IntoIterator<Item = Trivector>,
+    color: u32,
+  ) -> &mut Self {
+    for point in points {
+      self.point(point, color, "");
+    }
+    self
+  }
+
+  /// Add each of the lines, with the same colour
+  pub fn lines(
+    &mut self,
+    lines: impl IntoIterator<Item = Bivector>,
+    color: u32,
+  ) -> &mut Self {
+    for line in lines {
+      self.line(line, color, "");
+    }
+    self
+  }
+
+  /// Add each of the planes, with the same colour
+  pub fn planes(
+    &mut self,
+    planes: impl IntoIterator<Item = Vector>,
+    color: u32,
+  ) -> &mut Self {
+    for plane in planes {
+      self.plane(plane, color, "");
+    }
+    self
+  }
+
+  /// The number of objects in the scene
+  pub fn len(&self) -> usize {
+    self.items.len()
+  }
+
+  /// Whether the scene has no objects
+  pub fn is_empty(&self) -> bool {
+    self.items.is_empty()
+  }
+
+  fn push(
+    &mut self,
+    value: Multivector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    self.items.push(Item {
+      color,
+      label: String::from(label),
+      expression: expression(value),
+    });
+    self
+  }
+
+  /// The javascript expression calling ganja.js, which evaluates to the
+  /// element displaying the scene
+  pub fn to_script(&self) -> String {
+    let options = &self.options;
+    let mut script = String::from("Algebra(3,0,1,()=>this.graph([\n");
+    let mut previous_color = None;
+    for item in &self.items {
+      script.push_str("  ");
+      if previous_color != Some(item.color) {
+        let _ = write!(script, "0x{:08x}, ", item.color);
+        previous_color = Some(item.color);
+      }
+      script.push_str(&item.expression);
+      if !item.label.is_empty() {
+        script.push_str(", ");
+        push_string_literal(&mut script, &item.label);
+      }
+      script.push_str(",\n");
+    }
+    let _ = write!(
+      script,
+      "], {{grid:{}, labels:{}, lineWidth:{}, pointRadius:{}, h:{}, p:{}}}))",
+      options.grid,
+      options.labels,
+      number(options.line_width),
+      number(options.point_radius),
+      number(options.heading),
+      number(options.pitch),
+    );
+    script
+  }
+
+  /// An html page displaying the scene
+  ///
+  /// The page is self-contained only if ganja.js is embedded with
+  /// [`GanjaScript::Inline`]; see [`GanjaOptions::script`].
+  pub fn to_html(&self) -> String {
+    let mut html = String::from(
+      "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n",
+    );
+    match &self.options.script {
+      GanjaScript::Src(src) => {
+        html.push_str("<script src=\"");
+        push_escaped_html(&mut html, src);
+        html.push_str("\"></script>\n");
+      },
+      GanjaScript::Inline(source) => {
+        html.push_str("<script>\n");
+        push_inline_script(&mut html, source);
+        html.push_str("\n</script>\n");
+      },
+    }
+    html.push_str("</head>\n<body>\n<script>\n");
+    html.push_str("document.body.appendChild(");
+    html.push_str(&self.to_script());
+    html.push_str(");\n</script>\n</body>\n</html>\n");
+    html
+  }
+}
+
+/// The ganja.js basis blades, in the order of `MultivectorArray`, & the sign
+/// relating them to the native basis elements
+///
+/// ganja.js only names blades with their indices in increasing order.
+#[rustfmt::skip]
+const BASIS: [(&str, f32); 16] = [
+  ("1e0", 1.),   ("1e1", 1.),    ("1e2", 1.),    ("1e3", 1.),
+  ("", 1.),      ("1e23", 1.),   ("1e13", -1.),  ("1e12", 1.),
+  ("1e01", 1.),  ("1e02", 1.),   ("1e03", 1.),   ("1e0123", 1.),
+  ("1e123", 1.), ("1e023", -1.), ("1e013", 1.),  ("1e012", -1.),
+];
+
+/// The javascript expression of the value, in ganja.js' inline syntax
+fn expression(value: Multivector) -> String {
+  let elements = value.to_multivector_array().elements;
+  let mut terms = Vec::new();
+  for (&element, &(blade, sign)) in elements.iter().zip(BASIS.iter()) {
+    if element == 0. {
+      continue;
+    }
+    let coefficient = number(sign * element);
+    terms.push(if blade.is_empty() {
+      coefficient
+    } else {
+      format!("{coefficient}*{blade}")
+    });
+  }
+  if terms.is_empty() {
+    return String::from("0");
+  }
+  format!("({})", terms.join(" + "))
+}
+
+/// A javascript number literal
+fn number(x: f32) -> String {
+  if x.is_nan() {
+    String::from("NaN")
+  } else if x.is_infinite() {
+    String::from(if x > 0. { "Infinity" } else { "-Infinity" })
+  } else {
+    // Display never uses exponent notation, which the ganja.js inline
+    // syntax would confuse with a basis blade
+    format!("({x})")
+  }
+}
+
+fn push_string_literal(out: &mut String, string: &str) {
+  out.push('"');
+  for c in string.chars() {
+    match c {
+      '"' => out.push_str("\\\""),
+      '\\' => out.push_str("\\\\"),
+      '\n' => out.push_str("\\n"),
+      '\r' => out.push_str("\\r"),
+      '\t' => out.push_str("\\t"),
+      // so that a label can't close the surrounding script tag
+      '<' => out.push_str("\\u003c"),
+      '>' => out.push_str("\\u003e"),
+      c if c.is_control() => {
+        let _ = write!(out, "\\u{:04x}", c as u32);
+      },
+      c => out.push(c),
+    }
+  }
+  out.push('"');
+}
+
+/// Append the javascript source, so that it can't close the surrounding
+/// script tag
+fn push_inline_script(out: &mut String, source: &str) {
+  let mut rest = source;
+  while let Some(i) = rest.find("</") {
+    let tag = rest[i + 2..].get(..6);
+    out.push_str(&rest[..i]);
+    if tag.is_some_and(|tag| tag.eq_ignore_ascii_case("script")) {
+      // equivalent within the strings & regular expressions it can occur in
+      out.push_str("<\\/");
+    } else {
+      out.push_str("</");
+    }
+    rest = &rest[i + 2..];
+  }
+  out.push_str(rest);
+}
+
+fn push_escaped_html(out: &mut String, string: &str) {
+  for c in string.chars() {
+    match c {
+      '&' => out.push_str("&amp;"),
+      '"' => out.push_str("&quot;"),
+      '<' => out.push_str("&lt;"),
+      '>' => out.push_str("&gt;"),
+      c => out.push(c),
+    }
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+
+  const ORIGIN: Trivector = Trivector { e123: 1., e032: 0., e013: 0., e021: 0. };
+  const POINT: Trivector = Trivector { e123: 1., e032: 2., e013: 3., e021: -5. };
+
+  #[test]
+  fn point_expression() {
+    let result = expression(POINT.into());
+    let expected = "((1)*1e123 + (-2)*1e023 + (3)*1e013 + (5)*1e012)";
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn bivector_expression() {
+    let line = Bivector { e23: 1., e31: 2., e12: 0., e01: 0., e02: 0.5, e03: 0. };
+    let result = expression(line.into());
+    let expected = "((1)*1e23 + (-2)*1e13 + (0.5)*1e02)";
+    assert_eq!(dbg!(result), dbg!(expected));
+  }
+
+  #[test]
+  fn zero_and_scalar_expression() {
+    assert_eq!(expression(Multivector::zero()), "0");
+    let scalar = Multivector { s: 2.5, ..zero() };
+    assert_eq!(expression(scalar), "((2.5))");
+  }
+
+  #[test]
+  fn numbers() {
+    assert_eq!(number(1e-7), "(0.0000001)");
+    assert_eq!(number(f32::INFINITY), "Infinity");
+    assert_eq!(number(f32::NEG_INFINITY), "-Infinity");
+    assert_eq!(number(f32::NAN), "NaN");
+  }
+
+  #[test]
+  fn script() {
+    let mut scene = GanjaScene::new();
+    scene
+      .point(ORIGIN, 0xff0000, "origin")
+      .point(POINT, 0xff0000, "")
+      .line(ORIGIN.join(POINT), 0x0000ff, "line");
+    assert_eq!(scene.len(), 3);
+
+    let result = scene.to_script();
+    let expected = "Algebra(3,0,1,()=>this.graph([\n\
+      \x20 0x00ff0000, ((1)*1e123), \"origin\",\n\
+      \x20 ((1)*1e123 + (-2)*1e023 + (3)*1e013 + (5)*1e012),\n\
+      \x20 0x000000ff, ((-2)*1e23 + (3)*1e13 + (5)*1e12), \"line\",\n\
+      ], {grid:true, labels:true, lineWidth:(3), pointRadius:(1), h:(0.6), p:(-0.2)}))";
+    assert_eq!(result, expected);
+  }
+
+  #[test]
+  fn labels_are_escaped() {
+    let mut scene = GanjaScene::new();
+    scene.point(ORIGIN, 0, "a \"quoted\"\n</script>");
+    let result = scene.to_script();
+    assert!(result.contains(r#""a \"quoted\"\n\u003c/script\u003e""#), "{result}");
+  }
+
+  #[test]
+  fn motor_is_drawn_as_its_axis() {
+    let plane_x = Vector { e0: 0., e1: 1., e2: 0., e3: 0. };
+    let plane_y = Vector { e0: 0., e1: 0., e2: 1., e3: 0. };
+    let rotor = plane_x * plane_y;
+    let mut scene = GanjaScene::new();
+    scene.motor(rotor, 0, "");
+    assert_eq!(scene.items[0].expression, "((1)*1e12)");
+
+    let plane_x_2 = Vector { e0: -2., e1: 1., e2: 0., e3: 0. };
+    let translator = plane_x * plane_x_2;
+    let mut scene = GanjaScene::new();
+    scene.motor(translator, 0, "");
+    assert_eq!(scene.items[0].expression, "((2)*1e01)");
+  }
+
+  #[test]
+  fn html() {
+    let mut scene = GanjaScene::with_options(GanjaOptions {
+      script: GanjaScript::Src(String::from("ganja.js")),
+      ..GanjaOptions::default()
+    });
+    scene.planes([Vector { e0: 0., e1: 0., e2: 0., e3: 1. }], 0x80ffff00);
+    let result = scene.to_html();
+    assert!(result.starts_with("<!DOCTYPE html>"));
+    assert!(result.contains("<script src=\"ganja.js\"></script>"));
+    assert!(result.contains(&format!("document.body.appendChild({});", scene.to_script())));
+    assert!(result.contains("0x80ffff00, ((1)*1e3),"));
+  }
+
+  #[test]
+  fn inline_html() {
+    let source = "function Algebra() { return '</SCRIPT>' + '</div>' }";
+    let scene = GanjaScene::with_options(GanjaOptions {
+      script: GanjaScript::Inline(String::from(source)),
+      ..GanjaOptions::default()
+    });
+    let result = scene.to_html();
+    assert!(!result.contains("src="));
+    assert!(result.contains("<script>\nfunction Algebra() { return '<\\/SCRIPT>' + '</div>' }\n</script>"), "{result}");
+    assert_eq!(result.matches("</script>").count(), 2);
+  }
+}
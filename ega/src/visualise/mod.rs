@@ -0,0 +1,5 @@
+mod ganja;
+mod svg;
+
+pub use ganja::{GanjaOptions, GanjaScene, GanjaScript};
+pub use svg::{SvgOptions, SvgScene};
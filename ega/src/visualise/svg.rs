@@ -0,0 +1,671 @@
+use crate::*;
+use core::fmt::Write;
+
+/// Options controlling how an [`SvgScene`] is rendered
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgOptions {
+  /// The plane the scene is projected onto
+  ///
+  /// The scene is viewed from the side the plane's normal points to, so that
+  /// the default `e3` plane is a top-down view with `x` to the right & `y`
+  /// up. Only the orientation of the plane matters.
+  pub view_plane: Vector,
+  /// The minimum corner of the view box, everything outside it is clipped
+  pub min: [f32; 3],
+  /// The maximum corner of the view box, everything outside it is clipped
+  pub max: [f32; 3],
+  /// The width of the image, in pixels
+  pub width: f32,
+  /// The height of the image, in pixels
+  pub height: f32,
+  /// The width of lines, in pixels
+  pub stroke_width: f32,
+  /// The radius of points, in pixels
+  pub point_radius: f32,
+}
+
+impl Default for SvgOptions {
+  fn default() -> Self {
+    SvgOptions {
+      view_plane: Vector { e3: 1., ..zero() },
+      min: [-1., -1., -1.],
+      max: [1., 1., 1.],
+      width: 512.,
+      height: 512.,
+      stroke_width: 1.,
+      point_radius: 3.,
+    }
+  }
+}
+
+/// A collection of points, segments, lines & planes to be rendered as an
+/// orthographic projection to svg
+///
+/// ```
+/// # use ega::*;
+/// # use ega::visualise::SvgScene;
+/// let origin = Trivector { e123: 1., e032: 0., e013: 0., e021: 0. };
+/// let x = Trivector { e123: 1., e032: 0.5, e013: 0., e021: 0. };
+/// let plane = Vector { e0: 0., e1: 1., e2: 1., e3: 0. };
+///
+/// let mut scene = SvgScene::new();
+/// scene
+///   .plane(plane, 0x808080, "")
+///   .segment(origin, x, 0x0000ff, "")
+///   .point(origin, 0xff0000, "origin");
+/// let svg = scene.to_svg();
+/// // std::fs::write("scene.svg", svg).unwrap();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SvgScene {
+  pub options: SvgOptions,
+  elements: Vec<Element>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Element {
+  shape: Shape,
+  color: u32,
+  label: String,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Shape {
+  Point(Trivector),
+  Segment(Trivector, Trivector),
+  Line(Bivector),
+  Plane(Vector),
+}
+
+impl SvgScene {
+  /// An empty scene with the default options
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// An empty scene with the given options
+  pub fn with_options(options: SvgOptions) -> Self {
+    SvgScene {
+      options,
+      elements: Vec::new(),
+    }
+  }
+
+  /// Add a point
+  ///
+  /// `color` is in `0xRRGGBB` format. An empty `label` is not drawn.
+  pub fn point(
+    &mut self,
+    point: Trivector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    self.push(Shape::Point(point), color, label)
+  }
+
+  /// Add the line segment between two points
+  ///
+  /// See [`point`](Self::point) for the meaning of `color` & `label`.
+  pub fn segment(
+    &mut self,
+    start: Trivector,
+    end: Trivector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    self.push(Shape::Segment(start, end), color, label)
+  }
+
+  /// Add a line
+  ///
+  /// See [`point`](Self::point) for the meaning of `color` & `label`.
+  pub fn line(
+    &mut self,
+    line: Bivector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    self.push(Shape::Line(line), color, label)
+  }
+
+  /// Add a plane, drawn as its intersection with the view box
+  ///
+  /// See [`point`](Self::point) for the meaning of `color` & `label`.
+  pub fn plane(
+    &mut self,
+    plane: Vector,
+    color: u32,
+    label: &str,
+  ) -> &mut Self {
+    self.push(Shape::Plane(plane), color, label)
+  }
+
+  /// Add each of the points, with the same colour
+  pub fn points(
+    &mut self,
+    points: impl IntoIterator<Item = Trivector>,
+    color: u32,
+  ) -> &mut Self {
+    for point in points {
+      self.point(point, color, "");
+    }
+    self
+  }
+
+  /// The number of objects in the scene
+  pub fn len(&self) -> usize {
+    self.elements.len()
+  }
+
+  /// Whether the scene has no objects
+  pub fn is_empty(&self) -> bool {
+    self.elements.is_empty()
+  }
+
+  fn push(&mut self, shape: Shape, color: u32, label: &str) -> &mut Self {
+    self.elements.push(Element {
+      shape,
+      color,
+      label: String::from(label),
+    });
+    self
+  }
+
+  /// The svg document of the scene
+  ///
+  /// Objects are drawn in the order they were added; anything outside of the
+  /// view box, or at infinity, is not drawn.
+  pub fn to_svg(&self) -> String {
+    let options = &self.options;
+    let view_box = ViewBox::new(options.min, options.max);
+    let projection = Projection::new(options);
+
+    let mut svg = String::new();
+    let _ = writeln!(
+      svg,
+      "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+       width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">",
+      w = options.width,
+      h = options.height,
+    );
+    for element in &self.elements {
+      let color = element.color & 0xffffff;
+      let anchor = match element.shape {
+        Shape::Point(point) => view_box.clip_point(point).map(|point| {
+          let [x, y] = projection.project(point);
+          let _ = writeln!(
+            svg,
+            "<circle cx=\"{x:.2}\" cy=\"{y:.2}\" r=\"{}\" \
+               fill=\"#{color:06x}\"/>",
+            options.point_radius,
+          );
+          [x, y]
+        }),
+        Shape::Segment(start, end) => {
+          view_box.clip_segment(start, end).map(|(start, end)| {
+            draw_line(&mut svg, &projection, start, end, color)
+          })
+        },
+        Shape::Line(line) => view_box.clip_line(line).map(|(start, end)| {
+          draw_line(&mut svg, &projection, start, end, color)
+        }),
+        Shape::Plane(plane) => view_box.clip_plane(plane).map(|polygon| {
+          let mut centre = [0., 0.];
+          svg.push_str("<polygon points=\"");
+          for (i, &point) in polygon.iter().enumerate() {
+            let [x, y] = projection.project(point);
+            centre[0] += x / polygon.len() as f32;
+            centre[1] += y / polygon.len() as f32;
+            let separator = if i == 0 { "" } else { " " };
+            let _ = write!(svg, "{separator}{x:.2},{y:.2}");
+          }
+          let _ = writeln!(
+            svg,
+            "\" fill=\"#{color:06x}\" fill-opacity=\"0.25\" \
+             stroke=\"#{color:06x}\" stroke-width=\"{}\"/>",
+            options.stroke_width,
+          );
+          centre
+        }),
+      };
+      if let (Some([x, y]), false) = (anchor, element.label.is_empty()) {
+        let _ = write!(
+          svg,
+          "<text x=\"{:.2}\" y=\"{:.2}\" font-size=\"12\" \
+           fill=\"#{color:06x}\">",
+          x + options.point_radius + 2.,
+          y - options.point_radius - 2.,
+        );
+        push_escaped_xml(&mut svg, &element.label);
+        svg.push_str("</text>\n");
+      }
+    }
+    svg.push_str("</svg>\n");
+    svg
+  }
+}
+
+/// Draw a line between two projected points, returning its midpoint
+fn draw_line(
+  svg: &mut String,
+  projection: &Projection,
+  start: [f32; 3],
+  end: [f32; 3],
+  color: u32,
+) -> [f32; 2] {
+  let [x1, y1] = projection.project(start);
+  let [x2, y2] = projection.project(end);
+  let _ = writeln!(
+    svg,
+    "<line x1=\"{x1:.2}\" y1=\"{y1:.2}\" x2=\"{x2:.2}\" y2=\"{y2:.2}\" \
+     stroke=\"#{color:06x}\" stroke-width=\"{}\"/>",
+    projection.stroke_width,
+  );
+  [(x1 + x2) / 2., (y1 + y2) / 2.]
+}
+
+/// The orthographic projection from the view box onto the image
+struct Projection {
+  u: [f32; 3],
+  v: [f32; 3],
+  centre: [f32; 2],
+  scale: f32,
+  size: [f32; 2],
+  stroke_width: f32,
+}
+
+impl Projection {
+  fn new(options: &SvgOptions) -> Self {
+    let normal = [
+      options.view_plane.e1,
+      options.view_plane.e2,
+      options.view_plane.e3,
+    ];
+    let (u, v) = plane_basis(normal).unwrap_or(([1., 0., 0.], [0., 1., 0.]));
+
+    // the extent of the projected view box
+    let mut min = [f32::INFINITY; 2];
+    let mut max = [f32::NEG_INFINITY; 2];
+    for corner in ViewBox::new(options.min, options.max).corners() {
+      let projected = [dot(corner, u), dot(corner, v)];
+      for i in 0..2 {
+        min[i] = min[i].min(projected[i]);
+        max[i] = max[i].max(projected[i]);
+      }
+    }
+    let extent = [max[0] - min[0], max[1] - min[1]];
+    let scale = (options.width / extent[0]).min(options.height / extent[1]);
+
+    Projection {
+      u,
+      v,
+      centre: [(min[0] + max[0]) / 2., (min[1] + max[1]) / 2.],
+      scale: if scale.is_finite() { scale } else { 1. },
+      size: [options.width, options.height],
+      stroke_width: options.stroke_width,
+    }
+  }
+
+  /// The image coordinates of a point, with `y` pointing down
+  fn project(&self, point: [f32; 3]) -> [f32; 2] {
+    let a = dot(point, self.u) - self.centre[0];
+    let b = dot(point, self.v) - self.centre[1];
+    [
+      self.size[0] / 2. + a * self.scale,
+      self.size[1] / 2. - b * self.scale,
+    ]
+  }
+}
+
+/// An axis aligned box, bounded by 6 inward facing planes
+struct ViewBox {
+  min: [f32; 3],
+  max: [f32; 3],
+  planes: [Vector; 6],
+  tolerance: f32,
+}
+
+impl ViewBox {
+  fn new(min: [f32; 3], max: [f32; 3]) -> Self {
+    #[rustfmt::skip]
+    let planes = [
+      Vector { e0: -min[0], e1:  1., e2:  0., e3:  0. },
+      Vector { e0:  max[0], e1: -1., e2:  0., e3:  0. },
+      Vector { e0: -min[1], e1:  0., e2:  1., e3:  0. },
+      Vector { e0:  max[1], e1:  0., e2: -1., e3:  0. },
+      Vector { e0: -min[2], e1:  0., e2:  0., e3:  1. },
+      Vector { e0:  max[2], e1:  0., e2:  0., e3: -1. },
+    ];
+    let size = (0..3).map(|i| max[i] - min[i]).fold(0., f32::max);
+    ViewBox {
+      min,
+      max,
+      planes,
+      tolerance: size * 1e-4,
+    }
+  }
+
+  fn corners(&self) -> [[f32; 3]; 8] {
+    let (min, max) = (self.min, self.max);
+    core::array::from_fn(|i| {
+      [
+        if i & 1 == 0 { min[0] } else { max[0] },
+        if i & 2 == 0 { min[1] } else { max[1] },
+        if i & 4 == 0 { min[2] } else { max[2] },
+      ]
+    })
+  }
+
+  fn contains(&self, point: [f32; 3]) -> bool {
+    (0..3).all(|i| {
+      self.min[i] - self.tolerance <= point[i]
+        && point[i] <= self.max[i] + self.tolerance
+    })
+  }
+
+  fn clip_point(&self, point: Trivector) -> Option<[f32; 3]> {
+    coordinates(point).filter(|&point| self.contains(point))
+  }
+
+  fn clip_segment(
+    &self,
+    start: Trivector,
+    end: Trivector,
+  ) -> Option<([f32; 3], [f32; 3])> {
+    let mut start = from_coordinates(coordinates(start)?);
+    let mut end = from_coordinates(coordinates(end)?);
+    let line = start.join(end);
+    for plane in self.planes {
+      let start_distance = signed_distance(plane, start);
+      let end_distance = signed_distance(plane, end);
+      match (start_distance < 0., end_distance < 0.) {
+        (true, true) => return None,
+        (true, false) => start = line.meet(plane),
+        (false, true) => end = line.meet(plane),
+        (false, false) => (),
+      }
+    }
+    Some((coordinates(start)?, coordinates(end)?))
+  }
+
+  fn clip_line(&self, line: Bivector) -> Option<([f32; 3], [f32; 3])> {
+    // the points where the line crosses the boundary of the box
+    let points = self
+      .planes
+      .iter()
+      .filter_map(|&plane| coordinates(line.meet(plane)))
+      .filter(|&point| self.contains(point))
+      .collect::<Vec<_>>();
+
+    let mut furthest = None;
+    let mut furthest_distance = 0.;
+    for (i, &a) in points.iter().enumerate() {
+      for &b in &points[i + 1..] {
+        let distance = distance_squared(a, b);
+        if distance > furthest_distance {
+          furthest_distance = distance;
+          furthest = Some((a, b));
+        }
+      }
+    }
+    furthest
+  }
+
+  fn clip_plane(&self, plane: Vector) -> Option<Vec<[f32; 3]>> {
+    let (u, v) = plane_basis([plane.e1, plane.e2, plane.e3])?;
+    let corners = self.corners().map(from_coordinates);
+
+    // the points where the plane crosses the edges of the box
+    let mut polygon: Vec<[f32; 3]> = Vec::new();
+    for i in 0..8 {
+      for axis in [1, 2, 4] {
+        if i & axis != 0 {
+          continue;
+        }
+        let (a, b) = (corners[i], corners[i | axis]);
+        let a_distance = signed_distance(plane, a);
+        let b_distance = signed_distance(plane, b);
+        let crossing = if a_distance == 0. && b_distance == 0. {
+          coordinates(a)
+        } else if a_distance * b_distance <= 0. {
+          coordinates(a.join(b).meet(plane))
+        } else {
+          None
+        };
+        if let Some(point) = crossing.filter(|&point| self.contains(point)) {
+          let is_new = polygon.iter().all(|&other| {
+            distance_squared(point, other) > self.tolerance * self.tolerance
+          });
+          if is_new {
+            polygon.push(point);
+          }
+        }
+      }
+    }
+    if polygon.len() < 3 {
+      return None;
+    }
+
+    // order the points around their centroid
+    let mut centre = [0.; 3];
+    for point in &polygon {
+      for i in 0..3 {
+        centre[i] += point[i] / polygon.len() as f32;
+      }
+    }
+    let angle = |point: &[f32; 3]| {
+      let offset = sub(*point, centre);
+      dot(offset, v).atan2(dot(offset, u))
+    };
+    polygon.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+    Some(polygon)
+  }
+}
+
+/// The distance of a point from a plane, scaled by the norm of the plane
+///
+/// Positive on the side the plane's normal points to.
+fn signed_distance(plane: Vector, point: Trivector) -> f32 {
+  plane.meet(point).e0123 / point.e123
+}
+
+/// The Euclidean coordinates of a point, or `None` for a point at infinity
+fn coordinates(point: Trivector) -> Option<[f32; 3]> {
+  let coordinates = [
+    point.e032 / point.e123,
+    point.e013 / point.e123,
+    point.e021 / point.e123,
+  ];
+  coordinates
+    .iter()
+    .all(|x| x.is_finite())
+    .then_some(coordinates)
+}
+
+fn from_coordinates([x, y, z]: [f32; 3]) -> Trivector {
+  Trivector {
+    e123: 1.,
+    e032: x,
+    e013: y,
+    e021: z,
+  }
+}
+
+/// An orthonormal basis `u`, `v` of the plane with the given normal, such
+/// that `u × v` points along the normal
+fn plane_basis(normal: [f32; 3]) -> Option<([f32; 3], [f32; 3])> {
+  let length = dot(normal, normal).sqrt();
+  if length == 0. || !length.is_finite() {
+    return None;
+  }
+  let n = normal.map(|x| x / length);
+
+  // the axis least aligned with the normal
+  let mut axis = [0.; 3];
+  let i = (0..3)
+    .min_by(|&i, &j| n[i].abs().total_cmp(&n[j].abs()))
+    .unwrap_or(0);
+  axis[i] = 1.;
+
+  let u = sub(axis, n.map(|x| x * n[i]));
+  let length = dot(u, u).sqrt();
+  let u = u.map(|x| x / length);
+  let v = cross(n, u);
+  Some((u, v))
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+  a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+  [
+    a[1] * b[2] - a[2] * b[1],
+    a[2] * b[0] - a[0] * b[2],
+    a[0] * b[1] - a[1] * b[0],
+  ]
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+  [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn distance_squared(a: [f32; 3], b: [f32; 3]) -> f32 {
+  let d = sub(a, b);
+  dot(d, d)
+}
+
+fn push_escaped_xml(out: &mut String, string: &str) {
+  for c in string.chars() {
+    match c {
+      '&' => out.push_str("&amp;"),
+      '"' => out.push_str("&quot;"),
+      '<' => out.push_str("&lt;"),
+      '>' => out.push_str("&gt;"),
+      c => out.push(c),
+    }
+  }
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+
+  const ORIGIN: Trivector = Trivector { e123: 1., e032: 0., e013: 0., e021: 0. };
+  const X_2: Trivector = Trivector { e123: 1., e032: 2., e013: 0., e021: 0. };
+  const PLANE_Z: Vector = Vector { e0: 0., e1: 0., e2: 0., e3: 1. };
+
+  fn view_box() -> ViewBox {
+    ViewBox::new([-1., -1., -1.], [1., 1., 1.])
+  }
+
+  #[test]
+  fn basis() {
+    let result = plane_basis([0., 0., 2.]);
+    let expected = Some(([1., 0., 0.], [0., 1., 0.]));
+    assert_eq!(dbg!(result), dbg!(expected));
+
+    let result = plane_basis([0., -1., 0.]);
+    let expected = Some(([1., 0., 0.], [0., 0., 1.]));
+    assert_eq!(dbg!(result), dbg!(expected));
+
+    assert_eq!(plane_basis([0., 0., 0.]), None);
+  }
+
+  #[test]
+  fn clip_point() {
+    let view_box = view_box();
+    assert_eq!(view_box.clip_point(ORIGIN), Some([0., 0., 0.]));
+    assert_eq!(view_box.clip_point(X_2), None);
+    let ideal = Trivector { e123: 0., ..X_2 };
+    assert_eq!(view_box.clip_point(ideal), None);
+  }
+
+  #[test]
+  fn clip_segment() {
+    let view_box = view_box();
+    let result = view_box.clip_segment(ORIGIN, X_2);
+    let expected = Some(([0., 0., 0.], [1., 0., 0.]));
+    assert_eq!(dbg!(result), dbg!(expected));
+
+    let result = view_box.clip_segment(Trivector { e032: -2., ..X_2 }, X_2);
+    let expected = Some(([-1., 0., 0.], [1., 0., 0.]));
+    assert_eq!(dbg!(result), dbg!(expected));
+
+    let outside = Trivector { e013: 2., ..X_2 };
+    assert_eq!(view_box.clip_segment(X_2, outside), None);
+  }
+
+  #[test]
+  fn clip_line() {
+    let view_box = view_box();
+    let (a, b) = view_box.clip_line(ORIGIN.join(X_2)).unwrap();
+    let mut xs = [a[0], b[0]];
+    xs.sort_by(f32::total_cmp);
+    assert_eq!(dbg!(xs), [-1., 1.]);
+    assert_eq!([a[1], a[2], b[1], b[2]], [0.; 4]);
+
+    let outside = Trivector { e013: 2., ..X_2 };
+    assert_eq!(view_box.clip_line(X_2.join(outside)), None);
+  }
+
+  #[test]
+  fn clip_plane() {
+    let view_box = view_box();
+    let result = view_box.clip_plane(PLANE_Z);
+    let expected = Some(vec![
+      [-1., -1., 0.], [1., -1., 0.], [1., 1., 0.], [-1., 1., 0.],
+    ]);
+    assert_eq!(dbg!(result), dbg!(expected));
+
+    // a plane through a corner of the box cuts off a triangle
+    let plane = Vector { e0: -2.5, e1: 1., e2: 1., e3: 1. };
+    assert_eq!(view_box.clip_plane(plane).map(|p| p.len()), Some(3));
+
+    // a plane through the centre, perpendicular to a diagonal, is a hexagon
+    let plane = Vector { e0: 0., e1: 1., e2: 1., e3: 1. };
+    assert_eq!(view_box.clip_plane(plane).map(|p| p.len()), Some(6));
+
+    let plane = Vector { e0: -2., ..PLANE_Z };
+    assert_eq!(view_box.clip_plane(plane), None);
+  }
+
+  #[test]
+  fn svg() {
+    let mut scene = SvgScene::with_options(SvgOptions {
+      width: 100.,
+      height: 100.,
+      ..SvgOptions::default()
+    });
+    scene
+      .plane(PLANE_Z, 0x808080, "")
+      .segment(ORIGIN, X_2, 0x0000ff, "")
+      .point(ORIGIN, 0xff0000, "<origin>")
+      .point(X_2, 0xff0000, "outside");
+    let result = scene.to_svg();
+    let expected = "\
+      <svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"100\" viewBox=\"0 0 100 100\">\n\
+      <polygon points=\"0.00,100.00 100.00,100.00 100.00,0.00 0.00,0.00\" fill=\"#808080\" fill-opacity=\"0.25\" stroke=\"#808080\" stroke-width=\"1\"/>\n\
+      <line x1=\"50.00\" y1=\"50.00\" x2=\"100.00\" y2=\"50.00\" stroke=\"#0000ff\" stroke-width=\"1\"/>\n\
+      <circle cx=\"50.00\" cy=\"50.00\" r=\"3\" fill=\"#ff0000\"/>\n\
+      <text x=\"55.00\" y=\"45.00\" font-size=\"12\" fill=\"#ff0000\">&lt;origin&gt;</text>\n\
+      </svg>\n";
+    assert_eq!(result, expected);
+  }
+
+  #[test]
+  fn side_view() {
+    // looking along +y, x is to the right & z is up
+    let mut scene = SvgScene::with_options(SvgOptions {
+      view_plane: Vector { e0: 0., e1: 0., e2: -1., e3: 0. },
+      width: 100.,
+      height: 100.,
+      ..SvgOptions::default()
+    });
+    let up = Trivector { e123: 1., e032: 0., e013: 0., e021: 1. };
+    scene.segment(ORIGIN, up, 0, "");
+    let result = scene.to_svg();
+    assert!(result.contains("x1=\"50.00\" y1=\"50.00\" x2=\"50.00\" y2=\"0.00\""), "{result}");
+  }
+}
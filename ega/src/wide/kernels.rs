@@ -0,0 +1,4905 @@
+//! Lane-wise kernels for the operators on the wide types
+//!
+//! Like the scalar operators, each kernel is written out component by
+//! component, but over whole lane arrays:
+//! `out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i]` for `i in 0..N`, so
+//! every load & store is of contiguous `f32`s.
+
+use super::*;
+
+/// Implement a binary operator with a kernel
+macro_rules! impl_binary {
+  (
+    $trait:ident::$method:ident {
+      $($fn:ident: $lhs:ty, $rhs:ty => $output:ty;)+
+    }
+  ) => {
+    $(
+      impl<const N: usize> $trait<$rhs> for $lhs {
+        type Output = $output;
+
+        #[inline]
+        fn $method(self, rhs: $rhs) -> $output {
+          $fn(self, rhs)
+        }
+      }
+    )+
+  };
+}
+
+/// Implement `Mul` as the geometric product
+macro_rules! impl_mul {
+  ($($lhs:ty, $rhs:ty => $output:ty;)+) => {
+    $(
+      impl<const N: usize> Mul<$rhs> for $lhs {
+        type Output = $output;
+
+        /// The `GeometricProduct`
+        #[inline]
+        fn mul(self, rhs: $rhs) -> $output {
+          self.geometric_product(rhs)
+        }
+      }
+    )+
+  };
+}
+
+/// Implement a unary operator which negates the listed components
+macro_rules! impl_negate_components {
+  ($trait:ident::$method:ident: $wide:ident {}) => {
+    impl<const N: usize> $trait for $wide<N> {
+      #[inline]
+      fn $method(self) -> Self {
+        self
+      }
+    }
+  };
+  ($trait:ident::$method:ident: $wide:ident { $($field:ident),+ }) => {
+    impl<const N: usize> $trait for $wide<N> {
+      #[inline]
+      fn $method(self) -> Self {
+        let mut out = self;
+        for i in 0..N {
+          $(out.$field[i] = -self.$field[i];)+
+        }
+        out
+      }
+    }
+  };
+}
+
+/// Implement `Neg`, negating every component
+macro_rules! impl_neg {
+  ($wide:ident { $($field:ident),+ }) => {
+    impl<const N: usize> Neg for $wide<N> {
+      type Output = Self;
+
+      #[inline]
+      fn neg(self) -> Self {
+        let mut out = self;
+        for i in 0..N {
+          $(out.$field[i] = -self.$field[i];)+
+        }
+        out
+      }
+    }
+  };
+}
+
+/// return `Empty`
+#[inline]
+fn return_empty<Lhs, Rhs>(_: Lhs, _: Rhs) -> Empty {
+  Empty
+}
+
+/// return the left-hand-side
+#[inline]
+fn return_lhs<Lhs, Rhs>(lhs: Lhs, _: Rhs) -> Lhs {
+  lhs
+}
+
+// Geometric product
+
+impl_binary! { GeometricProduct::geometric_product {
+  multivector_mul_multivector: MultivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  multivector_mul_scalar: MultivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  multivector_mul_vector: MultivectorX<N>, VectorX<N> => MultivectorX<N>;
+  multivector_mul_bivector: MultivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  multivector_mul_trivector: MultivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  multivector_mul_pseudoscalar: MultivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_empty: MultivectorX<N>, Empty => Empty;
+  scalar_mul_multivector: ScalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  scalar_mul_scalar: ScalarX<N>, ScalarX<N> => ScalarX<N>;
+  scalar_mul_vector: ScalarX<N>, VectorX<N> => VectorX<N>;
+  scalar_mul_bivector: ScalarX<N>, BivectorX<N> => BivectorX<N>;
+  scalar_mul_trivector: ScalarX<N>, TrivectorX<N> => TrivectorX<N>;
+  scalar_mul_pseudoscalar: ScalarX<N>, PseudoscalarX<N> => PseudoscalarX<N>;
+  return_empty: ScalarX<N>, Empty => Empty;
+  vector_mul_multivector: VectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  vector_mul_scalar: VectorX<N>, ScalarX<N> => VectorX<N>;
+  vector_mul_vector: VectorX<N>, VectorX<N> => MultivectorX<N>;
+  vector_mul_bivector: VectorX<N>, BivectorX<N> => MultivectorX<N>;
+  vector_mul_trivector: VectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  vector_mul_pseudoscalar: VectorX<N>, PseudoscalarX<N> => TrivectorX<N>;
+  return_empty: VectorX<N>, Empty => Empty;
+  bivector_mul_multivector: BivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  bivector_mul_scalar: BivectorX<N>, ScalarX<N> => BivectorX<N>;
+  bivector_mul_vector: BivectorX<N>, VectorX<N> => MultivectorX<N>;
+  bivector_mul_bivector: BivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  bivector_mul_trivector: BivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  bivector_mul_pseudoscalar: BivectorX<N>, PseudoscalarX<N> => BivectorX<N>;
+  return_empty: BivectorX<N>, Empty => Empty;
+  trivector_mul_multivector: TrivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  trivector_mul_scalar: TrivectorX<N>, ScalarX<N> => TrivectorX<N>;
+  trivector_mul_vector: TrivectorX<N>, VectorX<N> => MultivectorX<N>;
+  trivector_mul_bivector: TrivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  trivector_mul_trivector: TrivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  trivector_mul_pseudoscalar: TrivectorX<N>, PseudoscalarX<N> => VectorX<N>;
+  return_empty: TrivectorX<N>, Empty => Empty;
+  pseudoscalar_mul_multivector: PseudoscalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  pseudoscalar_mul_scalar: PseudoscalarX<N>, ScalarX<N> => PseudoscalarX<N>;
+  pseudoscalar_mul_vector: PseudoscalarX<N>, VectorX<N> => TrivectorX<N>;
+  pseudoscalar_mul_bivector: PseudoscalarX<N>, BivectorX<N> => BivectorX<N>;
+  pseudoscalar_mul_trivector: PseudoscalarX<N>, TrivectorX<N> => VectorX<N>;
+  return_empty: PseudoscalarX<N>, PseudoscalarX<N> => Empty;
+  return_empty: PseudoscalarX<N>, Empty => Empty;
+  motor_mul_motor: MotorX<N>, MotorX<N> => MotorX<N>;
+} }
+
+impl_mul! {
+  MultivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  MultivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  MultivectorX<N>, VectorX<N> => MultivectorX<N>;
+  MultivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  MultivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  MultivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  MultivectorX<N>, Empty => Empty;
+  ScalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  ScalarX<N>, ScalarX<N> => ScalarX<N>;
+  ScalarX<N>, VectorX<N> => VectorX<N>;
+  ScalarX<N>, BivectorX<N> => BivectorX<N>;
+  ScalarX<N>, TrivectorX<N> => TrivectorX<N>;
+  ScalarX<N>, PseudoscalarX<N> => PseudoscalarX<N>;
+  ScalarX<N>, Empty => Empty;
+  VectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  VectorX<N>, ScalarX<N> => VectorX<N>;
+  VectorX<N>, VectorX<N> => MultivectorX<N>;
+  VectorX<N>, BivectorX<N> => MultivectorX<N>;
+  VectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  VectorX<N>, PseudoscalarX<N> => TrivectorX<N>;
+  VectorX<N>, Empty => Empty;
+  BivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  BivectorX<N>, ScalarX<N> => BivectorX<N>;
+  BivectorX<N>, VectorX<N> => MultivectorX<N>;
+  BivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  BivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  BivectorX<N>, PseudoscalarX<N> => BivectorX<N>;
+  BivectorX<N>, Empty => Empty;
+  TrivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  TrivectorX<N>, ScalarX<N> => TrivectorX<N>;
+  TrivectorX<N>, VectorX<N> => MultivectorX<N>;
+  TrivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  TrivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  TrivectorX<N>, PseudoscalarX<N> => VectorX<N>;
+  TrivectorX<N>, Empty => Empty;
+  PseudoscalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  PseudoscalarX<N>, ScalarX<N> => PseudoscalarX<N>;
+  PseudoscalarX<N>, VectorX<N> => TrivectorX<N>;
+  PseudoscalarX<N>, BivectorX<N> => BivectorX<N>;
+  PseudoscalarX<N>, TrivectorX<N> => VectorX<N>;
+  PseudoscalarX<N>, PseudoscalarX<N> => Empty;
+  PseudoscalarX<N>, Empty => Empty;
+  MotorX<N>, MotorX<N> => MotorX<N>;
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_mul_multivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i] - a.e1[i]*b.e01[i] - a.e2[i]*b.e02[i]
+              - a.e3[i]*b.e03[i] + a.s[i]*b.e0[i] + a.e23[i]*b.e032[i]
+              + a.e31[i]*b.e013[i] + a.e12[i]*b.e021[i] + a.e01[i]*b.e1[i]
+              + a.e02[i]*b.e2[i] + a.e03[i]*b.e3[i] - a.e0123[i]*b.e123[i]
+              + a.e123[i]*b.e0123[i] + a.e032[i]*b.e23[i] + a.e013[i]*b.e31[i]
+              + a.e021[i]*b.e12[i];
+    out.e1[i] = a.e1[i]*b.s[i] - a.e2[i]*b.e12[i] + a.e3[i]*b.e31[i]
+              + a.s[i]*b.e1[i] - a.e23[i]*b.e123[i] - a.e31[i]*b.e3[i]
+              + a.e12[i]*b.e2[i] - a.e123[i]*b.e23[i];
+    out.e2[i] = a.e1[i]*b.e12[i] + a.e2[i]*b.s[i] - a.e3[i]*b.e23[i]
+              + a.s[i]*b.e2[i] + a.e23[i]*b.e3[i] - a.e31[i]*b.e123[i]
+              - a.e12[i]*b.e1[i] - a.e123[i]*b.e31[i];
+    out.e3[i] = a.e2[i]*b.e23[i] + a.e3[i]*b.s[i] + a.s[i]*b.e3[i]
+              + a.e31[i]*b.e1[i] - a.e1[i]*b.e31[i] - a.e23[i]*b.e2[i]
+              - a.e12[i]*b.e123[i] - a.e123[i]*b.e12[i];
+    out.s[i] = a.e1[i]*b.e1[i] + a.e2[i]*b.e2[i] + a.e3[i]*b.e3[i]
+             + a.s[i]*b.s[i] - a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i]
+             - a.e12[i]*b.e12[i] - a.e123[i]*b.e123[i];
+    out.e23[i] = a.e1[i]*b.e123[i] + a.e2[i]*b.e3[i] - a.e3[i]*b.e2[i]
+               + a.s[i]*b.e23[i] + a.e23[i]*b.s[i] - a.e31[i]*b.e12[i]
+               + a.e12[i]*b.e31[i] + a.e123[i]*b.e1[i];
+    out.e31[i] = a.e2[i]*b.e123[i] + a.e3[i]*b.e1[i] + a.s[i]*b.e31[i]
+               + a.e23[i]*b.e12[i] + a.e31[i]*b.s[i] + a.e123[i]*b.e2[i]
+               - a.e1[i]*b.e3[i] - a.e12[i]*b.e23[i];
+    out.e12[i] = a.e1[i]*b.e2[i] - a.e2[i]*b.e1[i] + a.e3[i]*b.e123[i]
+               + a.s[i]*b.e12[i] - a.e23[i]*b.e31[i] + a.e31[i]*b.e23[i]
+               + a.e12[i]*b.s[i] + a.e123[i]*b.e3[i];
+    out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i] - a.e2[i]*b.e021[i]
+               + a.e3[i]*b.e013[i] + a.s[i]*b.e01[i] - a.e23[i]*b.e0123[i]
+               - a.e31[i]*b.e03[i] + a.e12[i]*b.e02[i] + a.e01[i]*b.s[i]
+               - a.e02[i]*b.e12[i] + a.e03[i]*b.e31[i] - a.e0123[i]*b.e23[i]
+               - a.e123[i]*b.e032[i] + a.e032[i]*b.e123[i] + a.e013[i]*b.e3[i]
+               - a.e021[i]*b.e2[i];
+    out.e02[i] = a.e0[i]*b.e2[i] + a.e1[i]*b.e021[i] - a.e2[i]*b.e0[i]
+               - a.e3[i]*b.e032[i] + a.s[i]*b.e02[i] + a.e23[i]*b.e03[i]
+               - a.e31[i]*b.e0123[i] - a.e12[i]*b.e01[i] + a.e01[i]*b.e12[i]
+               + a.e02[i]*b.s[i] - a.e03[i]*b.e23[i] - a.e0123[i]*b.e31[i]
+               - a.e123[i]*b.e013[i] - a.e032[i]*b.e3[i] + a.e013[i]*b.e123[i]
+               + a.e021[i]*b.e1[i];
+    out.e03[i] = a.e0[i]*b.e3[i] - a.e1[i]*b.e013[i] + a.e2[i]*b.e032[i]
+               - a.e3[i]*b.e0[i] + a.s[i]*b.e03[i] - a.e23[i]*b.e02[i]
+               + a.e31[i]*b.e01[i] - a.e12[i]*b.e0123[i] - a.e01[i]*b.e31[i]
+               + a.e02[i]*b.e23[i] + a.e03[i]*b.s[i] - a.e0123[i]*b.e12[i]
+               - a.e123[i]*b.e021[i] + a.e032[i]*b.e2[i] - a.e013[i]*b.e1[i]
+               + a.e021[i]*b.e123[i];
+    out.e0123[i] = a.e0[i]*b.e123[i] + a.e1[i]*b.e032[i] + a.e2[i]*b.e013[i]
+                 + a.e3[i]*b.e021[i] + a.s[i]*b.e0123[i] + a.e23[i]*b.e01[i]
+                 + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i] + a.e01[i]*b.e23[i]
+                 + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i] + a.e0123[i]*b.s[i]
+                 - a.e123[i]*b.e0[i] - a.e032[i]*b.e1[i] - a.e013[i]*b.e2[i]
+                 - a.e021[i]*b.e3[i];
+    out.e123[i] = a.e1[i]*b.e23[i] + a.e2[i]*b.e31[i] + a.e3[i]*b.e12[i]
+                + a.s[i]*b.e123[i] + a.e23[i]*b.e1[i] + a.e31[i]*b.e2[i]
+                + a.e12[i]*b.e3[i] + a.e123[i]*b.s[i];
+    out.e032[i] = a.e1[i]*b.e0123[i] + a.e2[i]*b.e03[i] + a.s[i]*b.e032[i]
+                + a.e12[i]*b.e013[i] + a.e03[i]*b.e2[i] + a.e123[i]*b.e01[i]
+                + a.e032[i]*b.s[i] + a.e021[i]*b.e31[i] - a.e0[i]*b.e23[i]
+                - a.e3[i]*b.e02[i] - a.e23[i]*b.e0[i] - a.e31[i]*b.e021[i]
+                - a.e01[i]*b.e123[i] - a.e02[i]*b.e3[i] - a.e0123[i]*b.e1[i]
+                - a.e013[i]*b.e12[i];
+    out.e013[i] = a.e2[i]*b.e0123[i] + a.e3[i]*b.e01[i] + a.s[i]*b.e013[i]
+                + a.e23[i]*b.e021[i] + a.e01[i]*b.e3[i] + a.e123[i]*b.e02[i]
+                + a.e032[i]*b.e12[i] + a.e013[i]*b.s[i] - a.e0[i]*b.e31[i]
+                - a.e1[i]*b.e03[i] - a.e31[i]*b.e0[i] - a.e12[i]*b.e032[i]
+                - a.e02[i]*b.e123[i] - a.e03[i]*b.e1[i] - a.e0123[i]*b.e2[i]
+                - a.e021[i]*b.e23[i];
+    out.e021[i] = a.e1[i]*b.e02[i] + a.e3[i]*b.e0123[i] + a.s[i]*b.e021[i]
+                + a.e31[i]*b.e032[i] + a.e02[i]*b.e1[i] + a.e123[i]*b.e03[i]
+                + a.e013[i]*b.e23[i] + a.e021[i]*b.s[i] - a.e0[i]*b.e12[i]
+                - a.e2[i]*b.e01[i] - a.e23[i]*b.e013[i] - a.e12[i]*b.e0[i]
+                - a.e01[i]*b.e2[i] - a.e03[i]*b.e123[i] - a.e0123[i]*b.e3[i]
+                - a.e032[i]*b.e31[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_mul_scalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i];
+    out.e1[i] = a.e1[i]*b.s[i];
+    out.e2[i] = a.e2[i]*b.s[i];
+    out.e3[i] = a.e3[i]*b.s[i];
+    out.s[i] = a.s[i]*b.s[i];
+    out.e23[i] = a.e23[i]*b.s[i];
+    out.e31[i] = a.e31[i]*b.s[i];
+    out.e12[i] = a.e12[i]*b.s[i];
+    out.e01[i] = a.e01[i]*b.s[i];
+    out.e02[i] = a.e02[i]*b.s[i];
+    out.e03[i] = a.e03[i]*b.s[i];
+    out.e0123[i] = a.e0123[i]*b.s[i];
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e032[i]*b.s[i];
+    out.e013[i] = a.e013[i]*b.s[i];
+    out.e021[i] = a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_mul_vector<const N: usize>(
+  a: MultivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i] + a.e01[i]*b.e1[i] + a.e02[i]*b.e2[i]
+              + a.e03[i]*b.e3[i];
+    out.e1[i] = a.s[i]*b.e1[i] - a.e31[i]*b.e3[i] + a.e12[i]*b.e2[i];
+    out.e2[i] = a.s[i]*b.e2[i] + a.e23[i]*b.e3[i] - a.e12[i]*b.e1[i];
+    out.e3[i] = a.s[i]*b.e3[i] - a.e23[i]*b.e2[i] + a.e31[i]*b.e1[i];
+    out.s[i] = a.e1[i]*b.e1[i] + a.e2[i]*b.e2[i] + a.e3[i]*b.e3[i];
+    out.e23[i] = a.e2[i]*b.e3[i] - a.e3[i]*b.e2[i] + a.e123[i]*b.e1[i];
+    out.e31[i] = a.e3[i]*b.e1[i] + a.e123[i]*b.e2[i] - a.e1[i]*b.e3[i];
+    out.e12[i] = a.e1[i]*b.e2[i] - a.e2[i]*b.e1[i] + a.e123[i]*b.e3[i];
+    out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i] + a.e013[i]*b.e3[i]
+               - a.e021[i]*b.e2[i];
+    out.e02[i] = a.e0[i]*b.e2[i] - a.e2[i]*b.e0[i] - a.e032[i]*b.e3[i]
+               + a.e021[i]*b.e1[i];
+    out.e03[i] = a.e0[i]*b.e3[i] - a.e3[i]*b.e0[i] + a.e032[i]*b.e2[i]
+               - a.e013[i]*b.e1[i];
+    out.e0123[i] = -a.e123[i]*b.e0[i] - a.e032[i]*b.e1[i] - a.e013[i]*b.e2[i]
+                 - a.e021[i]*b.e3[i];
+    out.e123[i] = a.e23[i]*b.e1[i] + a.e31[i]*b.e2[i] + a.e12[i]*b.e3[i];
+    out.e032[i] = a.e03[i]*b.e2[i] - a.e23[i]*b.e0[i] - a.e02[i]*b.e3[i]
+                - a.e0123[i]*b.e1[i];
+    out.e013[i] = a.e01[i]*b.e3[i] - a.e31[i]*b.e0[i] - a.e03[i]*b.e1[i]
+                - a.e0123[i]*b.e2[i];
+    out.e021[i] = a.e02[i]*b.e1[i] - a.e12[i]*b.e0[i] - a.e01[i]*b.e2[i]
+                - a.e0123[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_mul_bivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e032[i]*b.e23[i] + a.e013[i]*b.e31[i] + a.e021[i]*b.e12[i]
+              - a.e1[i]*b.e01[i] - a.e2[i]*b.e02[i] - a.e3[i]*b.e03[i];
+    out.e1[i] = a.e3[i]*b.e31[i] - a.e2[i]*b.e12[i] - a.e123[i]*b.e23[i];
+    out.e2[i] = a.e1[i]*b.e12[i] - a.e3[i]*b.e23[i] - a.e123[i]*b.e31[i];
+    out.e3[i] = a.e2[i]*b.e23[i] - a.e1[i]*b.e31[i] - a.e123[i]*b.e12[i];
+    out.s[i] = -a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i] - a.e12[i]*b.e12[i];
+    out.e23[i] = a.s[i]*b.e23[i] - a.e31[i]*b.e12[i] + a.e12[i]*b.e31[i];
+    out.e31[i] = a.s[i]*b.e31[i] + a.e23[i]*b.e12[i] - a.e12[i]*b.e23[i];
+    out.e12[i] = a.s[i]*b.e12[i] - a.e23[i]*b.e31[i] + a.e31[i]*b.e23[i];
+    out.e01[i] = a.s[i]*b.e01[i] - a.e31[i]*b.e03[i] + a.e12[i]*b.e02[i]
+               - a.e02[i]*b.e12[i] + a.e03[i]*b.e31[i] - a.e0123[i]*b.e23[i];
+    out.e02[i] = a.s[i]*b.e02[i] + a.e23[i]*b.e03[i] - a.e12[i]*b.e01[i]
+               + a.e01[i]*b.e12[i] - a.e03[i]*b.e23[i] - a.e0123[i]*b.e31[i];
+    out.e03[i] = a.s[i]*b.e03[i] - a.e23[i]*b.e02[i] + a.e31[i]*b.e01[i]
+               - a.e01[i]*b.e31[i] + a.e02[i]*b.e23[i] - a.e0123[i]*b.e12[i];
+    out.e0123[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+                 + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+    out.e123[i] = a.e1[i]*b.e23[i] + a.e2[i]*b.e31[i] + a.e3[i]*b.e12[i];
+    out.e032[i] = a.e2[i]*b.e03[i] + a.e123[i]*b.e01[i] + a.e021[i]*b.e31[i]
+                - a.e0[i]*b.e23[i] - a.e3[i]*b.e02[i] - a.e013[i]*b.e12[i];
+    out.e013[i] = a.e3[i]*b.e01[i] + a.e123[i]*b.e02[i] + a.e032[i]*b.e12[i]
+                - a.e0[i]*b.e31[i] - a.e1[i]*b.e03[i] - a.e021[i]*b.e23[i];
+    out.e021[i] = a.e1[i]*b.e02[i] + a.e123[i]*b.e03[i] + a.e013[i]*b.e23[i]
+                - a.e0[i]*b.e12[i] - a.e2[i]*b.e01[i] - a.e032[i]*b.e31[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_mul_trivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e23[i]*b.e032[i] + a.e31[i]*b.e013[i] + a.e12[i]*b.e021[i]
+              - a.e0123[i]*b.e123[i];
+    out.e1[i] = -a.e23[i]*b.e123[i];
+    out.e2[i] = -a.e31[i]*b.e123[i];
+    out.e3[i] = -a.e12[i]*b.e123[i];
+    out.s[i] = -a.e123[i]*b.e123[i];
+    out.e23[i] = a.e1[i]*b.e123[i];
+    out.e31[i] = a.e2[i]*b.e123[i];
+    out.e12[i] = a.e3[i]*b.e123[i];
+    out.e01[i] = a.e3[i]*b.e013[i] + a.e032[i]*b.e123[i] - a.e2[i]*b.e021[i]
+               - a.e123[i]*b.e032[i];
+    out.e02[i] = a.e1[i]*b.e021[i] - a.e3[i]*b.e032[i] - a.e123[i]*b.e013[i]
+               + a.e013[i]*b.e123[i];
+    out.e03[i] = a.e2[i]*b.e032[i] + a.e021[i]*b.e123[i] - a.e1[i]*b.e013[i]
+               - a.e123[i]*b.e021[i];
+    out.e0123[i] = a.e0[i]*b.e123[i] + a.e1[i]*b.e032[i] + a.e2[i]*b.e013[i]
+                 + a.e3[i]*b.e021[i];
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i] - a.e31[i]*b.e021[i] + a.e12[i]*b.e013[i]
+                - a.e01[i]*b.e123[i];
+    out.e013[i] = a.s[i]*b.e013[i] + a.e23[i]*b.e021[i] - a.e12[i]*b.e032[i]
+                - a.e02[i]*b.e123[i];
+    out.e021[i] = a.s[i]*b.e021[i] - a.e23[i]*b.e013[i] + a.e31[i]*b.e032[i]
+                - a.e03[i]*b.e123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_mul_pseudoscalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e123[i]*b.e0123[i];
+    out.e01[i] = -a.e23[i]*b.e0123[i];
+    out.e02[i] = -a.e31[i]*b.e0123[i];
+    out.e03[i] = -a.e12[i]*b.e0123[i];
+    out.e0123[i] = a.s[i]*b.e0123[i];
+    out.e032[i] = a.e1[i]*b.e0123[i];
+    out.e013[i] = a.e2[i]*b.e0123[i];
+    out.e021[i] = a.e3[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_mul_multivector<const N: usize>(
+  a: ScalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i];
+    out.e1[i] = a.s[i]*b.e1[i];
+    out.e2[i] = a.s[i]*b.e2[i];
+    out.e3[i] = a.s[i]*b.e3[i];
+    out.s[i] = a.s[i]*b.s[i];
+    out.e23[i] = a.s[i]*b.e23[i];
+    out.e31[i] = a.s[i]*b.e31[i];
+    out.e12[i] = a.s[i]*b.e12[i];
+    out.e01[i] = a.s[i]*b.e01[i];
+    out.e02[i] = a.s[i]*b.e02[i];
+    out.e03[i] = a.s[i]*b.e03[i];
+    out.e0123[i] = a.s[i]*b.e0123[i];
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i];
+    out.e013[i] = a.s[i]*b.e013[i];
+    out.e021[i] = a.s[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_mul_scalar<const N: usize>(
+  a: ScalarX<N>,
+  b: ScalarX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_mul_vector<const N: usize>(
+  a: ScalarX<N>,
+  b: VectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i];
+    out.e1[i] = a.s[i]*b.e1[i];
+    out.e2[i] = a.s[i]*b.e2[i];
+    out.e3[i] = a.s[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_mul_bivector<const N: usize>(
+  a: ScalarX<N>,
+  b: BivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.s[i]*b.e23[i];
+    out.e31[i] = a.s[i]*b.e31[i];
+    out.e12[i] = a.s[i]*b.e12[i];
+    out.e01[i] = a.s[i]*b.e01[i];
+    out.e02[i] = a.s[i]*b.e02[i];
+    out.e03[i] = a.s[i]*b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_mul_trivector<const N: usize>(
+  a: ScalarX<N>,
+  b: TrivectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i];
+    out.e013[i] = a.s[i]*b.e013[i];
+    out.e021[i] = a.s[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_mul_pseudoscalar<const N: usize>(
+  a: ScalarX<N>,
+  b: PseudoscalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.s[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_mul_multivector<const N: usize>(
+  a: VectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i] - a.e1[i]*b.e01[i] - a.e2[i]*b.e02[i]
+              - a.e3[i]*b.e03[i];
+    out.e1[i] = a.e1[i]*b.s[i] - a.e2[i]*b.e12[i] + a.e3[i]*b.e31[i];
+    out.e2[i] = a.e1[i]*b.e12[i] + a.e2[i]*b.s[i] - a.e3[i]*b.e23[i];
+    out.e3[i] = a.e2[i]*b.e23[i] + a.e3[i]*b.s[i] - a.e1[i]*b.e31[i];
+    out.s[i] = a.e1[i]*b.e1[i] + a.e2[i]*b.e2[i] + a.e3[i]*b.e3[i];
+    out.e23[i] = a.e1[i]*b.e123[i] + a.e2[i]*b.e3[i] - a.e3[i]*b.e2[i];
+    out.e31[i] = a.e2[i]*b.e123[i] + a.e3[i]*b.e1[i] - a.e1[i]*b.e3[i];
+    out.e12[i] = a.e1[i]*b.e2[i] - a.e2[i]*b.e1[i] + a.e3[i]*b.e123[i];
+    out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i] - a.e2[i]*b.e021[i]
+               + a.e3[i]*b.e013[i];
+    out.e02[i] = a.e0[i]*b.e2[i] + a.e1[i]*b.e021[i] - a.e2[i]*b.e0[i]
+               - a.e3[i]*b.e032[i];
+    out.e03[i] = a.e0[i]*b.e3[i] - a.e1[i]*b.e013[i] + a.e2[i]*b.e032[i]
+               - a.e3[i]*b.e0[i];
+    out.e0123[i] = a.e0[i]*b.e123[i] + a.e1[i]*b.e032[i] + a.e2[i]*b.e013[i]
+                 + a.e3[i]*b.e021[i];
+    out.e123[i] = a.e1[i]*b.e23[i] + a.e2[i]*b.e31[i] + a.e3[i]*b.e12[i];
+    out.e032[i] = a.e1[i]*b.e0123[i] + a.e2[i]*b.e03[i] - a.e0[i]*b.e23[i]
+                - a.e3[i]*b.e02[i];
+    out.e013[i] = a.e2[i]*b.e0123[i] + a.e3[i]*b.e01[i] - a.e0[i]*b.e31[i]
+                - a.e1[i]*b.e03[i];
+    out.e021[i] = a.e1[i]*b.e02[i] + a.e3[i]*b.e0123[i] - a.e0[i]*b.e12[i]
+                - a.e2[i]*b.e01[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_mul_scalar<const N: usize>(
+  a: VectorX<N>,
+  b: ScalarX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i];
+    out.e1[i] = a.e1[i]*b.s[i];
+    out.e2[i] = a.e2[i]*b.s[i];
+    out.e3[i] = a.e3[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_mul_vector<const N: usize>(
+  a: VectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = a.e1[i]*b.e1[i] + a.e2[i]*b.e2[i] + a.e3[i]*b.e3[i];
+    out.e23[i] = a.e2[i]*b.e3[i] - a.e3[i]*b.e2[i];
+    out.e31[i] = a.e3[i]*b.e1[i] - a.e1[i]*b.e3[i];
+    out.e12[i] = a.e1[i]*b.e2[i] - a.e2[i]*b.e1[i];
+    out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i];
+    out.e02[i] = a.e0[i]*b.e2[i] - a.e2[i]*b.e0[i];
+    out.e03[i] = a.e0[i]*b.e3[i] - a.e3[i]*b.e0[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_mul_bivector<const N: usize>(
+  a: VectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e1[i]*b.e01[i] - a.e2[i]*b.e02[i] - a.e3[i]*b.e03[i];
+    out.e1[i] = a.e3[i]*b.e31[i] - a.e2[i]*b.e12[i];
+    out.e2[i] = a.e1[i]*b.e12[i] - a.e3[i]*b.e23[i];
+    out.e3[i] = a.e2[i]*b.e23[i] - a.e1[i]*b.e31[i];
+    out.e123[i] = a.e1[i]*b.e23[i] + a.e2[i]*b.e31[i] + a.e3[i]*b.e12[i];
+    out.e032[i] = a.e2[i]*b.e03[i] - a.e0[i]*b.e23[i] - a.e3[i]*b.e02[i];
+    out.e013[i] = a.e3[i]*b.e01[i] - a.e0[i]*b.e31[i] - a.e1[i]*b.e03[i];
+    out.e021[i] = a.e1[i]*b.e02[i] - a.e0[i]*b.e12[i] - a.e2[i]*b.e01[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_mul_trivector<const N: usize>(
+  a: VectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e1[i]*b.e123[i];
+    out.e31[i] = a.e2[i]*b.e123[i];
+    out.e12[i] = a.e3[i]*b.e123[i];
+    out.e01[i] = a.e3[i]*b.e013[i] - a.e2[i]*b.e021[i];
+    out.e02[i] = a.e1[i]*b.e021[i] - a.e3[i]*b.e032[i];
+    out.e03[i] = a.e2[i]*b.e032[i] - a.e1[i]*b.e013[i];
+    out.e0123[i] = a.e0[i]*b.e123[i] + a.e1[i]*b.e032[i] + a.e2[i]*b.e013[i]
+                 + a.e3[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_mul_pseudoscalar<const N: usize>(
+  a: VectorX<N>,
+  b: PseudoscalarX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e032[i] = a.e1[i]*b.e0123[i];
+    out.e013[i] = a.e2[i]*b.e0123[i];
+    out.e021[i] = a.e3[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_mul_multivector<const N: usize>(
+  a: BivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e23[i]*b.e032[i] + a.e31[i]*b.e013[i] + a.e12[i]*b.e021[i]
+              + a.e01[i]*b.e1[i] + a.e02[i]*b.e2[i] + a.e03[i]*b.e3[i];
+    out.e1[i] = a.e12[i]*b.e2[i] - a.e23[i]*b.e123[i] - a.e31[i]*b.e3[i];
+    out.e2[i] = a.e23[i]*b.e3[i] - a.e31[i]*b.e123[i] - a.e12[i]*b.e1[i];
+    out.e3[i] = a.e31[i]*b.e1[i] - a.e23[i]*b.e2[i] - a.e12[i]*b.e123[i];
+    out.s[i] = -a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i] - a.e12[i]*b.e12[i];
+    out.e23[i] = a.e23[i]*b.s[i] - a.e31[i]*b.e12[i] + a.e12[i]*b.e31[i];
+    out.e31[i] = a.e23[i]*b.e12[i] + a.e31[i]*b.s[i] - a.e12[i]*b.e23[i];
+    out.e12[i] = a.e31[i]*b.e23[i] + a.e12[i]*b.s[i] - a.e23[i]*b.e31[i];
+    out.e01[i] = a.e12[i]*b.e02[i] + a.e01[i]*b.s[i] + a.e03[i]*b.e31[i]
+               - a.e23[i]*b.e0123[i] - a.e31[i]*b.e03[i] - a.e02[i]*b.e12[i];
+    out.e02[i] = a.e23[i]*b.e03[i] - a.e31[i]*b.e0123[i] - a.e12[i]*b.e01[i]
+               + a.e01[i]*b.e12[i] + a.e02[i]*b.s[i] - a.e03[i]*b.e23[i];
+    out.e03[i] = a.e31[i]*b.e01[i] + a.e02[i]*b.e23[i] + a.e03[i]*b.s[i]
+               - a.e23[i]*b.e02[i] - a.e12[i]*b.e0123[i] - a.e01[i]*b.e31[i];
+    out.e0123[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+                 + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+    out.e123[i] = a.e23[i]*b.e1[i] + a.e31[i]*b.e2[i] + a.e12[i]*b.e3[i];
+    out.e032[i] = a.e12[i]*b.e013[i] + a.e03[i]*b.e2[i] - a.e23[i]*b.e0[i]
+                - a.e31[i]*b.e021[i] - a.e01[i]*b.e123[i] - a.e02[i]*b.e3[i];
+    out.e013[i] = a.e23[i]*b.e021[i] - a.e31[i]*b.e0[i] - a.e12[i]*b.e032[i]
+                + a.e01[i]*b.e3[i] - a.e02[i]*b.e123[i] - a.e03[i]*b.e1[i];
+    out.e021[i] = a.e31[i]*b.e032[i] + a.e02[i]*b.e1[i] - a.e23[i]*b.e013[i]
+                - a.e12[i]*b.e0[i] - a.e01[i]*b.e2[i] - a.e03[i]*b.e123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_mul_scalar<const N: usize>(
+  a: BivectorX<N>,
+  b: ScalarX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i]*b.s[i];
+    out.e31[i] = a.e31[i]*b.s[i];
+    out.e12[i] = a.e12[i]*b.s[i];
+    out.e01[i] = a.e01[i]*b.s[i];
+    out.e02[i] = a.e02[i]*b.s[i];
+    out.e03[i] = a.e03[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_mul_vector<const N: usize>(
+  a: BivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e01[i]*b.e1[i] + a.e02[i]*b.e2[i] + a.e03[i]*b.e3[i];
+    out.e1[i] = a.e12[i]*b.e2[i] - a.e31[i]*b.e3[i];
+    out.e2[i] = a.e23[i]*b.e3[i] - a.e12[i]*b.e1[i];
+    out.e3[i] = a.e31[i]*b.e1[i] - a.e23[i]*b.e2[i];
+    out.e123[i] = a.e23[i]*b.e1[i] + a.e31[i]*b.e2[i] + a.e12[i]*b.e3[i];
+    out.e032[i] = a.e03[i]*b.e2[i] - a.e23[i]*b.e0[i] - a.e02[i]*b.e3[i];
+    out.e013[i] = a.e01[i]*b.e3[i] - a.e31[i]*b.e0[i] - a.e03[i]*b.e1[i];
+    out.e021[i] = a.e02[i]*b.e1[i] - a.e12[i]*b.e0[i] - a.e01[i]*b.e2[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_mul_bivector<const N: usize>(
+  a: BivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = -a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i] - a.e12[i]*b.e12[i];
+    out.e23[i] = a.e12[i]*b.e31[i] - a.e31[i]*b.e12[i];
+    out.e31[i] = a.e23[i]*b.e12[i] - a.e12[i]*b.e23[i];
+    out.e12[i] = a.e31[i]*b.e23[i] - a.e23[i]*b.e31[i];
+    out.e01[i] = a.e12[i]*b.e02[i] + a.e03[i]*b.e31[i] - a.e31[i]*b.e03[i]
+               - a.e02[i]*b.e12[i];
+    out.e02[i] = a.e23[i]*b.e03[i] - a.e12[i]*b.e01[i] + a.e01[i]*b.e12[i]
+               - a.e03[i]*b.e23[i];
+    out.e03[i] = a.e31[i]*b.e01[i] + a.e02[i]*b.e23[i] - a.e23[i]*b.e02[i]
+               - a.e01[i]*b.e31[i];
+    out.e0123[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+                 + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_mul_trivector<const N: usize>(
+  a: BivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e23[i]*b.e032[i] + a.e31[i]*b.e013[i] + a.e12[i]*b.e021[i];
+    out.e1[i] = -a.e23[i]*b.e123[i];
+    out.e2[i] = -a.e31[i]*b.e123[i];
+    out.e3[i] = -a.e12[i]*b.e123[i];
+    out.e032[i] = a.e12[i]*b.e013[i] - a.e31[i]*b.e021[i] - a.e01[i]*b.e123[i];
+    out.e013[i] = a.e23[i]*b.e021[i] - a.e12[i]*b.e032[i] - a.e02[i]*b.e123[i];
+    out.e021[i] = a.e31[i]*b.e032[i] - a.e23[i]*b.e013[i] - a.e03[i]*b.e123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_mul_pseudoscalar<const N: usize>(
+  a: BivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e01[i] = -a.e23[i]*b.e0123[i];
+    out.e02[i] = -a.e31[i]*b.e0123[i];
+    out.e03[i] = -a.e12[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_mul_multivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e123[i]*b.e0123[i] + a.e032[i]*b.e23[i] + a.e013[i]*b.e31[i]
+              + a.e021[i]*b.e12[i];
+    out.e1[i] = -a.e123[i]*b.e23[i];
+    out.e2[i] = -a.e123[i]*b.e31[i];
+    out.e3[i] = -a.e123[i]*b.e12[i];
+    out.s[i] = -a.e123[i]*b.e123[i];
+    out.e23[i] = a.e123[i]*b.e1[i];
+    out.e31[i] = a.e123[i]*b.e2[i];
+    out.e12[i] = a.e123[i]*b.e3[i];
+    out.e01[i] = a.e032[i]*b.e123[i] + a.e013[i]*b.e3[i] - a.e123[i]*b.e032[i]
+               - a.e021[i]*b.e2[i];
+    out.e02[i] = a.e013[i]*b.e123[i] + a.e021[i]*b.e1[i] - a.e123[i]*b.e013[i]
+               - a.e032[i]*b.e3[i];
+    out.e03[i] = a.e032[i]*b.e2[i] + a.e021[i]*b.e123[i] - a.e123[i]*b.e021[i]
+               - a.e013[i]*b.e1[i];
+    out.e0123[i] = -a.e123[i]*b.e0[i] - a.e032[i]*b.e1[i] - a.e013[i]*b.e2[i]
+                 - a.e021[i]*b.e3[i];
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e123[i]*b.e01[i] + a.e032[i]*b.s[i] - a.e013[i]*b.e12[i]
+                + a.e021[i]*b.e31[i];
+    out.e013[i] = a.e123[i]*b.e02[i] + a.e032[i]*b.e12[i] + a.e013[i]*b.s[i]
+                - a.e021[i]*b.e23[i];
+    out.e021[i] = a.e123[i]*b.e03[i] - a.e032[i]*b.e31[i] + a.e013[i]*b.e23[i]
+                + a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_mul_scalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: ScalarX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e032[i]*b.s[i];
+    out.e013[i] = a.e013[i]*b.s[i];
+    out.e021[i] = a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_mul_vector<const N: usize>(
+  a: TrivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e123[i]*b.e1[i];
+    out.e31[i] = a.e123[i]*b.e2[i];
+    out.e12[i] = a.e123[i]*b.e3[i];
+    out.e01[i] = a.e013[i]*b.e3[i] - a.e021[i]*b.e2[i];
+    out.e02[i] = a.e021[i]*b.e1[i] - a.e032[i]*b.e3[i];
+    out.e03[i] = a.e032[i]*b.e2[i] - a.e013[i]*b.e1[i];
+    out.e0123[i] = -a.e123[i]*b.e0[i] - a.e032[i]*b.e1[i] - a.e013[i]*b.e2[i]
+                 - a.e021[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_mul_bivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e032[i]*b.e23[i] + a.e013[i]*b.e31[i] + a.e021[i]*b.e12[i];
+    out.e1[i] = -a.e123[i]*b.e23[i];
+    out.e2[i] = -a.e123[i]*b.e31[i];
+    out.e3[i] = -a.e123[i]*b.e12[i];
+    out.e032[i] = a.e123[i]*b.e01[i] - a.e013[i]*b.e12[i] + a.e021[i]*b.e31[i];
+    out.e013[i] = a.e123[i]*b.e02[i] + a.e032[i]*b.e12[i] - a.e021[i]*b.e23[i];
+    out.e021[i] = a.e123[i]*b.e03[i] - a.e032[i]*b.e31[i] + a.e013[i]*b.e23[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_mul_trivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = -a.e123[i]*b.e123[i];
+    out.e01[i] = a.e032[i]*b.e123[i] - a.e123[i]*b.e032[i];
+    out.e02[i] = a.e013[i]*b.e123[i] - a.e123[i]*b.e013[i];
+    out.e03[i] = a.e021[i]*b.e123[i] - a.e123[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_mul_pseudoscalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e123[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_mul_multivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e0123[i]*b.e123[i];
+    out.e01[i] = -a.e0123[i]*b.e23[i];
+    out.e02[i] = -a.e0123[i]*b.e31[i];
+    out.e03[i] = -a.e0123[i]*b.e12[i];
+    out.e0123[i] = a.e0123[i]*b.s[i];
+    out.e032[i] = -a.e0123[i]*b.e1[i];
+    out.e013[i] = -a.e0123[i]*b.e2[i];
+    out.e021[i] = -a.e0123[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_mul_scalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: ScalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_mul_vector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: VectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e032[i] = -a.e0123[i]*b.e1[i];
+    out.e013[i] = -a.e0123[i]*b.e2[i];
+    out.e021[i] = -a.e0123[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_mul_bivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: BivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e01[i] = -a.e0123[i]*b.e23[i];
+    out.e02[i] = -a.e0123[i]*b.e31[i];
+    out.e03[i] = -a.e0123[i]*b.e12[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_mul_trivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: TrivectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e0123[i]*b.e123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn motor_mul_motor<const N: usize>(
+  a: MotorX<N>,
+  b: MotorX<N>,
+) -> MotorX<N> {
+  let mut out = MotorX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i]*b.s[i] - a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i]
+             - a.e12[i]*b.e12[i];
+    out.e23[i] = a.s[i]*b.e23[i] + a.e23[i]*b.s[i] - a.e31[i]*b.e12[i]
+               + a.e12[i]*b.e31[i];
+    out.e31[i] = a.s[i]*b.e31[i] + a.e23[i]*b.e12[i] + a.e31[i]*b.s[i]
+               - a.e12[i]*b.e23[i];
+    out.e12[i] = a.s[i]*b.e12[i] - a.e23[i]*b.e31[i] + a.e31[i]*b.e23[i]
+               + a.e12[i]*b.s[i];
+    out.e01[i] = a.s[i]*b.e01[i] - a.e23[i]*b.e0123[i] - a.e31[i]*b.e03[i]
+               + a.e12[i]*b.e02[i] + a.e01[i]*b.s[i] - a.e02[i]*b.e12[i]
+               + a.e03[i]*b.e31[i] - a.e0123[i]*b.e23[i];
+    out.e02[i] = a.s[i]*b.e02[i] + a.e23[i]*b.e03[i] - a.e31[i]*b.e0123[i]
+               - a.e12[i]*b.e01[i] + a.e01[i]*b.e12[i] + a.e02[i]*b.s[i]
+               - a.e03[i]*b.e23[i] - a.e0123[i]*b.e31[i];
+    out.e03[i] = a.s[i]*b.e03[i] - a.e23[i]*b.e02[i] + a.e31[i]*b.e01[i]
+               - a.e12[i]*b.e0123[i] - a.e01[i]*b.e31[i] + a.e02[i]*b.e23[i]
+               + a.e03[i]*b.s[i] - a.e0123[i]*b.e12[i];
+    out.e0123[i] = a.s[i]*b.e0123[i] + a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i]
+                 + a.e12[i]*b.e03[i] + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i]
+                 + a.e03[i]*b.e12[i] + a.e0123[i]*b.s[i];
+  }
+  out
+}
+
+// Meet
+
+impl_binary! { Meet::meet {
+  multivector_meet_multivector: MultivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  multivector_meet_scalar: MultivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  multivector_meet_vector: MultivectorX<N>, VectorX<N> => MultivectorX<N>;
+  multivector_meet_bivector: MultivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  multivector_meet_trivector: MultivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  multivector_meet_pseudoscalar: MultivectorX<N>, PseudoscalarX<N> => PseudoscalarX<N>;
+  return_empty: MultivectorX<N>, Empty => Empty;
+  scalar_meet_multivector: ScalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  scalar_meet_scalar: ScalarX<N>, ScalarX<N> => ScalarX<N>;
+  scalar_meet_vector: ScalarX<N>, VectorX<N> => VectorX<N>;
+  scalar_meet_bivector: ScalarX<N>, BivectorX<N> => BivectorX<N>;
+  scalar_meet_trivector: ScalarX<N>, TrivectorX<N> => TrivectorX<N>;
+  scalar_meet_pseudoscalar: ScalarX<N>, PseudoscalarX<N> => PseudoscalarX<N>;
+  return_empty: ScalarX<N>, Empty => Empty;
+  vector_meet_multivector: VectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  vector_meet_scalar: VectorX<N>, ScalarX<N> => VectorX<N>;
+  vector_meet_vector: VectorX<N>, VectorX<N> => BivectorX<N>;
+  vector_meet_bivector: VectorX<N>, BivectorX<N> => TrivectorX<N>;
+  vector_meet_trivector: VectorX<N>, TrivectorX<N> => PseudoscalarX<N>;
+  return_empty: VectorX<N>, PseudoscalarX<N> => Empty;
+  return_empty: VectorX<N>, Empty => Empty;
+  bivector_meet_multivector: BivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  bivector_meet_scalar: BivectorX<N>, ScalarX<N> => BivectorX<N>;
+  bivector_meet_vector: BivectorX<N>, VectorX<N> => TrivectorX<N>;
+  bivector_meet_bivector: BivectorX<N>, BivectorX<N> => PseudoscalarX<N>;
+  return_empty: BivectorX<N>, TrivectorX<N> => Empty;
+  return_empty: BivectorX<N>, PseudoscalarX<N> => Empty;
+  return_empty: BivectorX<N>, Empty => Empty;
+  trivector_meet_multivector: TrivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  trivector_meet_scalar: TrivectorX<N>, ScalarX<N> => TrivectorX<N>;
+  trivector_meet_vector: TrivectorX<N>, VectorX<N> => PseudoscalarX<N>;
+  return_empty: TrivectorX<N>, BivectorX<N> => Empty;
+  return_empty: TrivectorX<N>, TrivectorX<N> => Empty;
+  return_empty: TrivectorX<N>, PseudoscalarX<N> => Empty;
+  return_empty: TrivectorX<N>, Empty => Empty;
+  pseudoscalar_meet_multivector: PseudoscalarX<N>, MultivectorX<N> => PseudoscalarX<N>;
+  pseudoscalar_meet_scalar: PseudoscalarX<N>, ScalarX<N> => PseudoscalarX<N>;
+  return_empty: PseudoscalarX<N>, VectorX<N> => Empty;
+  return_empty: PseudoscalarX<N>, BivectorX<N> => Empty;
+  return_empty: PseudoscalarX<N>, TrivectorX<N> => Empty;
+  return_empty: PseudoscalarX<N>, PseudoscalarX<N> => Empty;
+  return_empty: PseudoscalarX<N>, Empty => Empty;
+} }
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_meet_multivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i] + a.s[i]*b.e0[i];
+    out.e1[i] = a.e1[i]*b.s[i] + a.s[i]*b.e1[i];
+    out.e2[i] = a.e2[i]*b.s[i] + a.s[i]*b.e2[i];
+    out.e3[i] = a.e3[i]*b.s[i] + a.s[i]*b.e3[i];
+    out.s[i] = a.s[i]*b.s[i];
+    out.e23[i] = a.e2[i]*b.e3[i] - a.e3[i]*b.e2[i] + a.s[i]*b.e23[i]
+               + a.e23[i]*b.s[i];
+    out.e31[i] = a.e3[i]*b.e1[i] + a.s[i]*b.e31[i] + a.e31[i]*b.s[i]
+               - a.e1[i]*b.e3[i];
+    out.e12[i] = a.e1[i]*b.e2[i] - a.e2[i]*b.e1[i] + a.s[i]*b.e12[i]
+               + a.e12[i]*b.s[i];
+    out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i] + a.s[i]*b.e01[i]
+               + a.e01[i]*b.s[i];
+    out.e02[i] = a.e0[i]*b.e2[i] - a.e2[i]*b.e0[i] + a.s[i]*b.e02[i]
+               + a.e02[i]*b.s[i];
+    out.e03[i] = a.e0[i]*b.e3[i] - a.e3[i]*b.e0[i] + a.s[i]*b.e03[i]
+               + a.e03[i]*b.s[i];
+    out.e0123[i] = a.e0[i]*b.e123[i] + a.e1[i]*b.e032[i] + a.e2[i]*b.e013[i]
+                 + a.e3[i]*b.e021[i] + a.s[i]*b.e0123[i] + a.e23[i]*b.e01[i]
+                 + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i] + a.e01[i]*b.e23[i]
+                 + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i] + a.e0123[i]*b.s[i]
+                 - a.e123[i]*b.e0[i] - a.e032[i]*b.e1[i] - a.e013[i]*b.e2[i]
+                 - a.e021[i]*b.e3[i];
+    out.e123[i] = a.e1[i]*b.e23[i] + a.e2[i]*b.e31[i] + a.e3[i]*b.e12[i]
+                + a.s[i]*b.e123[i] + a.e23[i]*b.e1[i] + a.e31[i]*b.e2[i]
+                + a.e12[i]*b.e3[i] + a.e123[i]*b.s[i];
+    out.e032[i] = a.e2[i]*b.e03[i] + a.s[i]*b.e032[i] + a.e03[i]*b.e2[i]
+                + a.e032[i]*b.s[i] - a.e0[i]*b.e23[i] - a.e3[i]*b.e02[i]
+                - a.e23[i]*b.e0[i] - a.e02[i]*b.e3[i];
+    out.e013[i] = a.e3[i]*b.e01[i] + a.s[i]*b.e013[i] + a.e01[i]*b.e3[i]
+                + a.e013[i]*b.s[i] - a.e0[i]*b.e31[i] - a.e1[i]*b.e03[i]
+                - a.e31[i]*b.e0[i] - a.e03[i]*b.e1[i];
+    out.e021[i] = a.e1[i]*b.e02[i] + a.s[i]*b.e021[i] + a.e02[i]*b.e1[i]
+                + a.e021[i]*b.s[i] - a.e0[i]*b.e12[i] - a.e2[i]*b.e01[i]
+                - a.e12[i]*b.e0[i] - a.e01[i]*b.e2[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_meet_scalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i];
+    out.e1[i] = a.e1[i]*b.s[i];
+    out.e2[i] = a.e2[i]*b.s[i];
+    out.e3[i] = a.e3[i]*b.s[i];
+    out.s[i] = a.s[i]*b.s[i];
+    out.e23[i] = a.e23[i]*b.s[i];
+    out.e31[i] = a.e31[i]*b.s[i];
+    out.e12[i] = a.e12[i]*b.s[i];
+    out.e01[i] = a.e01[i]*b.s[i];
+    out.e02[i] = a.e02[i]*b.s[i];
+    out.e03[i] = a.e03[i]*b.s[i];
+    out.e0123[i] = a.e0123[i]*b.s[i];
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e032[i]*b.s[i];
+    out.e013[i] = a.e013[i]*b.s[i];
+    out.e021[i] = a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_meet_vector<const N: usize>(
+  a: MultivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i];
+    out.e1[i] = a.s[i]*b.e1[i];
+    out.e2[i] = a.s[i]*b.e2[i];
+    out.e3[i] = a.s[i]*b.e3[i];
+    out.e23[i] = a.e2[i]*b.e3[i] - a.e3[i]*b.e2[i];
+    out.e31[i] = a.e3[i]*b.e1[i] - a.e1[i]*b.e3[i];
+    out.e12[i] = a.e1[i]*b.e2[i] - a.e2[i]*b.e1[i];
+    out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i];
+    out.e02[i] = a.e0[i]*b.e2[i] - a.e2[i]*b.e0[i];
+    out.e03[i] = a.e0[i]*b.e3[i] - a.e3[i]*b.e0[i];
+    out.e0123[i] = -a.e123[i]*b.e0[i] - a.e032[i]*b.e1[i] - a.e013[i]*b.e2[i]
+                 - a.e021[i]*b.e3[i];
+    out.e123[i] = a.e23[i]*b.e1[i] + a.e31[i]*b.e2[i] + a.e12[i]*b.e3[i];
+    out.e032[i] = a.e03[i]*b.e2[i] - a.e23[i]*b.e0[i] - a.e02[i]*b.e3[i];
+    out.e013[i] = a.e01[i]*b.e3[i] - a.e31[i]*b.e0[i] - a.e03[i]*b.e1[i];
+    out.e021[i] = a.e02[i]*b.e1[i] - a.e12[i]*b.e0[i] - a.e01[i]*b.e2[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_meet_bivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.s[i]*b.e23[i];
+    out.e31[i] = a.s[i]*b.e31[i];
+    out.e12[i] = a.s[i]*b.e12[i];
+    out.e01[i] = a.s[i]*b.e01[i];
+    out.e02[i] = a.s[i]*b.e02[i];
+    out.e03[i] = a.s[i]*b.e03[i];
+    out.e0123[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+                 + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+    out.e123[i] = a.e1[i]*b.e23[i] + a.e2[i]*b.e31[i] + a.e3[i]*b.e12[i];
+    out.e032[i] = a.e2[i]*b.e03[i] - a.e0[i]*b.e23[i] - a.e3[i]*b.e02[i];
+    out.e013[i] = a.e3[i]*b.e01[i] - a.e0[i]*b.e31[i] - a.e1[i]*b.e03[i];
+    out.e021[i] = a.e1[i]*b.e02[i] - a.e0[i]*b.e12[i] - a.e2[i]*b.e01[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_meet_trivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0[i]*b.e123[i] + a.e1[i]*b.e032[i] + a.e2[i]*b.e013[i]
+                 + a.e3[i]*b.e021[i];
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i];
+    out.e013[i] = a.s[i]*b.e013[i];
+    out.e021[i] = a.s[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_meet_pseudoscalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.s[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_meet_multivector<const N: usize>(
+  a: ScalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i];
+    out.e1[i] = a.s[i]*b.e1[i];
+    out.e2[i] = a.s[i]*b.e2[i];
+    out.e3[i] = a.s[i]*b.e3[i];
+    out.s[i] = a.s[i]*b.s[i];
+    out.e23[i] = a.s[i]*b.e23[i];
+    out.e31[i] = a.s[i]*b.e31[i];
+    out.e12[i] = a.s[i]*b.e12[i];
+    out.e01[i] = a.s[i]*b.e01[i];
+    out.e02[i] = a.s[i]*b.e02[i];
+    out.e03[i] = a.s[i]*b.e03[i];
+    out.e0123[i] = a.s[i]*b.e0123[i];
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i];
+    out.e013[i] = a.s[i]*b.e013[i];
+    out.e021[i] = a.s[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_meet_scalar<const N: usize>(
+  a: ScalarX<N>,
+  b: ScalarX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_meet_vector<const N: usize>(
+  a: ScalarX<N>,
+  b: VectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i];
+    out.e1[i] = a.s[i]*b.e1[i];
+    out.e2[i] = a.s[i]*b.e2[i];
+    out.e3[i] = a.s[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_meet_bivector<const N: usize>(
+  a: ScalarX<N>,
+  b: BivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.s[i]*b.e23[i];
+    out.e31[i] = a.s[i]*b.e31[i];
+    out.e12[i] = a.s[i]*b.e12[i];
+    out.e01[i] = a.s[i]*b.e01[i];
+    out.e02[i] = a.s[i]*b.e02[i];
+    out.e03[i] = a.s[i]*b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_meet_trivector<const N: usize>(
+  a: ScalarX<N>,
+  b: TrivectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i];
+    out.e013[i] = a.s[i]*b.e013[i];
+    out.e021[i] = a.s[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_meet_pseudoscalar<const N: usize>(
+  a: ScalarX<N>,
+  b: PseudoscalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.s[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_meet_multivector<const N: usize>(
+  a: VectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i];
+    out.e1[i] = a.e1[i]*b.s[i];
+    out.e2[i] = a.e2[i]*b.s[i];
+    out.e3[i] = a.e3[i]*b.s[i];
+    out.e23[i] = a.e2[i]*b.e3[i] - a.e3[i]*b.e2[i];
+    out.e31[i] = a.e3[i]*b.e1[i] - a.e1[i]*b.e3[i];
+    out.e12[i] = a.e1[i]*b.e2[i] - a.e2[i]*b.e1[i];
+    out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i];
+    out.e02[i] = a.e0[i]*b.e2[i] - a.e2[i]*b.e0[i];
+    out.e03[i] = a.e0[i]*b.e3[i] - a.e3[i]*b.e0[i];
+    out.e0123[i] = a.e0[i]*b.e123[i] + a.e1[i]*b.e032[i] + a.e2[i]*b.e013[i]
+                 + a.e3[i]*b.e021[i];
+    out.e123[i] = a.e1[i]*b.e23[i] + a.e2[i]*b.e31[i] + a.e3[i]*b.e12[i];
+    out.e032[i] = a.e2[i]*b.e03[i] - a.e0[i]*b.e23[i] - a.e3[i]*b.e02[i];
+    out.e013[i] = a.e3[i]*b.e01[i] - a.e0[i]*b.e31[i] - a.e1[i]*b.e03[i];
+    out.e021[i] = a.e1[i]*b.e02[i] - a.e0[i]*b.e12[i] - a.e2[i]*b.e01[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_meet_scalar<const N: usize>(
+  a: VectorX<N>,
+  b: ScalarX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i];
+    out.e1[i] = a.e1[i]*b.s[i];
+    out.e2[i] = a.e2[i]*b.s[i];
+    out.e3[i] = a.e3[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_meet_vector<const N: usize>(
+  a: VectorX<N>,
+  b: VectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e2[i]*b.e3[i] - a.e3[i]*b.e2[i];
+    out.e31[i] = a.e3[i]*b.e1[i] - a.e1[i]*b.e3[i];
+    out.e12[i] = a.e1[i]*b.e2[i] - a.e2[i]*b.e1[i];
+    out.e01[i] = a.e0[i]*b.e1[i] - a.e1[i]*b.e0[i];
+    out.e02[i] = a.e0[i]*b.e2[i] - a.e2[i]*b.e0[i];
+    out.e03[i] = a.e0[i]*b.e3[i] - a.e3[i]*b.e0[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_meet_bivector<const N: usize>(
+  a: VectorX<N>,
+  b: BivectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e1[i]*b.e23[i] + a.e2[i]*b.e31[i] + a.e3[i]*b.e12[i];
+    out.e032[i] = a.e2[i]*b.e03[i] - a.e0[i]*b.e23[i] - a.e3[i]*b.e02[i];
+    out.e013[i] = a.e3[i]*b.e01[i] - a.e0[i]*b.e31[i] - a.e1[i]*b.e03[i];
+    out.e021[i] = a.e1[i]*b.e02[i] - a.e0[i]*b.e12[i] - a.e2[i]*b.e01[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_meet_trivector<const N: usize>(
+  a: VectorX<N>,
+  b: TrivectorX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0[i]*b.e123[i] + a.e1[i]*b.e032[i] + a.e2[i]*b.e013[i]
+                 + a.e3[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_meet_multivector<const N: usize>(
+  a: BivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i]*b.s[i];
+    out.e31[i] = a.e31[i]*b.s[i];
+    out.e12[i] = a.e12[i]*b.s[i];
+    out.e01[i] = a.e01[i]*b.s[i];
+    out.e02[i] = a.e02[i]*b.s[i];
+    out.e03[i] = a.e03[i]*b.s[i];
+    out.e0123[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+                 + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+    out.e123[i] = a.e23[i]*b.e1[i] + a.e31[i]*b.e2[i] + a.e12[i]*b.e3[i];
+    out.e032[i] = a.e03[i]*b.e2[i] - a.e23[i]*b.e0[i] - a.e02[i]*b.e3[i];
+    out.e013[i] = a.e01[i]*b.e3[i] - a.e31[i]*b.e0[i] - a.e03[i]*b.e1[i];
+    out.e021[i] = a.e02[i]*b.e1[i] - a.e12[i]*b.e0[i] - a.e01[i]*b.e2[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_meet_scalar<const N: usize>(
+  a: BivectorX<N>,
+  b: ScalarX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i]*b.s[i];
+    out.e31[i] = a.e31[i]*b.s[i];
+    out.e12[i] = a.e12[i]*b.s[i];
+    out.e01[i] = a.e01[i]*b.s[i];
+    out.e02[i] = a.e02[i]*b.s[i];
+    out.e03[i] = a.e03[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_meet_vector<const N: usize>(
+  a: BivectorX<N>,
+  b: VectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e23[i]*b.e1[i] + a.e31[i]*b.e2[i] + a.e12[i]*b.e3[i];
+    out.e032[i] = a.e03[i]*b.e2[i] - a.e23[i]*b.e0[i] - a.e02[i]*b.e3[i];
+    out.e013[i] = a.e01[i]*b.e3[i] - a.e31[i]*b.e0[i] - a.e03[i]*b.e1[i];
+    out.e021[i] = a.e02[i]*b.e1[i] - a.e12[i]*b.e0[i] - a.e01[i]*b.e2[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_meet_bivector<const N: usize>(
+  a: BivectorX<N>,
+  b: BivectorX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+                 + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_meet_multivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0123[i] = -a.e123[i]*b.e0[i] - a.e032[i]*b.e1[i] - a.e013[i]*b.e2[i]
+                 - a.e021[i]*b.e3[i];
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e032[i]*b.s[i];
+    out.e013[i] = a.e013[i]*b.s[i];
+    out.e021[i] = a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_meet_scalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: ScalarX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e032[i]*b.s[i];
+    out.e013[i] = a.e013[i]*b.s[i];
+    out.e021[i] = a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_meet_vector<const N: usize>(
+  a: TrivectorX<N>,
+  b: VectorX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = -a.e123[i]*b.e0[i] - a.e032[i]*b.e1[i] - a.e013[i]*b.e2[i]
+                 - a.e021[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_meet_multivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: MultivectorX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_meet_scalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: ScalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i]*b.s[i];
+  }
+  out
+}
+
+// Join
+
+impl_binary! { Join::join {
+  multivector_join_multivector: MultivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  multivector_join_scalar: MultivectorX<N>, ScalarX<N> => ScalarX<N>;
+  multivector_join_vector: MultivectorX<N>, VectorX<N> => MultivectorX<N>;
+  multivector_join_bivector: MultivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  multivector_join_trivector: MultivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  multivector_join_pseudoscalar: MultivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_empty: MultivectorX<N>, Empty => Empty;
+  scalar_join_multivector: ScalarX<N>, MultivectorX<N> => ScalarX<N>;
+  return_empty: ScalarX<N>, ScalarX<N> => Empty;
+  return_empty: ScalarX<N>, VectorX<N> => Empty;
+  return_empty: ScalarX<N>, BivectorX<N> => Empty;
+  return_empty: ScalarX<N>, TrivectorX<N> => Empty;
+  scalar_join_pseudoscalar: ScalarX<N>, PseudoscalarX<N> => ScalarX<N>;
+  return_empty: ScalarX<N>, Empty => Empty;
+  vector_join_multivector: VectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  return_empty: VectorX<N>, ScalarX<N> => Empty;
+  return_empty: VectorX<N>, VectorX<N> => Empty;
+  return_empty: VectorX<N>, BivectorX<N> => Empty;
+  vector_join_trivector: VectorX<N>, TrivectorX<N> => ScalarX<N>;
+  vector_join_pseudoscalar: VectorX<N>, PseudoscalarX<N> => VectorX<N>;
+  return_empty: VectorX<N>, Empty => Empty;
+  bivector_join_multivector: BivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  return_empty: BivectorX<N>, ScalarX<N> => Empty;
+  return_empty: BivectorX<N>, VectorX<N> => Empty;
+  bivector_join_bivector: BivectorX<N>, BivectorX<N> => ScalarX<N>;
+  bivector_join_trivector: BivectorX<N>, TrivectorX<N> => VectorX<N>;
+  bivector_join_pseudoscalar: BivectorX<N>, PseudoscalarX<N> => BivectorX<N>;
+  return_empty: BivectorX<N>, Empty => Empty;
+  trivector_join_multivector: TrivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  return_empty: TrivectorX<N>, ScalarX<N> => Empty;
+  trivector_join_vector: TrivectorX<N>, VectorX<N> => ScalarX<N>;
+  trivector_join_bivector: TrivectorX<N>, BivectorX<N> => VectorX<N>;
+  trivector_join_trivector: TrivectorX<N>, TrivectorX<N> => BivectorX<N>;
+  trivector_join_pseudoscalar: TrivectorX<N>, PseudoscalarX<N> => TrivectorX<N>;
+  return_empty: TrivectorX<N>, Empty => Empty;
+  pseudoscalar_join_multivector: PseudoscalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  pseudoscalar_join_scalar: PseudoscalarX<N>, ScalarX<N> => ScalarX<N>;
+  pseudoscalar_join_vector: PseudoscalarX<N>, VectorX<N> => VectorX<N>;
+  pseudoscalar_join_bivector: PseudoscalarX<N>, BivectorX<N> => BivectorX<N>;
+  pseudoscalar_join_trivector: PseudoscalarX<N>, TrivectorX<N> => TrivectorX<N>;
+  pseudoscalar_join_pseudoscalar: PseudoscalarX<N>, PseudoscalarX<N> => PseudoscalarX<N>;
+  return_empty: PseudoscalarX<N>, Empty => Empty;
+} }
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_join_multivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.e0123[i] - a.e01[i]*b.e032[i] - a.e02[i]*b.e013[i]
+              - a.e03[i]*b.e021[i] + a.e0123[i]*b.e0[i] - a.e032[i]*b.e01[i]
+              - a.e013[i]*b.e02[i] - a.e021[i]*b.e03[i];
+    out.e1[i] = a.e1[i]*b.e0123[i] + a.e31[i]*b.e021[i] - a.e12[i]*b.e013[i]
+              + a.e01[i]*b.e123[i] + a.e0123[i]*b.e1[i] + a.e123[i]*b.e01[i]
+              - a.e013[i]*b.e12[i] + a.e021[i]*b.e31[i];
+    out.e2[i] = a.e2[i]*b.e0123[i] - a.e23[i]*b.e021[i] + a.e12[i]*b.e032[i]
+              + a.e02[i]*b.e123[i] + a.e0123[i]*b.e2[i] + a.e123[i]*b.e02[i]
+              + a.e032[i]*b.e12[i] - a.e021[i]*b.e23[i];
+    out.e3[i] = a.e3[i]*b.e0123[i] + a.e23[i]*b.e013[i] - a.e31[i]*b.e032[i]
+              + a.e03[i]*b.e123[i] + a.e0123[i]*b.e3[i] + a.e123[i]*b.e03[i]
+              - a.e032[i]*b.e31[i] + a.e013[i]*b.e23[i];
+    out.s[i] = a.s[i]*b.e0123[i] + a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i]
+             + a.e12[i]*b.e03[i] + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i]
+             + a.e03[i]*b.e12[i] + a.e0123[i]*b.s[i] + a.e123[i]*b.e0[i]
+             + a.e032[i]*b.e1[i] + a.e013[i]*b.e2[i] + a.e021[i]*b.e3[i]
+             - a.e0[i]*b.e123[i] - a.e1[i]*b.e032[i] - a.e2[i]*b.e013[i]
+             - a.e3[i]*b.e021[i];
+    out.e23[i] = a.e23[i]*b.e0123[i] + a.e0123[i]*b.e23[i] - a.e123[i]*b.e032[i]
+               + a.e032[i]*b.e123[i];
+    out.e31[i] = a.e31[i]*b.e0123[i] + a.e0123[i]*b.e31[i] - a.e123[i]*b.e013[i]
+               + a.e013[i]*b.e123[i];
+    out.e12[i] = a.e12[i]*b.e0123[i] + a.e0123[i]*b.e12[i] - a.e123[i]*b.e021[i]
+               + a.e021[i]*b.e123[i];
+    out.e01[i] = a.e01[i]*b.e0123[i] + a.e0123[i]*b.e01[i] - a.e013[i]*b.e021[i]
+               + a.e021[i]*b.e013[i];
+    out.e02[i] = a.e02[i]*b.e0123[i] + a.e0123[i]*b.e02[i] + a.e032[i]*b.e021[i]
+               - a.e021[i]*b.e032[i];
+    out.e03[i] = a.e03[i]*b.e0123[i] + a.e0123[i]*b.e03[i] - a.e032[i]*b.e013[i]
+               + a.e013[i]*b.e032[i];
+    out.e0123[i] = a.e0123[i]*b.e0123[i];
+    out.e123[i] = a.e0123[i]*b.e123[i] + a.e123[i]*b.e0123[i];
+    out.e032[i] = a.e0123[i]*b.e032[i] + a.e032[i]*b.e0123[i];
+    out.e013[i] = a.e0123[i]*b.e013[i] + a.e013[i]*b.e0123[i];
+    out.e021[i] = a.e0123[i]*b.e021[i] + a.e021[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_join_scalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: ScalarX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.e0123[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_join_vector<const N: usize>(
+  a: MultivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0123[i]*b.e0[i];
+    out.e1[i] = a.e0123[i]*b.e1[i];
+    out.e2[i] = a.e0123[i]*b.e2[i];
+    out.e3[i] = a.e0123[i]*b.e3[i];
+    out.s[i] = a.e123[i]*b.e0[i] + a.e032[i]*b.e1[i] + a.e013[i]*b.e2[i]
+             + a.e021[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_join_bivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e032[i]*b.e01[i] - a.e013[i]*b.e02[i] - a.e021[i]*b.e03[i];
+    out.e1[i] = a.e123[i]*b.e01[i] - a.e013[i]*b.e12[i] + a.e021[i]*b.e31[i];
+    out.e2[i] = a.e123[i]*b.e02[i] + a.e032[i]*b.e12[i] - a.e021[i]*b.e23[i];
+    out.e3[i] = a.e123[i]*b.e03[i] - a.e032[i]*b.e31[i] + a.e013[i]*b.e23[i];
+    out.s[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+             + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+    out.e23[i] = a.e0123[i]*b.e23[i];
+    out.e31[i] = a.e0123[i]*b.e31[i];
+    out.e12[i] = a.e0123[i]*b.e12[i];
+    out.e01[i] = a.e0123[i]*b.e01[i];
+    out.e02[i] = a.e0123[i]*b.e02[i];
+    out.e03[i] = a.e0123[i]*b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_join_trivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e01[i]*b.e032[i] - a.e02[i]*b.e013[i] - a.e03[i]*b.e021[i];
+    out.e1[i] = a.e31[i]*b.e021[i] - a.e12[i]*b.e013[i] + a.e01[i]*b.e123[i];
+    out.e2[i] = a.e12[i]*b.e032[i] + a.e02[i]*b.e123[i] - a.e23[i]*b.e021[i];
+    out.e3[i] = a.e23[i]*b.e013[i] - a.e31[i]*b.e032[i] + a.e03[i]*b.e123[i];
+    out.s[i] = -a.e0[i]*b.e123[i] - a.e1[i]*b.e032[i] - a.e2[i]*b.e013[i]
+             - a.e3[i]*b.e021[i];
+    out.e23[i] = a.e032[i]*b.e123[i] - a.e123[i]*b.e032[i];
+    out.e31[i] = a.e013[i]*b.e123[i] - a.e123[i]*b.e013[i];
+    out.e12[i] = a.e021[i]*b.e123[i] - a.e123[i]*b.e021[i];
+    out.e01[i] = a.e021[i]*b.e013[i] - a.e013[i]*b.e021[i];
+    out.e02[i] = a.e032[i]*b.e021[i] - a.e021[i]*b.e032[i];
+    out.e03[i] = a.e013[i]*b.e032[i] - a.e032[i]*b.e013[i];
+    out.e123[i] = a.e0123[i]*b.e123[i];
+    out.e032[i] = a.e0123[i]*b.e032[i];
+    out.e013[i] = a.e0123[i]*b.e013[i];
+    out.e021[i] = a.e0123[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_join_pseudoscalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.e0123[i];
+    out.e1[i] = a.e1[i]*b.e0123[i];
+    out.e2[i] = a.e2[i]*b.e0123[i];
+    out.e3[i] = a.e3[i]*b.e0123[i];
+    out.s[i] = a.s[i]*b.e0123[i];
+    out.e23[i] = a.e23[i]*b.e0123[i];
+    out.e31[i] = a.e31[i]*b.e0123[i];
+    out.e12[i] = a.e12[i]*b.e0123[i];
+    out.e01[i] = a.e01[i]*b.e0123[i];
+    out.e02[i] = a.e02[i]*b.e0123[i];
+    out.e03[i] = a.e03[i]*b.e0123[i];
+    out.e0123[i] = a.e0123[i]*b.e0123[i];
+    out.e123[i] = a.e123[i]*b.e0123[i];
+    out.e032[i] = a.e032[i]*b.e0123[i];
+    out.e013[i] = a.e013[i]*b.e0123[i];
+    out.e021[i] = a.e021[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_join_multivector<const N: usize>(
+  a: ScalarX<N>,
+  b: MultivectorX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_join_pseudoscalar<const N: usize>(
+  a: ScalarX<N>,
+  b: PseudoscalarX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_join_multivector<const N: usize>(
+  a: VectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.e0123[i];
+    out.e1[i] = a.e1[i]*b.e0123[i];
+    out.e2[i] = a.e2[i]*b.e0123[i];
+    out.e3[i] = a.e3[i]*b.e0123[i];
+    out.s[i] = -a.e0[i]*b.e123[i] - a.e1[i]*b.e032[i] - a.e2[i]*b.e013[i]
+             - a.e3[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_join_trivector<const N: usize>(
+  a: VectorX<N>,
+  b: TrivectorX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = -a.e0[i]*b.e123[i] - a.e1[i]*b.e032[i] - a.e2[i]*b.e013[i]
+             - a.e3[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_join_pseudoscalar<const N: usize>(
+  a: VectorX<N>,
+  b: PseudoscalarX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.e0123[i];
+    out.e1[i] = a.e1[i]*b.e0123[i];
+    out.e2[i] = a.e2[i]*b.e0123[i];
+    out.e3[i] = a.e3[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_join_multivector<const N: usize>(
+  a: BivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e01[i]*b.e032[i] - a.e02[i]*b.e013[i] - a.e03[i]*b.e021[i];
+    out.e1[i] = a.e31[i]*b.e021[i] - a.e12[i]*b.e013[i] + a.e01[i]*b.e123[i];
+    out.e2[i] = a.e12[i]*b.e032[i] + a.e02[i]*b.e123[i] - a.e23[i]*b.e021[i];
+    out.e3[i] = a.e23[i]*b.e013[i] - a.e31[i]*b.e032[i] + a.e03[i]*b.e123[i];
+    out.s[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+             + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+    out.e23[i] = a.e23[i]*b.e0123[i];
+    out.e31[i] = a.e31[i]*b.e0123[i];
+    out.e12[i] = a.e12[i]*b.e0123[i];
+    out.e01[i] = a.e01[i]*b.e0123[i];
+    out.e02[i] = a.e02[i]*b.e0123[i];
+    out.e03[i] = a.e03[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_join_bivector<const N: usize>(
+  a: BivectorX<N>,
+  b: BivectorX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.e23[i]*b.e01[i] + a.e31[i]*b.e02[i] + a.e12[i]*b.e03[i]
+             + a.e01[i]*b.e23[i] + a.e02[i]*b.e31[i] + a.e03[i]*b.e12[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_join_trivector<const N: usize>(
+  a: BivectorX<N>,
+  b: TrivectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e01[i]*b.e032[i] - a.e02[i]*b.e013[i] - a.e03[i]*b.e021[i];
+    out.e1[i] = a.e31[i]*b.e021[i] - a.e12[i]*b.e013[i] + a.e01[i]*b.e123[i];
+    out.e2[i] = a.e12[i]*b.e032[i] + a.e02[i]*b.e123[i] - a.e23[i]*b.e021[i];
+    out.e3[i] = a.e23[i]*b.e013[i] - a.e31[i]*b.e032[i] + a.e03[i]*b.e123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_join_pseudoscalar<const N: usize>(
+  a: BivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i]*b.e0123[i];
+    out.e31[i] = a.e31[i]*b.e0123[i];
+    out.e12[i] = a.e12[i]*b.e0123[i];
+    out.e01[i] = a.e01[i]*b.e0123[i];
+    out.e02[i] = a.e02[i]*b.e0123[i];
+    out.e03[i] = a.e03[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_join_multivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e032[i]*b.e01[i] - a.e013[i]*b.e02[i] - a.e021[i]*b.e03[i];
+    out.e1[i] = a.e123[i]*b.e01[i] - a.e013[i]*b.e12[i] + a.e021[i]*b.e31[i];
+    out.e2[i] = a.e123[i]*b.e02[i] + a.e032[i]*b.e12[i] - a.e021[i]*b.e23[i];
+    out.e3[i] = a.e123[i]*b.e03[i] - a.e032[i]*b.e31[i] + a.e013[i]*b.e23[i];
+    out.s[i] = a.e123[i]*b.e0[i] + a.e032[i]*b.e1[i] + a.e013[i]*b.e2[i]
+             + a.e021[i]*b.e3[i];
+    out.e23[i] = a.e032[i]*b.e123[i] - a.e123[i]*b.e032[i];
+    out.e31[i] = a.e013[i]*b.e123[i] - a.e123[i]*b.e013[i];
+    out.e12[i] = a.e021[i]*b.e123[i] - a.e123[i]*b.e021[i];
+    out.e01[i] = a.e021[i]*b.e013[i] - a.e013[i]*b.e021[i];
+    out.e02[i] = a.e032[i]*b.e021[i] - a.e021[i]*b.e032[i];
+    out.e03[i] = a.e013[i]*b.e032[i] - a.e032[i]*b.e013[i];
+    out.e123[i] = a.e123[i]*b.e0123[i];
+    out.e032[i] = a.e032[i]*b.e0123[i];
+    out.e013[i] = a.e013[i]*b.e0123[i];
+    out.e021[i] = a.e021[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_join_vector<const N: usize>(
+  a: TrivectorX<N>,
+  b: VectorX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.e123[i]*b.e0[i] + a.e032[i]*b.e1[i] + a.e013[i]*b.e2[i]
+             + a.e021[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_join_bivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: BivectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e032[i]*b.e01[i] - a.e013[i]*b.e02[i] - a.e021[i]*b.e03[i];
+    out.e1[i] = a.e123[i]*b.e01[i] - a.e013[i]*b.e12[i] + a.e021[i]*b.e31[i];
+    out.e2[i] = a.e123[i]*b.e02[i] + a.e032[i]*b.e12[i] - a.e021[i]*b.e23[i];
+    out.e3[i] = a.e123[i]*b.e03[i] - a.e032[i]*b.e31[i] + a.e013[i]*b.e23[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_join_trivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: TrivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e032[i]*b.e123[i] - a.e123[i]*b.e032[i];
+    out.e31[i] = a.e013[i]*b.e123[i] - a.e123[i]*b.e013[i];
+    out.e12[i] = a.e021[i]*b.e123[i] - a.e123[i]*b.e021[i];
+    out.e01[i] = a.e021[i]*b.e013[i] - a.e013[i]*b.e021[i];
+    out.e02[i] = a.e032[i]*b.e021[i] - a.e021[i]*b.e032[i];
+    out.e03[i] = a.e013[i]*b.e032[i] - a.e032[i]*b.e013[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_join_pseudoscalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e123[i]*b.e0123[i];
+    out.e032[i] = a.e032[i]*b.e0123[i];
+    out.e013[i] = a.e013[i]*b.e0123[i];
+    out.e021[i] = a.e021[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_join_multivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0123[i]*b.e0[i];
+    out.e1[i] = a.e0123[i]*b.e1[i];
+    out.e2[i] = a.e0123[i]*b.e2[i];
+    out.e3[i] = a.e0123[i]*b.e3[i];
+    out.s[i] = a.e0123[i]*b.s[i];
+    out.e23[i] = a.e0123[i]*b.e23[i];
+    out.e31[i] = a.e0123[i]*b.e31[i];
+    out.e12[i] = a.e0123[i]*b.e12[i];
+    out.e01[i] = a.e0123[i]*b.e01[i];
+    out.e02[i] = a.e0123[i]*b.e02[i];
+    out.e03[i] = a.e0123[i]*b.e03[i];
+    out.e0123[i] = a.e0123[i]*b.e0123[i];
+    out.e123[i] = a.e0123[i]*b.e123[i];
+    out.e032[i] = a.e0123[i]*b.e032[i];
+    out.e013[i] = a.e0123[i]*b.e013[i];
+    out.e021[i] = a.e0123[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_join_scalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: ScalarX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.e0123[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_join_vector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: VectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0123[i]*b.e0[i];
+    out.e1[i] = a.e0123[i]*b.e1[i];
+    out.e2[i] = a.e0123[i]*b.e2[i];
+    out.e3[i] = a.e0123[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_join_bivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: BivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e0123[i]*b.e23[i];
+    out.e31[i] = a.e0123[i]*b.e31[i];
+    out.e12[i] = a.e0123[i]*b.e12[i];
+    out.e01[i] = a.e0123[i]*b.e01[i];
+    out.e02[i] = a.e0123[i]*b.e02[i];
+    out.e03[i] = a.e0123[i]*b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_join_trivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: TrivectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e0123[i]*b.e123[i];
+    out.e032[i] = a.e0123[i]*b.e032[i];
+    out.e013[i] = a.e0123[i]*b.e013[i];
+    out.e021[i] = a.e0123[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_join_pseudoscalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: PseudoscalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i]*b.e0123[i];
+  }
+  out
+}
+
+// Dot
+
+impl_binary! { Dot::dot {
+  multivector_dot_multivector: MultivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  multivector_dot_scalar: MultivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  multivector_dot_vector: MultivectorX<N>, VectorX<N> => MultivectorX<N>;
+  multivector_dot_bivector: MultivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  multivector_dot_trivector: MultivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  multivector_dot_pseudoscalar: MultivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_empty: MultivectorX<N>, Empty => Empty;
+  scalar_dot_multivector: ScalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  scalar_dot_scalar: ScalarX<N>, ScalarX<N> => ScalarX<N>;
+  scalar_dot_vector: ScalarX<N>, VectorX<N> => VectorX<N>;
+  scalar_dot_bivector: ScalarX<N>, BivectorX<N> => BivectorX<N>;
+  scalar_dot_trivector: ScalarX<N>, TrivectorX<N> => TrivectorX<N>;
+  scalar_dot_pseudoscalar: ScalarX<N>, PseudoscalarX<N> => PseudoscalarX<N>;
+  return_empty: ScalarX<N>, Empty => Empty;
+  vector_dot_multivector: VectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  vector_dot_scalar: VectorX<N>, ScalarX<N> => VectorX<N>;
+  vector_dot_vector: VectorX<N>, VectorX<N> => ScalarX<N>;
+  vector_dot_bivector: VectorX<N>, BivectorX<N> => VectorX<N>;
+  vector_dot_trivector: VectorX<N>, TrivectorX<N> => BivectorX<N>;
+  vector_dot_pseudoscalar: VectorX<N>, PseudoscalarX<N> => TrivectorX<N>;
+  return_empty: VectorX<N>, Empty => Empty;
+  bivector_dot_multivector: BivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  bivector_dot_scalar: BivectorX<N>, ScalarX<N> => BivectorX<N>;
+  bivector_dot_vector: BivectorX<N>, VectorX<N> => VectorX<N>;
+  bivector_dot_bivector: BivectorX<N>, BivectorX<N> => ScalarX<N>;
+  bivector_dot_trivector: BivectorX<N>, TrivectorX<N> => VectorX<N>;
+  bivector_dot_pseudoscalar: BivectorX<N>, PseudoscalarX<N> => BivectorX<N>;
+  return_empty: BivectorX<N>, Empty => Empty;
+  trivector_dot_multivector: TrivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  trivector_dot_scalar: TrivectorX<N>, ScalarX<N> => TrivectorX<N>;
+  trivector_dot_vector: TrivectorX<N>, VectorX<N> => BivectorX<N>;
+  trivector_dot_bivector: TrivectorX<N>, BivectorX<N> => VectorX<N>;
+  trivector_dot_trivector: TrivectorX<N>, TrivectorX<N> => ScalarX<N>;
+  trivector_dot_pseudoscalar: TrivectorX<N>, PseudoscalarX<N> => VectorX<N>;
+  return_empty: TrivectorX<N>, Empty => Empty;
+  pseudoscalar_dot_multivector: PseudoscalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  pseudoscalar_dot_scalar: PseudoscalarX<N>, ScalarX<N> => PseudoscalarX<N>;
+  pseudoscalar_dot_vector: PseudoscalarX<N>, VectorX<N> => TrivectorX<N>;
+  pseudoscalar_dot_bivector: PseudoscalarX<N>, BivectorX<N> => BivectorX<N>;
+  pseudoscalar_dot_trivector: PseudoscalarX<N>, TrivectorX<N> => VectorX<N>;
+  return_empty: PseudoscalarX<N>, PseudoscalarX<N> => Empty;
+  return_empty: PseudoscalarX<N>, Empty => Empty;
+} }
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_dot_multivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i] - a.e1[i]*b.e01[i] - a.e2[i]*b.e02[i]
+              - a.e3[i]*b.e03[i] + a.s[i]*b.e0[i] + a.e23[i]*b.e032[i]
+              + a.e31[i]*b.e013[i] + a.e12[i]*b.e021[i] + a.e01[i]*b.e1[i]
+              + a.e02[i]*b.e2[i] + a.e03[i]*b.e3[i] - a.e0123[i]*b.e123[i]
+              + a.e123[i]*b.e0123[i] + a.e032[i]*b.e23[i] + a.e013[i]*b.e31[i]
+              + a.e021[i]*b.e12[i];
+    out.e1[i] = a.e1[i]*b.s[i] - a.e2[i]*b.e12[i] + a.e3[i]*b.e31[i]
+              + a.s[i]*b.e1[i] - a.e23[i]*b.e123[i] - a.e31[i]*b.e3[i]
+              + a.e12[i]*b.e2[i] - a.e123[i]*b.e23[i];
+    out.e2[i] = a.e1[i]*b.e12[i] + a.e2[i]*b.s[i] - a.e3[i]*b.e23[i]
+              + a.s[i]*b.e2[i] + a.e23[i]*b.e3[i] - a.e31[i]*b.e123[i]
+              - a.e12[i]*b.e1[i] - a.e123[i]*b.e31[i];
+    out.e3[i] = a.e2[i]*b.e23[i] + a.e3[i]*b.s[i] + a.s[i]*b.e3[i]
+              + a.e31[i]*b.e1[i] - a.e1[i]*b.e31[i] - a.e23[i]*b.e2[i]
+              - a.e12[i]*b.e123[i] - a.e123[i]*b.e12[i];
+    out.s[i] = a.e1[i]*b.e1[i] + a.e2[i]*b.e2[i] + a.e3[i]*b.e3[i]
+             + a.s[i]*b.s[i] - a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i]
+             - a.e12[i]*b.e12[i] - a.e123[i]*b.e123[i];
+    out.e23[i] = a.e1[i]*b.e123[i] + a.s[i]*b.e23[i] + a.e23[i]*b.s[i]
+               + a.e123[i]*b.e1[i];
+    out.e31[i] = a.e2[i]*b.e123[i] + a.s[i]*b.e31[i] + a.e31[i]*b.s[i]
+               + a.e123[i]*b.e2[i];
+    out.e12[i] = a.e3[i]*b.e123[i] + a.s[i]*b.e12[i] + a.e12[i]*b.s[i]
+               + a.e123[i]*b.e3[i];
+    out.e01[i] = a.e3[i]*b.e013[i] + a.s[i]*b.e01[i] + a.e01[i]*b.s[i]
+               + a.e013[i]*b.e3[i] - a.e2[i]*b.e021[i] - a.e23[i]*b.e0123[i]
+               - a.e0123[i]*b.e23[i] - a.e021[i]*b.e2[i];
+    out.e02[i] = a.e1[i]*b.e021[i] - a.e3[i]*b.e032[i] + a.s[i]*b.e02[i]
+               - a.e31[i]*b.e0123[i] + a.e02[i]*b.s[i] - a.e0123[i]*b.e31[i]
+               - a.e032[i]*b.e3[i] + a.e021[i]*b.e1[i];
+    out.e03[i] = a.e2[i]*b.e032[i] + a.s[i]*b.e03[i] + a.e03[i]*b.s[i]
+               + a.e032[i]*b.e2[i] - a.e1[i]*b.e013[i] - a.e12[i]*b.e0123[i]
+               - a.e0123[i]*b.e12[i] - a.e013[i]*b.e1[i];
+    out.e0123[i] = a.s[i]*b.e0123[i] + a.e0123[i]*b.s[i];
+    out.e123[i] = a.s[i]*b.e123[i] + a.e123[i]*b.s[i];
+    out.e032[i] = a.e1[i]*b.e0123[i] + a.s[i]*b.e032[i] - a.e0123[i]*b.e1[i]
+                + a.e032[i]*b.s[i];
+    out.e013[i] = a.e2[i]*b.e0123[i] + a.s[i]*b.e013[i] - a.e0123[i]*b.e2[i]
+                + a.e013[i]*b.s[i];
+    out.e021[i] = a.e3[i]*b.e0123[i] + a.s[i]*b.e021[i] - a.e0123[i]*b.e3[i]
+                + a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_dot_scalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i];
+    out.e1[i] = a.e1[i]*b.s[i];
+    out.e2[i] = a.e2[i]*b.s[i];
+    out.e3[i] = a.e3[i]*b.s[i];
+    out.s[i] = a.s[i]*b.s[i];
+    out.e23[i] = a.e23[i]*b.s[i];
+    out.e31[i] = a.e31[i]*b.s[i];
+    out.e12[i] = a.e12[i]*b.s[i];
+    out.e01[i] = a.e01[i]*b.s[i];
+    out.e02[i] = a.e02[i]*b.s[i];
+    out.e03[i] = a.e03[i]*b.s[i];
+    out.e0123[i] = a.e0123[i]*b.s[i];
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e032[i]*b.s[i];
+    out.e013[i] = a.e013[i]*b.s[i];
+    out.e021[i] = a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_dot_vector<const N: usize>(
+  a: MultivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i] + a.e01[i]*b.e1[i] + a.e02[i]*b.e2[i]
+              + a.e03[i]*b.e3[i];
+    out.e1[i] = a.s[i]*b.e1[i] - a.e31[i]*b.e3[i] + a.e12[i]*b.e2[i];
+    out.e2[i] = a.s[i]*b.e2[i] + a.e23[i]*b.e3[i] - a.e12[i]*b.e1[i];
+    out.e3[i] = a.s[i]*b.e3[i] - a.e23[i]*b.e2[i] + a.e31[i]*b.e1[i];
+    out.s[i] = a.e1[i]*b.e1[i] + a.e2[i]*b.e2[i] + a.e3[i]*b.e3[i];
+    out.e23[i] = a.e123[i]*b.e1[i];
+    out.e31[i] = a.e123[i]*b.e2[i];
+    out.e12[i] = a.e123[i]*b.e3[i];
+    out.e01[i] = a.e013[i]*b.e3[i] - a.e021[i]*b.e2[i];
+    out.e02[i] = a.e021[i]*b.e1[i] - a.e032[i]*b.e3[i];
+    out.e03[i] = a.e032[i]*b.e2[i] - a.e013[i]*b.e1[i];
+    out.e032[i] = -a.e0123[i]*b.e1[i];
+    out.e013[i] = -a.e0123[i]*b.e2[i];
+    out.e021[i] = -a.e0123[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_dot_bivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e032[i]*b.e23[i] + a.e013[i]*b.e31[i] + a.e021[i]*b.e12[i]
+              - a.e1[i]*b.e01[i] - a.e2[i]*b.e02[i] - a.e3[i]*b.e03[i];
+    out.e1[i] = a.e3[i]*b.e31[i] - a.e2[i]*b.e12[i] - a.e123[i]*b.e23[i];
+    out.e2[i] = a.e1[i]*b.e12[i] - a.e3[i]*b.e23[i] - a.e123[i]*b.e31[i];
+    out.e3[i] = a.e2[i]*b.e23[i] - a.e1[i]*b.e31[i] - a.e123[i]*b.e12[i];
+    out.s[i] = -a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i] - a.e12[i]*b.e12[i];
+    out.e23[i] = a.s[i]*b.e23[i];
+    out.e31[i] = a.s[i]*b.e31[i];
+    out.e12[i] = a.s[i]*b.e12[i];
+    out.e01[i] = a.s[i]*b.e01[i] - a.e0123[i]*b.e23[i];
+    out.e02[i] = a.s[i]*b.e02[i] - a.e0123[i]*b.e31[i];
+    out.e03[i] = a.s[i]*b.e03[i] - a.e0123[i]*b.e12[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_dot_trivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e23[i]*b.e032[i] + a.e31[i]*b.e013[i] + a.e12[i]*b.e021[i]
+              - a.e0123[i]*b.e123[i];
+    out.e1[i] = -a.e23[i]*b.e123[i];
+    out.e2[i] = -a.e31[i]*b.e123[i];
+    out.e3[i] = -a.e12[i]*b.e123[i];
+    out.s[i] = -a.e123[i]*b.e123[i];
+    out.e23[i] = a.e1[i]*b.e123[i];
+    out.e31[i] = a.e2[i]*b.e123[i];
+    out.e12[i] = a.e3[i]*b.e123[i];
+    out.e01[i] = a.e3[i]*b.e013[i] - a.e2[i]*b.e021[i];
+    out.e02[i] = a.e1[i]*b.e021[i] - a.e3[i]*b.e032[i];
+    out.e03[i] = a.e2[i]*b.e032[i] - a.e1[i]*b.e013[i];
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i];
+    out.e013[i] = a.s[i]*b.e013[i];
+    out.e021[i] = a.s[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_dot_pseudoscalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e123[i]*b.e0123[i];
+    out.e01[i] = -a.e23[i]*b.e0123[i];
+    out.e02[i] = -a.e31[i]*b.e0123[i];
+    out.e03[i] = -a.e12[i]*b.e0123[i];
+    out.e0123[i] = a.s[i]*b.e0123[i];
+    out.e032[i] = a.e1[i]*b.e0123[i];
+    out.e013[i] = a.e2[i]*b.e0123[i];
+    out.e021[i] = a.e3[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_dot_multivector<const N: usize>(
+  a: ScalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i];
+    out.e1[i] = a.s[i]*b.e1[i];
+    out.e2[i] = a.s[i]*b.e2[i];
+    out.e3[i] = a.s[i]*b.e3[i];
+    out.s[i] = a.s[i]*b.s[i];
+    out.e23[i] = a.s[i]*b.e23[i];
+    out.e31[i] = a.s[i]*b.e31[i];
+    out.e12[i] = a.s[i]*b.e12[i];
+    out.e01[i] = a.s[i]*b.e01[i];
+    out.e02[i] = a.s[i]*b.e02[i];
+    out.e03[i] = a.s[i]*b.e03[i];
+    out.e0123[i] = a.s[i]*b.e0123[i];
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i];
+    out.e013[i] = a.s[i]*b.e013[i];
+    out.e021[i] = a.s[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_dot_scalar<const N: usize>(
+  a: ScalarX<N>,
+  b: ScalarX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_dot_vector<const N: usize>(
+  a: ScalarX<N>,
+  b: VectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.s[i]*b.e0[i];
+    out.e1[i] = a.s[i]*b.e1[i];
+    out.e2[i] = a.s[i]*b.e2[i];
+    out.e3[i] = a.s[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_dot_bivector<const N: usize>(
+  a: ScalarX<N>,
+  b: BivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.s[i]*b.e23[i];
+    out.e31[i] = a.s[i]*b.e31[i];
+    out.e12[i] = a.s[i]*b.e12[i];
+    out.e01[i] = a.s[i]*b.e01[i];
+    out.e02[i] = a.s[i]*b.e02[i];
+    out.e03[i] = a.s[i]*b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_dot_trivector<const N: usize>(
+  a: ScalarX<N>,
+  b: TrivectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.s[i]*b.e123[i];
+    out.e032[i] = a.s[i]*b.e032[i];
+    out.e013[i] = a.s[i]*b.e013[i];
+    out.e021[i] = a.s[i]*b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_dot_pseudoscalar<const N: usize>(
+  a: ScalarX<N>,
+  b: PseudoscalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.s[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_dot_multivector<const N: usize>(
+  a: VectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i] - a.e1[i]*b.e01[i] - a.e2[i]*b.e02[i]
+              - a.e3[i]*b.e03[i];
+    out.e1[i] = a.e1[i]*b.s[i] - a.e2[i]*b.e12[i] + a.e3[i]*b.e31[i];
+    out.e2[i] = a.e1[i]*b.e12[i] + a.e2[i]*b.s[i] - a.e3[i]*b.e23[i];
+    out.e3[i] = a.e2[i]*b.e23[i] + a.e3[i]*b.s[i] - a.e1[i]*b.e31[i];
+    out.s[i] = a.e1[i]*b.e1[i] + a.e2[i]*b.e2[i] + a.e3[i]*b.e3[i];
+    out.e23[i] = a.e1[i]*b.e123[i];
+    out.e31[i] = a.e2[i]*b.e123[i];
+    out.e12[i] = a.e3[i]*b.e123[i];
+    out.e01[i] = a.e3[i]*b.e013[i] - a.e2[i]*b.e021[i];
+    out.e02[i] = a.e1[i]*b.e021[i] - a.e3[i]*b.e032[i];
+    out.e03[i] = a.e2[i]*b.e032[i] - a.e1[i]*b.e013[i];
+    out.e032[i] = a.e1[i]*b.e0123[i];
+    out.e013[i] = a.e2[i]*b.e0123[i];
+    out.e021[i] = a.e3[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_dot_scalar<const N: usize>(
+  a: VectorX<N>,
+  b: ScalarX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i]*b.s[i];
+    out.e1[i] = a.e1[i]*b.s[i];
+    out.e2[i] = a.e2[i]*b.s[i];
+    out.e3[i] = a.e3[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_dot_vector<const N: usize>(
+  a: VectorX<N>,
+  b: VectorX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.e1[i]*b.e1[i] + a.e2[i]*b.e2[i] + a.e3[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_dot_bivector<const N: usize>(
+  a: VectorX<N>,
+  b: BivectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e1[i]*b.e01[i] - a.e2[i]*b.e02[i] - a.e3[i]*b.e03[i];
+    out.e1[i] = a.e3[i]*b.e31[i] - a.e2[i]*b.e12[i];
+    out.e2[i] = a.e1[i]*b.e12[i] - a.e3[i]*b.e23[i];
+    out.e3[i] = a.e2[i]*b.e23[i] - a.e1[i]*b.e31[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_dot_trivector<const N: usize>(
+  a: VectorX<N>,
+  b: TrivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e1[i]*b.e123[i];
+    out.e31[i] = a.e2[i]*b.e123[i];
+    out.e12[i] = a.e3[i]*b.e123[i];
+    out.e01[i] = a.e3[i]*b.e013[i] - a.e2[i]*b.e021[i];
+    out.e02[i] = a.e1[i]*b.e021[i] - a.e3[i]*b.e032[i];
+    out.e03[i] = a.e2[i]*b.e032[i] - a.e1[i]*b.e013[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_dot_pseudoscalar<const N: usize>(
+  a: VectorX<N>,
+  b: PseudoscalarX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e032[i] = a.e1[i]*b.e0123[i];
+    out.e013[i] = a.e2[i]*b.e0123[i];
+    out.e021[i] = a.e3[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_dot_multivector<const N: usize>(
+  a: BivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e23[i]*b.e032[i] + a.e31[i]*b.e013[i] + a.e12[i]*b.e021[i]
+              + a.e01[i]*b.e1[i] + a.e02[i]*b.e2[i] + a.e03[i]*b.e3[i];
+    out.e1[i] = a.e12[i]*b.e2[i] - a.e23[i]*b.e123[i] - a.e31[i]*b.e3[i];
+    out.e2[i] = a.e23[i]*b.e3[i] - a.e31[i]*b.e123[i] - a.e12[i]*b.e1[i];
+    out.e3[i] = a.e31[i]*b.e1[i] - a.e23[i]*b.e2[i] - a.e12[i]*b.e123[i];
+    out.s[i] = -a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i] - a.e12[i]*b.e12[i];
+    out.e23[i] = a.e23[i]*b.s[i];
+    out.e31[i] = a.e31[i]*b.s[i];
+    out.e12[i] = a.e12[i]*b.s[i];
+    out.e01[i] = a.e01[i]*b.s[i] - a.e23[i]*b.e0123[i];
+    out.e02[i] = a.e02[i]*b.s[i] - a.e31[i]*b.e0123[i];
+    out.e03[i] = a.e03[i]*b.s[i] - a.e12[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_dot_scalar<const N: usize>(
+  a: BivectorX<N>,
+  b: ScalarX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i]*b.s[i];
+    out.e31[i] = a.e31[i]*b.s[i];
+    out.e12[i] = a.e12[i]*b.s[i];
+    out.e01[i] = a.e01[i]*b.s[i];
+    out.e02[i] = a.e02[i]*b.s[i];
+    out.e03[i] = a.e03[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_dot_vector<const N: usize>(
+  a: BivectorX<N>,
+  b: VectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e01[i]*b.e1[i] + a.e02[i]*b.e2[i] + a.e03[i]*b.e3[i];
+    out.e1[i] = a.e12[i]*b.e2[i] - a.e31[i]*b.e3[i];
+    out.e2[i] = a.e23[i]*b.e3[i] - a.e12[i]*b.e1[i];
+    out.e3[i] = a.e31[i]*b.e1[i] - a.e23[i]*b.e2[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_dot_bivector<const N: usize>(
+  a: BivectorX<N>,
+  b: BivectorX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = -a.e23[i]*b.e23[i] - a.e31[i]*b.e31[i] - a.e12[i]*b.e12[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_dot_trivector<const N: usize>(
+  a: BivectorX<N>,
+  b: TrivectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e23[i]*b.e032[i] + a.e31[i]*b.e013[i] + a.e12[i]*b.e021[i];
+    out.e1[i] = -a.e23[i]*b.e123[i];
+    out.e2[i] = -a.e31[i]*b.e123[i];
+    out.e3[i] = -a.e12[i]*b.e123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_dot_pseudoscalar<const N: usize>(
+  a: BivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e01[i] = -a.e23[i]*b.e0123[i];
+    out.e02[i] = -a.e31[i]*b.e0123[i];
+    out.e03[i] = -a.e12[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_dot_multivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e123[i]*b.e0123[i] + a.e032[i]*b.e23[i] + a.e013[i]*b.e31[i]
+              + a.e021[i]*b.e12[i];
+    out.e1[i] = -a.e123[i]*b.e23[i];
+    out.e2[i] = -a.e123[i]*b.e31[i];
+    out.e3[i] = -a.e123[i]*b.e12[i];
+    out.s[i] = -a.e123[i]*b.e123[i];
+    out.e23[i] = a.e123[i]*b.e1[i];
+    out.e31[i] = a.e123[i]*b.e2[i];
+    out.e12[i] = a.e123[i]*b.e3[i];
+    out.e01[i] = a.e013[i]*b.e3[i] - a.e021[i]*b.e2[i];
+    out.e02[i] = a.e021[i]*b.e1[i] - a.e032[i]*b.e3[i];
+    out.e03[i] = a.e032[i]*b.e2[i] - a.e013[i]*b.e1[i];
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e032[i]*b.s[i];
+    out.e013[i] = a.e013[i]*b.s[i];
+    out.e021[i] = a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_dot_scalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: ScalarX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e123[i]*b.s[i];
+    out.e032[i] = a.e032[i]*b.s[i];
+    out.e013[i] = a.e013[i]*b.s[i];
+    out.e021[i] = a.e021[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_dot_vector<const N: usize>(
+  a: TrivectorX<N>,
+  b: VectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e123[i]*b.e1[i];
+    out.e31[i] = a.e123[i]*b.e2[i];
+    out.e12[i] = a.e123[i]*b.e3[i];
+    out.e01[i] = a.e013[i]*b.e3[i] - a.e021[i]*b.e2[i];
+    out.e02[i] = a.e021[i]*b.e1[i] - a.e032[i]*b.e3[i];
+    out.e03[i] = a.e032[i]*b.e2[i] - a.e013[i]*b.e1[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_dot_bivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: BivectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e032[i]*b.e23[i] + a.e013[i]*b.e31[i] + a.e021[i]*b.e12[i];
+    out.e1[i] = -a.e123[i]*b.e23[i];
+    out.e2[i] = -a.e123[i]*b.e31[i];
+    out.e3[i] = -a.e123[i]*b.e12[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_dot_trivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: TrivectorX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = -a.e123[i]*b.e123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_dot_pseudoscalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e123[i]*b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_dot_multivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e0123[i]*b.e123[i];
+    out.e01[i] = -a.e0123[i]*b.e23[i];
+    out.e02[i] = -a.e0123[i]*b.e31[i];
+    out.e03[i] = -a.e0123[i]*b.e12[i];
+    out.e0123[i] = a.e0123[i]*b.s[i];
+    out.e032[i] = -a.e0123[i]*b.e1[i];
+    out.e013[i] = -a.e0123[i]*b.e2[i];
+    out.e021[i] = -a.e0123[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_dot_scalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: ScalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i]*b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_dot_vector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: VectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e032[i] = -a.e0123[i]*b.e1[i];
+    out.e013[i] = -a.e0123[i]*b.e2[i];
+    out.e021[i] = -a.e0123[i]*b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_dot_bivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: BivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e01[i] = -a.e0123[i]*b.e23[i];
+    out.e02[i] = -a.e0123[i]*b.e31[i];
+    out.e03[i] = -a.e0123[i]*b.e12[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_dot_trivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: TrivectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -a.e0123[i]*b.e123[i];
+  }
+  out
+}
+
+// Add
+
+impl_binary! { Add::add {
+  multivector_add_multivector: MultivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  multivector_add_scalar: MultivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  multivector_add_vector: MultivectorX<N>, VectorX<N> => MultivectorX<N>;
+  multivector_add_bivector: MultivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  multivector_add_trivector: MultivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  multivector_add_pseudoscalar: MultivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: MultivectorX<N>, Empty => MultivectorX<N>;
+  scalar_add_multivector: ScalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  scalar_add_scalar: ScalarX<N>, ScalarX<N> => ScalarX<N>;
+  scalar_add_vector: ScalarX<N>, VectorX<N> => MultivectorX<N>;
+  scalar_add_bivector: ScalarX<N>, BivectorX<N> => MultivectorX<N>;
+  scalar_add_trivector: ScalarX<N>, TrivectorX<N> => MultivectorX<N>;
+  scalar_add_pseudoscalar: ScalarX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: ScalarX<N>, Empty => ScalarX<N>;
+  vector_add_multivector: VectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  vector_add_scalar: VectorX<N>, ScalarX<N> => MultivectorX<N>;
+  vector_add_vector: VectorX<N>, VectorX<N> => VectorX<N>;
+  vector_add_bivector: VectorX<N>, BivectorX<N> => MultivectorX<N>;
+  vector_add_trivector: VectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  vector_add_pseudoscalar: VectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: VectorX<N>, Empty => VectorX<N>;
+  bivector_add_multivector: BivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  bivector_add_scalar: BivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  bivector_add_vector: BivectorX<N>, VectorX<N> => MultivectorX<N>;
+  bivector_add_bivector: BivectorX<N>, BivectorX<N> => BivectorX<N>;
+  bivector_add_trivector: BivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  bivector_add_pseudoscalar: BivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: BivectorX<N>, Empty => BivectorX<N>;
+  trivector_add_multivector: TrivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  trivector_add_scalar: TrivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  trivector_add_vector: TrivectorX<N>, VectorX<N> => MultivectorX<N>;
+  trivector_add_bivector: TrivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  trivector_add_trivector: TrivectorX<N>, TrivectorX<N> => TrivectorX<N>;
+  trivector_add_pseudoscalar: TrivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: TrivectorX<N>, Empty => TrivectorX<N>;
+  pseudoscalar_add_multivector: PseudoscalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  pseudoscalar_add_scalar: PseudoscalarX<N>, ScalarX<N> => MultivectorX<N>;
+  pseudoscalar_add_vector: PseudoscalarX<N>, VectorX<N> => MultivectorX<N>;
+  pseudoscalar_add_bivector: PseudoscalarX<N>, BivectorX<N> => MultivectorX<N>;
+  pseudoscalar_add_trivector: PseudoscalarX<N>, TrivectorX<N> => MultivectorX<N>;
+  pseudoscalar_add_pseudoscalar: PseudoscalarX<N>, PseudoscalarX<N> => PseudoscalarX<N>;
+  return_lhs: PseudoscalarX<N>, Empty => PseudoscalarX<N>;
+} }
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_add_multivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i] + b.e0[i];
+    out.e1[i] = a.e1[i] + b.e1[i];
+    out.e2[i] = a.e2[i] + b.e2[i];
+    out.e3[i] = a.e3[i] + b.e3[i];
+    out.s[i] = a.s[i] + b.s[i];
+    out.e23[i] = a.e23[i] + b.e23[i];
+    out.e31[i] = a.e31[i] + b.e31[i];
+    out.e12[i] = a.e12[i] + b.e12[i];
+    out.e01[i] = a.e01[i] + b.e01[i];
+    out.e02[i] = a.e02[i] + b.e02[i];
+    out.e03[i] = a.e03[i] + b.e03[i];
+    out.e0123[i] = a.e0123[i] + b.e0123[i];
+    out.e123[i] = a.e123[i] + b.e123[i];
+    out.e032[i] = a.e032[i] + b.e032[i];
+    out.e013[i] = a.e013[i] + b.e013[i];
+    out.e021[i] = a.e021[i] + b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_add_scalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = a.s[i] + b.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_add_vector<const N: usize>(
+  a: MultivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i] + b.e0[i];
+    out.e1[i] = a.e1[i] + b.e1[i];
+    out.e2[i] = a.e2[i] + b.e2[i];
+    out.e3[i] = a.e3[i] + b.e3[i];
+    out.s[i] = a.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_add_bivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = a.s[i];
+    out.e23[i] = a.e23[i] + b.e23[i];
+    out.e31[i] = a.e31[i] + b.e31[i];
+    out.e12[i] = a.e12[i] + b.e12[i];
+    out.e01[i] = a.e01[i] + b.e01[i];
+    out.e02[i] = a.e02[i] + b.e02[i];
+    out.e03[i] = a.e03[i] + b.e03[i];
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_add_trivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = a.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = a.e123[i] + b.e123[i];
+    out.e032[i] = a.e032[i] + b.e032[i];
+    out.e013[i] = a.e013[i] + b.e013[i];
+    out.e021[i] = a.e021[i] + b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_add_pseudoscalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = a.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = a.e0123[i] + b.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_add_multivector<const N: usize>(
+  a: ScalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = b.e0[i];
+    out.e1[i] = b.e1[i];
+    out.e2[i] = b.e2[i];
+    out.e3[i] = b.e3[i];
+    out.s[i] = a.s[i] + b.s[i];
+    out.e23[i] = b.e23[i];
+    out.e31[i] = b.e31[i];
+    out.e12[i] = b.e12[i];
+    out.e01[i] = b.e01[i];
+    out.e02[i] = b.e02[i];
+    out.e03[i] = b.e03[i];
+    out.e0123[i] = b.e0123[i];
+    out.e123[i] = b.e123[i];
+    out.e032[i] = b.e032[i];
+    out.e013[i] = b.e013[i];
+    out.e021[i] = b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_add_scalar<const N: usize>(
+  a: ScalarX<N>,
+  b: ScalarX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i] + b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_add_vector<const N: usize>(
+  a: ScalarX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = b.e0[i];
+    out.e1[i] = b.e1[i];
+    out.e2[i] = b.e2[i];
+    out.e3[i] = b.e3[i];
+    out.s[i] = a.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_add_bivector<const N: usize>(
+  a: ScalarX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i];
+    out.e23[i] = b.e23[i];
+    out.e31[i] = b.e31[i];
+    out.e12[i] = b.e12[i];
+    out.e01[i] = b.e01[i];
+    out.e02[i] = b.e02[i];
+    out.e03[i] = b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_add_trivector<const N: usize>(
+  a: ScalarX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i];
+    out.e123[i] = b.e123[i];
+    out.e032[i] = b.e032[i];
+    out.e013[i] = b.e013[i];
+    out.e021[i] = b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_add_pseudoscalar<const N: usize>(
+  a: ScalarX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i];
+    out.e0123[i] = b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_add_multivector<const N: usize>(
+  a: VectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i] + b.e0[i];
+    out.e1[i] = a.e1[i] + b.e1[i];
+    out.e2[i] = a.e2[i] + b.e2[i];
+    out.e3[i] = a.e3[i] + b.e3[i];
+    out.s[i] = b.s[i];
+    out.e23[i] = b.e23[i];
+    out.e31[i] = b.e31[i];
+    out.e12[i] = b.e12[i];
+    out.e01[i] = b.e01[i];
+    out.e02[i] = b.e02[i];
+    out.e03[i] = b.e03[i];
+    out.e0123[i] = b.e0123[i];
+    out.e123[i] = b.e123[i];
+    out.e032[i] = b.e032[i];
+    out.e013[i] = b.e013[i];
+    out.e021[i] = b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_add_scalar<const N: usize>(
+  a: VectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_add_vector<const N: usize>(
+  a: VectorX<N>,
+  b: VectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i] + b.e0[i];
+    out.e1[i] = a.e1[i] + b.e1[i];
+    out.e2[i] = a.e2[i] + b.e2[i];
+    out.e3[i] = a.e3[i] + b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_add_bivector<const N: usize>(
+  a: VectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.e23[i] = b.e23[i];
+    out.e31[i] = b.e31[i];
+    out.e12[i] = b.e12[i];
+    out.e01[i] = b.e01[i];
+    out.e02[i] = b.e02[i];
+    out.e03[i] = b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_add_trivector<const N: usize>(
+  a: VectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.e123[i] = b.e123[i];
+    out.e032[i] = b.e032[i];
+    out.e013[i] = b.e013[i];
+    out.e021[i] = b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_add_pseudoscalar<const N: usize>(
+  a: VectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.e0123[i] = b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_add_multivector<const N: usize>(
+  a: BivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = b.e0[i];
+    out.e1[i] = b.e1[i];
+    out.e2[i] = b.e2[i];
+    out.e3[i] = b.e3[i];
+    out.s[i] = b.s[i];
+    out.e23[i] = a.e23[i] + b.e23[i];
+    out.e31[i] = a.e31[i] + b.e31[i];
+    out.e12[i] = a.e12[i] + b.e12[i];
+    out.e01[i] = a.e01[i] + b.e01[i];
+    out.e02[i] = a.e02[i] + b.e02[i];
+    out.e03[i] = a.e03[i] + b.e03[i];
+    out.e0123[i] = b.e0123[i];
+    out.e123[i] = b.e123[i];
+    out.e032[i] = b.e032[i];
+    out.e013[i] = b.e013[i];
+    out.e021[i] = b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_add_scalar<const N: usize>(
+  a: BivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = b.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_add_vector<const N: usize>(
+  a: BivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = b.e0[i];
+    out.e1[i] = b.e1[i];
+    out.e2[i] = b.e2[i];
+    out.e3[i] = b.e3[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_add_bivector<const N: usize>(
+  a: BivectorX<N>,
+  b: BivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i] + b.e23[i];
+    out.e31[i] = a.e31[i] + b.e31[i];
+    out.e12[i] = a.e12[i] + b.e12[i];
+    out.e01[i] = a.e01[i] + b.e01[i];
+    out.e02[i] = a.e02[i] + b.e02[i];
+    out.e03[i] = a.e03[i] + b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_add_trivector<const N: usize>(
+  a: BivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e123[i] = b.e123[i];
+    out.e032[i] = b.e032[i];
+    out.e013[i] = b.e013[i];
+    out.e021[i] = b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_add_pseudoscalar<const N: usize>(
+  a: BivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_add_multivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = b.e0[i];
+    out.e1[i] = b.e1[i];
+    out.e2[i] = b.e2[i];
+    out.e3[i] = b.e3[i];
+    out.s[i] = b.s[i];
+    out.e23[i] = b.e23[i];
+    out.e31[i] = b.e31[i];
+    out.e12[i] = b.e12[i];
+    out.e01[i] = b.e01[i];
+    out.e02[i] = b.e02[i];
+    out.e03[i] = b.e03[i];
+    out.e0123[i] = b.e0123[i];
+    out.e123[i] = a.e123[i] + b.e123[i];
+    out.e032[i] = a.e032[i] + b.e032[i];
+    out.e013[i] = a.e013[i] + b.e013[i];
+    out.e021[i] = a.e021[i] + b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_add_scalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = b.s[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_add_vector<const N: usize>(
+  a: TrivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = b.e0[i];
+    out.e1[i] = b.e1[i];
+    out.e2[i] = b.e2[i];
+    out.e3[i] = b.e3[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_add_bivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = b.e23[i];
+    out.e31[i] = b.e31[i];
+    out.e12[i] = b.e12[i];
+    out.e01[i] = b.e01[i];
+    out.e02[i] = b.e02[i];
+    out.e03[i] = b.e03[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_add_trivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: TrivectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e123[i] + b.e123[i];
+    out.e032[i] = a.e032[i] + b.e032[i];
+    out.e013[i] = a.e013[i] + b.e013[i];
+    out.e021[i] = a.e021[i] + b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_add_pseudoscalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0123[i] = b.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_add_multivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = b.e0[i];
+    out.e1[i] = b.e1[i];
+    out.e2[i] = b.e2[i];
+    out.e3[i] = b.e3[i];
+    out.s[i] = b.s[i];
+    out.e23[i] = b.e23[i];
+    out.e31[i] = b.e31[i];
+    out.e12[i] = b.e12[i];
+    out.e01[i] = b.e01[i];
+    out.e02[i] = b.e02[i];
+    out.e03[i] = b.e03[i];
+    out.e0123[i] = a.e0123[i] + b.e0123[i];
+    out.e123[i] = b.e123[i];
+    out.e032[i] = b.e032[i];
+    out.e013[i] = b.e013[i];
+    out.e021[i] = b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_add_scalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = b.s[i];
+    out.e0123[i] = a.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_add_vector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = b.e0[i];
+    out.e1[i] = b.e1[i];
+    out.e2[i] = b.e2[i];
+    out.e3[i] = b.e3[i];
+    out.e0123[i] = a.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_add_bivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = b.e23[i];
+    out.e31[i] = b.e31[i];
+    out.e12[i] = b.e12[i];
+    out.e01[i] = b.e01[i];
+    out.e02[i] = b.e02[i];
+    out.e03[i] = b.e03[i];
+    out.e0123[i] = a.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_add_trivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = b.e123[i];
+    out.e032[i] = b.e032[i];
+    out.e013[i] = b.e013[i];
+    out.e021[i] = b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_add_pseudoscalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: PseudoscalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i] + b.e0123[i];
+  }
+  out
+}
+
+// Sub
+
+impl_binary! { Sub::sub {
+  multivector_sub_multivector: MultivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  multivector_sub_scalar: MultivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  multivector_sub_vector: MultivectorX<N>, VectorX<N> => MultivectorX<N>;
+  multivector_sub_bivector: MultivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  multivector_sub_trivector: MultivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  multivector_sub_pseudoscalar: MultivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: MultivectorX<N>, Empty => MultivectorX<N>;
+  scalar_sub_multivector: ScalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  scalar_sub_scalar: ScalarX<N>, ScalarX<N> => ScalarX<N>;
+  scalar_sub_vector: ScalarX<N>, VectorX<N> => MultivectorX<N>;
+  scalar_sub_bivector: ScalarX<N>, BivectorX<N> => MultivectorX<N>;
+  scalar_sub_trivector: ScalarX<N>, TrivectorX<N> => MultivectorX<N>;
+  scalar_sub_pseudoscalar: ScalarX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: ScalarX<N>, Empty => ScalarX<N>;
+  vector_sub_multivector: VectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  vector_sub_scalar: VectorX<N>, ScalarX<N> => MultivectorX<N>;
+  vector_sub_vector: VectorX<N>, VectorX<N> => VectorX<N>;
+  vector_sub_bivector: VectorX<N>, BivectorX<N> => MultivectorX<N>;
+  vector_sub_trivector: VectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  vector_sub_pseudoscalar: VectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: VectorX<N>, Empty => VectorX<N>;
+  bivector_sub_multivector: BivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  bivector_sub_scalar: BivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  bivector_sub_vector: BivectorX<N>, VectorX<N> => MultivectorX<N>;
+  bivector_sub_bivector: BivectorX<N>, BivectorX<N> => BivectorX<N>;
+  bivector_sub_trivector: BivectorX<N>, TrivectorX<N> => MultivectorX<N>;
+  bivector_sub_pseudoscalar: BivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: BivectorX<N>, Empty => BivectorX<N>;
+  trivector_sub_multivector: TrivectorX<N>, MultivectorX<N> => MultivectorX<N>;
+  trivector_sub_scalar: TrivectorX<N>, ScalarX<N> => MultivectorX<N>;
+  trivector_sub_vector: TrivectorX<N>, VectorX<N> => MultivectorX<N>;
+  trivector_sub_bivector: TrivectorX<N>, BivectorX<N> => MultivectorX<N>;
+  trivector_sub_trivector: TrivectorX<N>, TrivectorX<N> => TrivectorX<N>;
+  trivector_sub_pseudoscalar: TrivectorX<N>, PseudoscalarX<N> => MultivectorX<N>;
+  return_lhs: TrivectorX<N>, Empty => TrivectorX<N>;
+  pseudoscalar_sub_multivector: PseudoscalarX<N>, MultivectorX<N> => MultivectorX<N>;
+  pseudoscalar_sub_scalar: PseudoscalarX<N>, ScalarX<N> => MultivectorX<N>;
+  pseudoscalar_sub_vector: PseudoscalarX<N>, VectorX<N> => MultivectorX<N>;
+  pseudoscalar_sub_bivector: PseudoscalarX<N>, BivectorX<N> => MultivectorX<N>;
+  pseudoscalar_sub_trivector: PseudoscalarX<N>, TrivectorX<N> => MultivectorX<N>;
+  pseudoscalar_sub_pseudoscalar: PseudoscalarX<N>, PseudoscalarX<N> => PseudoscalarX<N>;
+  return_lhs: PseudoscalarX<N>, Empty => PseudoscalarX<N>;
+} }
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_sub_multivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i] - b.e0[i];
+    out.e1[i] = a.e1[i] - b.e1[i];
+    out.e2[i] = a.e2[i] - b.e2[i];
+    out.e3[i] = a.e3[i] - b.e3[i];
+    out.s[i] = a.s[i] - b.s[i];
+    out.e23[i] = a.e23[i] - b.e23[i];
+    out.e31[i] = a.e31[i] - b.e31[i];
+    out.e12[i] = a.e12[i] - b.e12[i];
+    out.e01[i] = a.e01[i] - b.e01[i];
+    out.e02[i] = a.e02[i] - b.e02[i];
+    out.e03[i] = a.e03[i] - b.e03[i];
+    out.e0123[i] = a.e0123[i] - b.e0123[i];
+    out.e123[i] = a.e123[i] - b.e123[i];
+    out.e032[i] = a.e032[i] - b.e032[i];
+    out.e013[i] = a.e013[i] - b.e013[i];
+    out.e021[i] = a.e021[i] - b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_sub_scalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = a.s[i] - b.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_sub_vector<const N: usize>(
+  a: MultivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i] - b.e0[i];
+    out.e1[i] = a.e1[i] - b.e1[i];
+    out.e2[i] = a.e2[i] - b.e2[i];
+    out.e3[i] = a.e3[i] - b.e3[i];
+    out.s[i] = a.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_sub_bivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = a.s[i];
+    out.e23[i] = a.e23[i] - b.e23[i];
+    out.e31[i] = a.e31[i] - b.e31[i];
+    out.e12[i] = a.e12[i] - b.e12[i];
+    out.e01[i] = a.e01[i] - b.e01[i];
+    out.e02[i] = a.e02[i] - b.e02[i];
+    out.e03[i] = a.e03[i] - b.e03[i];
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_sub_trivector<const N: usize>(
+  a: MultivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = a.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = a.e123[i] - b.e123[i];
+    out.e032[i] = a.e032[i] - b.e032[i];
+    out.e013[i] = a.e013[i] - b.e013[i];
+    out.e021[i] = a.e021[i] - b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn multivector_sub_pseudoscalar<const N: usize>(
+  a: MultivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = a.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = a.e0123[i] - b.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_sub_multivector<const N: usize>(
+  a: ScalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -b.e0[i];
+    out.e1[i] = -b.e1[i];
+    out.e2[i] = -b.e2[i];
+    out.e3[i] = -b.e3[i];
+    out.s[i] = a.s[i] - b.s[i];
+    out.e23[i] = -b.e23[i];
+    out.e31[i] = -b.e31[i];
+    out.e12[i] = -b.e12[i];
+    out.e01[i] = -b.e01[i];
+    out.e02[i] = -b.e02[i];
+    out.e03[i] = -b.e03[i];
+    out.e0123[i] = -b.e0123[i];
+    out.e123[i] = -b.e123[i];
+    out.e032[i] = -b.e032[i];
+    out.e013[i] = -b.e013[i];
+    out.e021[i] = -b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_sub_scalar<const N: usize>(
+  a: ScalarX<N>,
+  b: ScalarX<N>,
+) -> ScalarX<N> {
+  let mut out = ScalarX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i] - b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_sub_vector<const N: usize>(
+  a: ScalarX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -b.e0[i];
+    out.e1[i] = -b.e1[i];
+    out.e2[i] = -b.e2[i];
+    out.e3[i] = -b.e3[i];
+    out.s[i] = a.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_sub_bivector<const N: usize>(
+  a: ScalarX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i];
+    out.e23[i] = -b.e23[i];
+    out.e31[i] = -b.e31[i];
+    out.e12[i] = -b.e12[i];
+    out.e01[i] = -b.e01[i];
+    out.e02[i] = -b.e02[i];
+    out.e03[i] = -b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_sub_trivector<const N: usize>(
+  a: ScalarX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i];
+    out.e123[i] = -b.e123[i];
+    out.e032[i] = -b.e032[i];
+    out.e013[i] = -b.e013[i];
+    out.e021[i] = -b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn scalar_sub_pseudoscalar<const N: usize>(
+  a: ScalarX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = a.s[i];
+    out.e0123[i] = -b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_sub_multivector<const N: usize>(
+  a: VectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i] - b.e0[i];
+    out.e1[i] = a.e1[i] - b.e1[i];
+    out.e2[i] = a.e2[i] - b.e2[i];
+    out.e3[i] = a.e3[i] - b.e3[i];
+    out.s[i] = -b.s[i];
+    out.e23[i] = -b.e23[i];
+    out.e31[i] = -b.e31[i];
+    out.e12[i] = -b.e12[i];
+    out.e01[i] = -b.e01[i];
+    out.e02[i] = -b.e02[i];
+    out.e03[i] = -b.e03[i];
+    out.e0123[i] = -b.e0123[i];
+    out.e123[i] = -b.e123[i];
+    out.e032[i] = -b.e032[i];
+    out.e013[i] = -b.e013[i];
+    out.e021[i] = -b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_sub_scalar<const N: usize>(
+  a: VectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.s[i] = -b.s[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_sub_vector<const N: usize>(
+  a: VectorX<N>,
+  b: VectorX<N>,
+) -> VectorX<N> {
+  let mut out = VectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i] - b.e0[i];
+    out.e1[i] = a.e1[i] - b.e1[i];
+    out.e2[i] = a.e2[i] - b.e2[i];
+    out.e3[i] = a.e3[i] - b.e3[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_sub_bivector<const N: usize>(
+  a: VectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.e23[i] = -b.e23[i];
+    out.e31[i] = -b.e31[i];
+    out.e12[i] = -b.e12[i];
+    out.e01[i] = -b.e01[i];
+    out.e02[i] = -b.e02[i];
+    out.e03[i] = -b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_sub_trivector<const N: usize>(
+  a: VectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.e123[i] = -b.e123[i];
+    out.e032[i] = -b.e032[i];
+    out.e013[i] = -b.e013[i];
+    out.e021[i] = -b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn vector_sub_pseudoscalar<const N: usize>(
+  a: VectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = a.e0[i];
+    out.e1[i] = a.e1[i];
+    out.e2[i] = a.e2[i];
+    out.e3[i] = a.e3[i];
+    out.e0123[i] = -b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_sub_multivector<const N: usize>(
+  a: BivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -b.e0[i];
+    out.e1[i] = -b.e1[i];
+    out.e2[i] = -b.e2[i];
+    out.e3[i] = -b.e3[i];
+    out.s[i] = -b.s[i];
+    out.e23[i] = a.e23[i] - b.e23[i];
+    out.e31[i] = a.e31[i] - b.e31[i];
+    out.e12[i] = a.e12[i] - b.e12[i];
+    out.e01[i] = a.e01[i] - b.e01[i];
+    out.e02[i] = a.e02[i] - b.e02[i];
+    out.e03[i] = a.e03[i] - b.e03[i];
+    out.e0123[i] = -b.e0123[i];
+    out.e123[i] = -b.e123[i];
+    out.e032[i] = -b.e032[i];
+    out.e013[i] = -b.e013[i];
+    out.e021[i] = -b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_sub_scalar<const N: usize>(
+  a: BivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = -b.s[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_sub_vector<const N: usize>(
+  a: BivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -b.e0[i];
+    out.e1[i] = -b.e1[i];
+    out.e2[i] = -b.e2[i];
+    out.e3[i] = -b.e3[i];
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_sub_bivector<const N: usize>(
+  a: BivectorX<N>,
+  b: BivectorX<N>,
+) -> BivectorX<N> {
+  let mut out = BivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i] - b.e23[i];
+    out.e31[i] = a.e31[i] - b.e31[i];
+    out.e12[i] = a.e12[i] - b.e12[i];
+    out.e01[i] = a.e01[i] - b.e01[i];
+    out.e02[i] = a.e02[i] - b.e02[i];
+    out.e03[i] = a.e03[i] - b.e03[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_sub_trivector<const N: usize>(
+  a: BivectorX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e123[i] = -b.e123[i];
+    out.e032[i] = -b.e032[i];
+    out.e013[i] = -b.e013[i];
+    out.e021[i] = -b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn bivector_sub_pseudoscalar<const N: usize>(
+  a: BivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = a.e23[i];
+    out.e31[i] = a.e31[i];
+    out.e12[i] = a.e12[i];
+    out.e01[i] = a.e01[i];
+    out.e02[i] = a.e02[i];
+    out.e03[i] = a.e03[i];
+    out.e0123[i] = -b.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_sub_multivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -b.e0[i];
+    out.e1[i] = -b.e1[i];
+    out.e2[i] = -b.e2[i];
+    out.e3[i] = -b.e3[i];
+    out.s[i] = -b.s[i];
+    out.e23[i] = -b.e23[i];
+    out.e31[i] = -b.e31[i];
+    out.e12[i] = -b.e12[i];
+    out.e01[i] = -b.e01[i];
+    out.e02[i] = -b.e02[i];
+    out.e03[i] = -b.e03[i];
+    out.e0123[i] = -b.e0123[i];
+    out.e123[i] = a.e123[i] - b.e123[i];
+    out.e032[i] = a.e032[i] - b.e032[i];
+    out.e013[i] = a.e013[i] - b.e013[i];
+    out.e021[i] = a.e021[i] - b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_sub_scalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = -b.s[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_sub_vector<const N: usize>(
+  a: TrivectorX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -b.e0[i];
+    out.e1[i] = -b.e1[i];
+    out.e2[i] = -b.e2[i];
+    out.e3[i] = -b.e3[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_sub_bivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = -b.e23[i];
+    out.e31[i] = -b.e31[i];
+    out.e12[i] = -b.e12[i];
+    out.e01[i] = -b.e01[i];
+    out.e02[i] = -b.e02[i];
+    out.e03[i] = -b.e03[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_sub_trivector<const N: usize>(
+  a: TrivectorX<N>,
+  b: TrivectorX<N>,
+) -> TrivectorX<N> {
+  let mut out = TrivectorX::zero();
+  for i in 0..N {
+    out.e123[i] = a.e123[i] - b.e123[i];
+    out.e032[i] = a.e032[i] - b.e032[i];
+    out.e013[i] = a.e013[i] - b.e013[i];
+    out.e021[i] = a.e021[i] - b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn trivector_sub_pseudoscalar<const N: usize>(
+  a: TrivectorX<N>,
+  b: PseudoscalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0123[i] = -b.e0123[i];
+    out.e123[i] = a.e123[i];
+    out.e032[i] = a.e032[i];
+    out.e013[i] = a.e013[i];
+    out.e021[i] = a.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_sub_multivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: MultivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -b.e0[i];
+    out.e1[i] = -b.e1[i];
+    out.e2[i] = -b.e2[i];
+    out.e3[i] = -b.e3[i];
+    out.s[i] = -b.s[i];
+    out.e23[i] = -b.e23[i];
+    out.e31[i] = -b.e31[i];
+    out.e12[i] = -b.e12[i];
+    out.e01[i] = -b.e01[i];
+    out.e02[i] = -b.e02[i];
+    out.e03[i] = -b.e03[i];
+    out.e0123[i] = a.e0123[i] - b.e0123[i];
+    out.e123[i] = -b.e123[i];
+    out.e032[i] = -b.e032[i];
+    out.e013[i] = -b.e013[i];
+    out.e021[i] = -b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_sub_scalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: ScalarX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.s[i] = -b.s[i];
+    out.e0123[i] = a.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_sub_vector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: VectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0[i] = -b.e0[i];
+    out.e1[i] = -b.e1[i];
+    out.e2[i] = -b.e2[i];
+    out.e3[i] = -b.e3[i];
+    out.e0123[i] = a.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_sub_bivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: BivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e23[i] = -b.e23[i];
+    out.e31[i] = -b.e31[i];
+    out.e12[i] = -b.e12[i];
+    out.e01[i] = -b.e01[i];
+    out.e02[i] = -b.e02[i];
+    out.e03[i] = -b.e03[i];
+    out.e0123[i] = a.e0123[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_sub_trivector<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: TrivectorX<N>,
+) -> MultivectorX<N> {
+  let mut out = MultivectorX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i];
+    out.e123[i] = -b.e123[i];
+    out.e032[i] = -b.e032[i];
+    out.e013[i] = -b.e013[i];
+    out.e021[i] = -b.e021[i];
+  }
+  out
+}
+
+#[rustfmt::skip]
+#[inline]
+fn pseudoscalar_sub_pseudoscalar<const N: usize>(
+  a: PseudoscalarX<N>,
+  b: PseudoscalarX<N>,
+) -> PseudoscalarX<N> {
+  let mut out = PseudoscalarX::zero();
+  for i in 0..N {
+    out.e0123[i] = a.e0123[i] - b.e0123[i];
+  }
+  out
+}
+
+// Unary
+
+impl_negate_components! { Reverse::reverse: MultivectorX {
+  e23, e31, e12, e01, e02, e03, e123, e032, e013, e021
+} }
+impl_negate_components! { Reverse::reverse: ScalarX {} }
+impl_negate_components! { Reverse::reverse: VectorX {} }
+impl_negate_components! { Reverse::reverse: BivectorX {
+  e23, e31, e12, e01, e02, e03
+} }
+impl_negate_components! { Reverse::reverse: TrivectorX {
+  e123, e032, e013, e021
+} }
+impl_negate_components! { Reverse::reverse: PseudoscalarX {} }
+impl_negate_components! { Reverse::reverse: MotorX {
+  e23, e31, e12, e01, e02, e03
+} }
+impl_negate_components! { Conjugate::conjugate: MultivectorX {
+  e0, e1, e2, e3, e23, e31, e12, e01, e02, e03
+} }
+impl_negate_components! { Conjugate::conjugate: ScalarX {} }
+impl_negate_components! { Conjugate::conjugate: VectorX { e0, e1, e2, e3 } }
+impl_negate_components! { Conjugate::conjugate: BivectorX {
+  e23, e31, e12, e01, e02, e03
+} }
+impl_negate_components! { Conjugate::conjugate: TrivectorX {} }
+impl_negate_components! { Conjugate::conjugate: PseudoscalarX {} }
+impl_neg! { MultivectorX {
+  e0, e1, e2, e3, s, e23, e31, e12, e01, e02, e03, e0123, e123, e032, e013,
+  e021
+} }
+impl_neg! { ScalarX { s } }
+impl_neg! { VectorX { e0, e1, e2, e3 } }
+impl_neg! { BivectorX { e23, e31, e12, e01, e02, e03 } }
+impl_neg! { TrivectorX { e123, e032, e013, e021 } }
+impl_neg! { PseudoscalarX { e0123 } }
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+  use ::approx::assert_relative_eq;
+
+  const MULTIVECTORS: [Multivector; 4] =
+    [MULTIVECTOR_A, MULTIVECTOR_B, MULTIVECTOR_C, MULTIVECTOR_D];
+
+  /// Four lanes of test values
+  trait Samples: Widen {
+    fn samples() -> [Self; 4];
+  }
+
+  macro_rules! impl_samples {
+    ($($type:ident: $from_multivector:expr),+) => {
+      $(
+        impl Samples for $type {
+          fn samples() -> [Self; 4] {
+            MULTIVECTORS.map($from_multivector)
+          }
+        }
+      )+
+    };
+  }
+
+  impl_samples! {
+    Multivector: |multivector| multivector,
+    Scalar: Multivector::grade_0,
+    Vector: Multivector::grade_1,
+    Bivector: Multivector::grade_2,
+    Trivector: Multivector::grade_3,
+    Pseudoscalar: Multivector::grade_4,
+    Motor: Motor::from_even,
+    Empty: |_| Empty
+  }
+
+  /// check that the kernels match the scalar operator lane by lane, up to
+  /// rounding
+  macro_rules! assert_kernels {
+    ($method:ident: $($lhs:ident, $rhs:ident;)+) => {$({
+      let (lhs, rhs) = ($lhs::samples(), $rhs::samples());
+      let (a, b) = (<$lhs as Widen>::Wide::<4>::from_array(lhs), <$rhs as Widen>::Wide::<4>::from_array(rhs));
+      let result = a.$method(b);
+      for i in 0..4 {
+        let expected = lhs[i].$method(rhs[i]);
+        assert_relative_eq!(result.extract(i), expected, epsilon = 1e-3, max_relative = 1e-6);
+      }
+    })+};
+  }
+
+  #[test]
+  fn geometric_product() {
+    assert_kernels! { geometric_product:
+      Multivector, Multivector;
+      Multivector, Scalar;
+      Multivector, Vector;
+      Multivector, Bivector;
+      Multivector, Trivector;
+      Multivector, Pseudoscalar;
+      Scalar, Multivector;
+      Scalar, Scalar;
+      Scalar, Vector;
+      Scalar, Bivector;
+      Scalar, Trivector;
+      Scalar, Pseudoscalar;
+      Vector, Multivector;
+      Vector, Scalar;
+      Vector, Vector;
+      Vector, Bivector;
+      Vector, Trivector;
+      Vector, Pseudoscalar;
+      Bivector, Multivector;
+      Bivector, Scalar;
+      Bivector, Vector;
+      Bivector, Bivector;
+      Bivector, Trivector;
+      Bivector, Pseudoscalar;
+      Trivector, Multivector;
+      Trivector, Scalar;
+      Trivector, Vector;
+      Trivector, Bivector;
+      Trivector, Trivector;
+      Trivector, Pseudoscalar;
+      Pseudoscalar, Multivector;
+      Pseudoscalar, Scalar;
+      Pseudoscalar, Vector;
+      Pseudoscalar, Bivector;
+      Pseudoscalar, Trivector;
+      Motor, Motor;
+    }
+    assert_kernels! { mul:
+      Multivector, Multivector;
+      Multivector, Scalar;
+      Multivector, Vector;
+      Multivector, Bivector;
+      Multivector, Trivector;
+      Multivector, Pseudoscalar;
+      Scalar, Multivector;
+      Scalar, Scalar;
+      Scalar, Vector;
+      Scalar, Bivector;
+      Scalar, Trivector;
+      Scalar, Pseudoscalar;
+      Vector, Multivector;
+      Vector, Scalar;
+      Vector, Vector;
+      Vector, Bivector;
+      Vector, Trivector;
+      Vector, Pseudoscalar;
+      Bivector, Multivector;
+      Bivector, Scalar;
+      Bivector, Vector;
+      Bivector, Bivector;
+      Bivector, Trivector;
+      Bivector, Pseudoscalar;
+      Trivector, Multivector;
+      Trivector, Scalar;
+      Trivector, Vector;
+      Trivector, Bivector;
+      Trivector, Trivector;
+      Trivector, Pseudoscalar;
+      Pseudoscalar, Multivector;
+      Pseudoscalar, Scalar;
+      Pseudoscalar, Vector;
+      Pseudoscalar, Bivector;
+      Pseudoscalar, Trivector;
+      Motor, Motor;
+    }
+  }
+
+  #[test]
+  fn meet() {
+    assert_kernels! { meet:
+      Multivector, Multivector;
+      Multivector, Scalar;
+      Multivector, Vector;
+      Multivector, Bivector;
+      Multivector, Trivector;
+      Multivector, Pseudoscalar;
+      Scalar, Multivector;
+      Scalar, Scalar;
+      Scalar, Vector;
+      Scalar, Bivector;
+      Scalar, Trivector;
+      Scalar, Pseudoscalar;
+      Vector, Multivector;
+      Vector, Scalar;
+      Vector, Vector;
+      Vector, Bivector;
+      Vector, Trivector;
+      Bivector, Multivector;
+      Bivector, Scalar;
+      Bivector, Vector;
+      Bivector, Bivector;
+      Trivector, Multivector;
+      Trivector, Scalar;
+      Trivector, Vector;
+      Pseudoscalar, Multivector;
+      Pseudoscalar, Scalar;
+    }
+  }
+
+  #[test]
+  fn join() {
+    assert_kernels! { join:
+      Multivector, Multivector;
+      Multivector, Scalar;
+      Multivector, Vector;
+      Multivector, Bivector;
+      Multivector, Trivector;
+      Multivector, Pseudoscalar;
+      Scalar, Multivector;
+      Scalar, Pseudoscalar;
+      Vector, Multivector;
+      Vector, Trivector;
+      Vector, Pseudoscalar;
+      Bivector, Multivector;
+      Bivector, Bivector;
+      Bivector, Trivector;
+      Bivector, Pseudoscalar;
+      Trivector, Multivector;
+      Trivector, Vector;
+      Trivector, Bivector;
+      Trivector, Trivector;
+      Trivector, Pseudoscalar;
+      Pseudoscalar, Multivector;
+      Pseudoscalar, Scalar;
+      Pseudoscalar, Vector;
+      Pseudoscalar, Bivector;
+      Pseudoscalar, Trivector;
+      Pseudoscalar, Pseudoscalar;
+    }
+  }
+
+  #[test]
+  fn dot() {
+    assert_kernels! { dot:
+      Multivector, Multivector;
+      Multivector, Scalar;
+      Multivector, Vector;
+      Multivector, Bivector;
+      Multivector, Trivector;
+      Multivector, Pseudoscalar;
+      Scalar, Multivector;
+      Scalar, Scalar;
+      Scalar, Vector;
+      Scalar, Bivector;
+      Scalar, Trivector;
+      Scalar, Pseudoscalar;
+      Vector, Multivector;
+      Vector, Scalar;
+      Vector, Vector;
+      Vector, Bivector;
+      Vector, Trivector;
+      Vector, Pseudoscalar;
+      Bivector, Multivector;
+      Bivector, Scalar;
+      Bivector, Vector;
+      Bivector, Bivector;
+      Bivector, Trivector;
+      Bivector, Pseudoscalar;
+      Trivector, Multivector;
+      Trivector, Scalar;
+      Trivector, Vector;
+      Trivector, Bivector;
+      Trivector, Trivector;
+      Trivector, Pseudoscalar;
+      Pseudoscalar, Multivector;
+      Pseudoscalar, Scalar;
+      Pseudoscalar, Vector;
+      Pseudoscalar, Bivector;
+      Pseudoscalar, Trivector;
+    }
+  }
+
+  #[test]
+  fn add() {
+    assert_kernels! { add:
+      Multivector, Multivector;
+      Multivector, Scalar;
+      Multivector, Vector;
+      Multivector, Bivector;
+      Multivector, Trivector;
+      Multivector, Pseudoscalar;
+      Multivector, Empty;
+      Scalar, Multivector;
+      Scalar, Scalar;
+      Scalar, Vector;
+      Scalar, Bivector;
+      Scalar, Trivector;
+      Scalar, Pseudoscalar;
+      Scalar, Empty;
+      Vector, Multivector;
+      Vector, Scalar;
+      Vector, Vector;
+      Vector, Bivector;
+      Vector, Trivector;
+      Vector, Pseudoscalar;
+      Vector, Empty;
+      Bivector, Multivector;
+      Bivector, Scalar;
+      Bivector, Vector;
+      Bivector, Bivector;
+      Bivector, Trivector;
+      Bivector, Pseudoscalar;
+      Bivector, Empty;
+      Trivector, Multivector;
+      Trivector, Scalar;
+      Trivector, Vector;
+      Trivector, Bivector;
+      Trivector, Trivector;
+      Trivector, Pseudoscalar;
+      Trivector, Empty;
+      Pseudoscalar, Multivector;
+      Pseudoscalar, Scalar;
+      Pseudoscalar, Vector;
+      Pseudoscalar, Bivector;
+      Pseudoscalar, Trivector;
+      Pseudoscalar, Pseudoscalar;
+      Pseudoscalar, Empty;
+    }
+  }
+
+  #[test]
+  fn sub() {
+    assert_kernels! { sub:
+      Multivector, Multivector;
+      Multivector, Scalar;
+      Multivector, Vector;
+      Multivector, Bivector;
+      Multivector, Trivector;
+      Multivector, Pseudoscalar;
+      Multivector, Empty;
+      Scalar, Multivector;
+      Scalar, Scalar;
+      Scalar, Vector;
+      Scalar, Bivector;
+      Scalar, Trivector;
+      Scalar, Pseudoscalar;
+      Scalar, Empty;
+      Vector, Multivector;
+      Vector, Scalar;
+      Vector, Vector;
+      Vector, Bivector;
+      Vector, Trivector;
+      Vector, Pseudoscalar;
+      Vector, Empty;
+      Bivector, Multivector;
+      Bivector, Scalar;
+      Bivector, Vector;
+      Bivector, Bivector;
+      Bivector, Trivector;
+      Bivector, Pseudoscalar;
+      Bivector, Empty;
+      Trivector, Multivector;
+      Trivector, Scalar;
+      Trivector, Vector;
+      Trivector, Bivector;
+      Trivector, Trivector;
+      Trivector, Pseudoscalar;
+      Trivector, Empty;
+      Pseudoscalar, Multivector;
+      Pseudoscalar, Scalar;
+      Pseudoscalar, Vector;
+      Pseudoscalar, Bivector;
+      Pseudoscalar, Trivector;
+      Pseudoscalar, Pseudoscalar;
+      Pseudoscalar, Empty;
+    }
+  }
+
+  #[test]
+  fn empty() {
+    let _: Empty = MultivectorX4::splat(Multivector::ZERO).geometric_product(Empty);
+    let _: Empty = ScalarX4::splat(Scalar::ZERO).geometric_product(Empty);
+    let _: Empty = VectorX4::splat(Vector::ZERO).geometric_product(Empty);
+    let _: Empty = BivectorX4::splat(Bivector::ZERO).geometric_product(Empty);
+    let _: Empty = TrivectorX4::splat(Trivector::ZERO).geometric_product(Empty);
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).geometric_product(PseudoscalarX4::splat(Pseudoscalar::ZERO));
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).geometric_product(Empty);
+    let _: Empty = MultivectorX4::splat(Multivector::ZERO).meet(Empty);
+    let _: Empty = ScalarX4::splat(Scalar::ZERO).meet(Empty);
+    let _: Empty = VectorX4::splat(Vector::ZERO).meet(PseudoscalarX4::splat(Pseudoscalar::ZERO));
+    let _: Empty = VectorX4::splat(Vector::ZERO).meet(Empty);
+    let _: Empty = BivectorX4::splat(Bivector::ZERO).meet(TrivectorX4::splat(Trivector::ZERO));
+    let _: Empty = BivectorX4::splat(Bivector::ZERO).meet(PseudoscalarX4::splat(Pseudoscalar::ZERO));
+    let _: Empty = BivectorX4::splat(Bivector::ZERO).meet(Empty);
+    let _: Empty = TrivectorX4::splat(Trivector::ZERO).meet(BivectorX4::splat(Bivector::ZERO));
+    let _: Empty = TrivectorX4::splat(Trivector::ZERO).meet(TrivectorX4::splat(Trivector::ZERO));
+    let _: Empty = TrivectorX4::splat(Trivector::ZERO).meet(PseudoscalarX4::splat(Pseudoscalar::ZERO));
+    let _: Empty = TrivectorX4::splat(Trivector::ZERO).meet(Empty);
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).meet(VectorX4::splat(Vector::ZERO));
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).meet(BivectorX4::splat(Bivector::ZERO));
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).meet(TrivectorX4::splat(Trivector::ZERO));
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).meet(PseudoscalarX4::splat(Pseudoscalar::ZERO));
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).meet(Empty);
+    let _: Empty = MultivectorX4::splat(Multivector::ZERO).join(Empty);
+    let _: Empty = ScalarX4::splat(Scalar::ZERO).join(ScalarX4::splat(Scalar::ZERO));
+    let _: Empty = ScalarX4::splat(Scalar::ZERO).join(VectorX4::splat(Vector::ZERO));
+    let _: Empty = ScalarX4::splat(Scalar::ZERO).join(BivectorX4::splat(Bivector::ZERO));
+    let _: Empty = ScalarX4::splat(Scalar::ZERO).join(TrivectorX4::splat(Trivector::ZERO));
+    let _: Empty = ScalarX4::splat(Scalar::ZERO).join(Empty);
+    let _: Empty = VectorX4::splat(Vector::ZERO).join(ScalarX4::splat(Scalar::ZERO));
+    let _: Empty = VectorX4::splat(Vector::ZERO).join(VectorX4::splat(Vector::ZERO));
+    let _: Empty = VectorX4::splat(Vector::ZERO).join(BivectorX4::splat(Bivector::ZERO));
+    let _: Empty = VectorX4::splat(Vector::ZERO).join(Empty);
+    let _: Empty = BivectorX4::splat(Bivector::ZERO).join(ScalarX4::splat(Scalar::ZERO));
+    let _: Empty = BivectorX4::splat(Bivector::ZERO).join(VectorX4::splat(Vector::ZERO));
+    let _: Empty = BivectorX4::splat(Bivector::ZERO).join(Empty);
+    let _: Empty = TrivectorX4::splat(Trivector::ZERO).join(ScalarX4::splat(Scalar::ZERO));
+    let _: Empty = TrivectorX4::splat(Trivector::ZERO).join(Empty);
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).join(Empty);
+    let _: Empty = MultivectorX4::splat(Multivector::ZERO).dot(Empty);
+    let _: Empty = ScalarX4::splat(Scalar::ZERO).dot(Empty);
+    let _: Empty = VectorX4::splat(Vector::ZERO).dot(Empty);
+    let _: Empty = BivectorX4::splat(Bivector::ZERO).dot(Empty);
+    let _: Empty = TrivectorX4::splat(Trivector::ZERO).dot(Empty);
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).dot(PseudoscalarX4::splat(Pseudoscalar::ZERO));
+    let _: Empty = PseudoscalarX4::splat(Pseudoscalar::ZERO).dot(Empty);
+  }
+
+  #[test]
+  fn unary() {
+    let values = Multivector::samples();
+    let wide = MultivectorX4::from_array(values);
+    for (i, value) in values.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), value.reverse());
+      assert_eq!(wide.conjugate().extract(i), value.conjugate());
+      assert_eq!((-wide).extract(i), -value);
+    }
+    let values = Scalar::samples();
+    let wide = ScalarX4::from_array(values);
+    for (i, value) in values.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), value.reverse());
+      assert_eq!(wide.conjugate().extract(i), value.conjugate());
+      assert_eq!((-wide).extract(i), -value);
+    }
+    let values = Vector::samples();
+    let wide = VectorX4::from_array(values);
+    for (i, value) in values.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), value.reverse());
+      assert_eq!(wide.conjugate().extract(i), value.conjugate());
+      assert_eq!((-wide).extract(i), -value);
+    }
+    let values = Bivector::samples();
+    let wide = BivectorX4::from_array(values);
+    for (i, value) in values.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), value.reverse());
+      assert_eq!(wide.conjugate().extract(i), value.conjugate());
+      assert_eq!((-wide).extract(i), -value);
+    }
+    let values = Trivector::samples();
+    let wide = TrivectorX4::from_array(values);
+    for (i, value) in values.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), value.reverse());
+      assert_eq!(wide.conjugate().extract(i), value.conjugate());
+      assert_eq!((-wide).extract(i), -value);
+    }
+    let values = Pseudoscalar::samples();
+    let wide = PseudoscalarX4::from_array(values);
+    for (i, value) in values.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), value.reverse());
+      assert_eq!(wide.conjugate().extract(i), value.conjugate());
+      assert_eq!((-wide).extract(i), -value);
+    }
+    let values = Motor::samples();
+    let wide = MotorX4::from_array(values);
+    for (i, value) in values.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), value.reverse());
+    }
+  }
+}
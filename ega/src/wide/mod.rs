@@ -0,0 +1,348 @@
+//! Structure-of-arrays "wide" values
+//!
+//! Each wide type stores `N` values with every component in its own lane
+//! array, e.g. [`VectorX4`] holds four [`Vector`]s as
+//! `{ e0: [f32; 4], e1: [f32; 4], .. }`. The operators are implemented by
+//! kernels which compute each output component for all of the lanes at once,
+//! from the lane arrays of the inputs, rather than extracting each lane as a
+//! scalar value.
+//!
+//! ```
+//! # use ega::*;
+//! # use ega::wide::*;
+//! let planes = [Vector { e0: 1., e1: 1., e2: 0., e3: 0. }; 4];
+//! let a = VectorX4::from_array(planes);
+//! let b = VectorX4::splat(Vector { e0: 0., e1: 0., e2: 1., e3: 0. });
+//!
+//! let lines: BivectorX4 = a.meet(b);
+//! assert_eq!(lines.extract(2), planes[2].meet(b.extract(2)));
+//! ```
+
+use crate::*;
+
+mod kernels;
+
+/// A wide value holding `N` lanes of `Self::Element`
+pub trait Lanes<const N: usize>: Copy {
+  /// The scalar type of each lane
+  type Element: Copy;
+
+  /// Construct a value by calling `f` with the index of each lane
+  fn from_fn(f: impl FnMut(usize) -> Self::Element) -> Self;
+
+  /// Get the value in lane `lane`
+  ///
+  /// # Panics
+  ///
+  /// If `lane >= N`.
+  fn extract(&self, lane: usize) -> Self::Element;
+
+  /// Set the value in lane `lane`
+  ///
+  /// # Panics
+  ///
+  /// If `lane >= N`.
+  fn replace(&mut self, lane: usize, value: Self::Element);
+
+  /// Every lane set to `value`
+  #[inline]
+  fn splat(value: Self::Element) -> Self {
+    Self::from_fn(|_| value)
+  }
+
+  /// Construct a value from an array of scalar values
+  #[inline]
+  fn from_array(values: [Self::Element; N]) -> Self {
+    Self::from_fn(|i| values[i])
+  }
+
+  /// Construct a value from the first `N` elements of a slice
+  ///
+  /// # Panics
+  ///
+  /// If `values.len() < N`.
+  #[inline]
+  fn from_slice(values: &[Self::Element]) -> Self {
+    assert!(
+      values.len() >= N,
+      "slice is shorter than the number of lanes"
+    );
+    Self::from_fn(|i| values[i])
+  }
+
+  /// The lanes as an array of scalar values
+  #[inline]
+  fn to_array(&self) -> [Self::Element; N] {
+    core::array::from_fn(|i| self.extract(i))
+  }
+
+  /// Write the lanes to the first `N` elements of a slice
+  ///
+  /// # Panics
+  ///
+  /// If `out.len() < N`.
+  #[inline]
+  fn write_to_slice(&self, out: &mut [Self::Element]) {
+    assert!(out.len() >= N, "slice is shorter than the number of lanes");
+    for (i, out) in out[..N].iter_mut().enumerate() {
+      *out = self.extract(i);
+    }
+  }
+}
+
+/// A scalar type with a wide counterpart
+pub trait Widen: Copy {
+  /// The wide type holding `N` lanes of `Self`
+  type Wide<const N: usize>: Lanes<N, Element = Self>;
+}
+
+macro_rules! wide_type {
+  (
+    $(#[$meta:meta])*
+    $wide:ident, $x4:ident, $x8:ident => $scalar:ident {
+      $($field:ident),+ $(,)?
+    }
+  ) => {
+    $(#[$meta])*
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    #[repr(C)]
+    pub struct $wide<const N: usize> {
+      $(pub $field: [f32; N],)+
+    }
+
+    #[doc = concat!("Four lanes of [`", stringify!($scalar), "`]")]
+    pub type $x4 = $wide<4>;
+    #[doc = concat!("Eight lanes of [`", stringify!($scalar), "`]")]
+    pub type $x8 = $wide<8>;
+
+    impl<const N: usize> Lanes<N> for $wide<N> {
+      type Element = $scalar;
+
+      #[inline]
+      fn from_fn(mut f: impl FnMut(usize) -> $scalar) -> Self {
+        let mut wide = $wide { $($field: [0.; N],)+ };
+        for i in 0..N {
+          let value = f(i);
+          $(wide.$field[i] = value.$field;)+
+        }
+        wide
+      }
+
+      #[inline]
+      fn extract(&self, lane: usize) -> $scalar {
+        $scalar { $($field: self.$field[lane],)+ }
+      }
+
+      #[inline]
+      fn replace(&mut self, lane: usize, value: $scalar) {
+        $(self.$field[lane] = value.$field;)+
+      }
+    }
+
+    impl Widen for $scalar {
+      type Wide<const N: usize> = $wide<N>;
+    }
+
+    impl<const N: usize> Zero for $wide<N> {
+      #[inline]
+      fn zero() -> Self {
+        $wide { $($field: [0.; N],)+ }
+      }
+    }
+
+    impl<const N: usize> From<[$scalar; N]> for $wide<N> {
+      #[inline]
+      fn from(values: [$scalar; N]) -> Self {
+        Self::from_array(values)
+      }
+    }
+
+    impl<const N: usize> From<$wide<N>> for [$scalar; N] {
+      #[inline]
+      fn from(wide: $wide<N>) -> Self {
+        wide.to_array()
+      }
+    }
+  };
+}
+
+wide_type! {
+  /// `N` lanes of [`Multivector`]
+  MultivectorX, MultivectorX4, MultivectorX8 => Multivector {
+    e0, e1, e2, e3, s, e23, e31, e12, e01, e02, e03, e0123, e123, e032, e013,
+    e021,
+  }
+}
+wide_type! {
+  /// `N` lanes of [`Scalar`]
+  ScalarX, ScalarX4, ScalarX8 => Scalar { s }
+}
+wide_type! {
+  /// `N` lanes of [`Vector`]
+  VectorX, VectorX4, VectorX8 => Vector { e0, e1, e2, e3 }
+}
+wide_type! {
+  /// `N` lanes of [`Bivector`]
+  BivectorX, BivectorX4, BivectorX8 => Bivector {
+    e23, e31, e12, e01, e02, e03,
+  }
+}
+wide_type! {
+  /// `N` lanes of [`Trivector`]
+  TrivectorX, TrivectorX4, TrivectorX8 => Trivector {
+    e123, e032, e013, e021,
+  }
+}
+wide_type! {
+  /// `N` lanes of [`Pseudoscalar`]
+  PseudoscalarX, PseudoscalarX4, PseudoscalarX8 => Pseudoscalar { e0123 }
+}
+wide_type! {
+  /// `N` lanes of [`Motor`]
+  MotorX, MotorX4, MotorX8 => Motor {
+    s, e23, e31, e12, e01, e02, e03, e0123,
+  }
+}
+
+// `Empty` has no components, so is its own wide type
+impl<const N: usize> Lanes<N> for Empty {
+  type Element = Empty;
+
+  #[inline]
+  fn from_fn(_: impl FnMut(usize) -> Empty) -> Self {
+    Empty
+  }
+
+  #[inline]
+  fn extract(&self, lane: usize) -> Empty {
+    assert!(lane < N, "lane out of bounds");
+    Empty
+  }
+
+  #[inline]
+  fn replace(&mut self, lane: usize, _: Empty) {
+    assert!(lane < N, "lane out of bounds");
+  }
+}
+
+impl Widen for Empty {
+  type Wide<const N: usize> = Empty;
+}
+
+#[rustfmt::skip]
+#[cfg(any(test, doctest))]
+mod tests {
+  use super::*;
+  use crate::test_values::*;
+  use ::approx::assert_relative_eq;
+
+  const MULTIVECTORS: [Multivector; 4] =
+    [MULTIVECTOR_A, MULTIVECTOR_B, MULTIVECTOR_C, MULTIVECTOR_D];
+  const VECTORS: [Vector; 4] = [VECTOR_A, VECTOR_B, VECTOR_C, VECTOR_A];
+  const BIVECTORS: [Bivector; 4] = [BIVECTOR_A, BIVECTOR_B, BIVECTOR_C, BIVECTOR_A];
+  const TRIVECTORS: [Trivector; 4] = [TRIVECTOR_A, TRIVECTOR_B, TRIVECTOR_C, TRIVECTOR_A];
+
+  fn widen<T: Widen, const N: usize>(values: [T; N]) -> T::Wide<N> {
+    Lanes::from_array(values)
+  }
+
+  /// check that a wide binary operation matches the scalar operation lane by
+  /// lane, up to rounding
+  macro_rules! assert_lanes {
+    ($lhs:expr, $rhs:expr, $method:ident) => {{
+      let (lhs, rhs) = ($lhs, $rhs);
+      let result = widen(lhs).$method(widen(rhs));
+      for i in 0..lhs.len() {
+        assert_relative_eq!(result.extract(i), lhs[i].$method(rhs[i]), max_relative = 1e-6);
+      }
+    }};
+  }
+
+  #[test]
+  fn round_trip() {
+    let wide = VectorX4::from_array(VECTORS);
+    assert_eq!(wide.e1, [VECTOR_A.e1, VECTOR_B.e1, VECTOR_C.e1, VECTOR_A.e1]);
+    assert_eq!(wide.to_array(), VECTORS);
+    assert_eq!(<[Vector; 4]>::from(wide), VECTORS);
+
+    let mut out = [zero(); 5];
+    wide.write_to_slice(&mut out);
+    assert_eq!(out[..4], VECTORS);
+    assert_eq!(VectorX4::from_slice(&out), wide);
+  }
+
+  #[test]
+  fn splat_extract_replace() {
+    let mut wide = TrivectorX8::splat(TRIVECTOR_A);
+    assert_eq!(wide.extract(7), TRIVECTOR_A);
+    wide.replace(3, TRIVECTOR_B);
+    assert_eq!(wide.extract(3), TRIVECTOR_B);
+    assert_eq!(wide.extract(4), TRIVECTOR_A);
+  }
+
+  #[test]
+  #[should_panic]
+  fn from_short_slice() {
+    VectorX4::from_slice(&VECTORS[..3]);
+  }
+
+  #[test]
+  fn geometric_product() {
+    assert_lanes!(MULTIVECTORS, MULTIVECTORS, geometric_product);
+    assert_lanes!(VECTORS, TRIVECTORS, geometric_product);
+    assert_lanes!(BIVECTORS, VECTORS, geometric_product);
+  }
+
+  #[test]
+  fn meet_and_join() {
+    let lines: BivectorX4 = VectorX4::from_array(VECTORS).meet(VectorX4::splat(VECTOR_B));
+    assert_eq!(lines.extract(0), VECTOR_A.meet(VECTOR_B));
+    assert_lanes!(VECTORS, BIVECTORS, meet);
+    assert_lanes!(TRIVECTORS, TRIVECTORS, join);
+    assert_lanes!(BIVECTORS, TRIVECTORS, join);
+  }
+
+  #[test]
+  fn dot() {
+    assert_lanes!(MULTIVECTORS, VECTORS, dot);
+    assert_lanes!(BIVECTORS, BIVECTORS, dot);
+  }
+
+  #[test]
+  fn add_sub_mul() {
+    assert_lanes!(VECTORS, BIVECTORS, add);
+    assert_lanes!(TRIVECTORS, TRIVECTORS, sub);
+    assert_lanes!(VECTORS, VECTORS, mul);
+  }
+
+  #[test]
+  fn empty() {
+    let result: Empty = TrivectorX4::from_array(TRIVECTORS).meet(TrivectorX4::splat(TRIVECTOR_A));
+    assert_eq!(result, Empty);
+  }
+
+  #[test]
+  fn unary() {
+    let wide = MultivectorX4::from_array(MULTIVECTORS);
+    for (i, multivector) in MULTIVECTORS.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), multivector.reverse());
+      assert_eq!(wide.conjugate().extract(i), multivector.conjugate());
+      assert_eq!((-wide).extract(i), -multivector);
+    }
+  }
+
+  #[test]
+  fn motors() {
+    let motors = [
+      Motor::from_even(VECTOR_A * VECTOR_B),
+      Motor::from_even(VECTOR_B * VECTOR_C),
+      Motor::from_even(VECTOR_C * VECTOR_A),
+      Motor::identity(),
+    ];
+    assert_lanes!(motors, motors, geometric_product);
+    let wide = MotorX4::from_array(motors);
+    for (i, motor) in motors.into_iter().enumerate() {
+      assert_eq!(wide.reverse().extract(i), motor.reverse());
+    }
+  }
+}